@@ -6,7 +6,7 @@ use colored::Colorize;
 use dialoguer::Confirm;
 use indicatif::ProgressBar;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::message::v0::Message;
 use solana_sdk::message::VersionedMessage;
 use solana_sdk::pubkey::Pubkey;
@@ -18,11 +18,13 @@ use squads_multisig::pda::{get_proposal_pda, get_transaction_pda};
 use squads_multisig::solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use squads_multisig::squads_multisig_program::accounts::BatchAccountsClose as BatchAccountsCloseAccounts;
 use squads_multisig::squads_multisig_program::accounts::ConfigTransactionAccountsClose as ConfigTransactionAccountsCloseAccounts;
+use squads_multisig::squads_multisig_program::accounts::TransactionsAccountsCloseMany as TransactionsAccountsCloseManyAccounts;
 use squads_multisig::squads_multisig_program::accounts::VaultTransactionAccountsClose as VaultTransactionAccountsCloseAccounts;
 use squads_multisig::squads_multisig_program::anchor_lang::InstructionData;
 use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
 use squads_multisig::squads_multisig_program::instruction::BatchAccountsClose as BatchAccountsCloseData;
 use squads_multisig::squads_multisig_program::instruction::ConfigTransactionAccountsClose as ConfigTransactionAccountsCloseData;
+use squads_multisig::squads_multisig_program::instruction::TransactionsAccountsCloseMany as TransactionsAccountsCloseManyData;
 use squads_multisig::squads_multisig_program::instruction::VaultTransactionAccountsClose as VaultTransactionAccountsCloseData;
 use squads_multisig::squads_multisig_program::state::{Batch, ConfigTransaction, VaultTransaction};
 use squads_multisig::state::{Proposal, ProposalStatus};
@@ -32,6 +34,9 @@ use crate::utils::{create_signer_from_path, send_and_confirm_transaction};
 const DEFAULT_PRIORITY_FEE: u64 = 5000;
 // Max close instructions per outer tx (RPC limits ~1232-byte message; 8 fits reliably).
 const MAX_CLOSE_IX_PER_TX: usize = 8;
+// Max (proposal, transaction) pairs per `transactions_accounts_close_many` instruction,
+// one instruction per outer tx to stay within the same ~1232-byte message limit.
+const MAX_CLOSE_PAIRS_PER_IX: usize = 12;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum TxAccountKind {
@@ -41,7 +46,8 @@ enum TxAccountKind {
 }
 
 /// Scan multisig transaction indices for closable proposal + transaction accounts and reclaim rent
-/// to the multisig `rent_collector` (same mechanism as the Squads UI).
+/// to the multisig `rent_collector` (same mechanism as the Squads UI), or refund it to the accounts
+/// that paid it if the multisig has no `rent_collector` and refunds the rent payers.
 #[derive(Args)]
 #[command(about = "Scan executed/terminal proposals and reclaim rent (vault, config, batch)")]
 pub struct ClaimRent {
@@ -82,6 +88,10 @@ struct CloseItem {
     status: ProposalStatus,
     proposal_lamports: u64,
     transaction_lamports: u64,
+    /// Where the transaction rent goes.
+    rent_collector: Pubkey,
+    /// Where the proposal rent goes, if it's not the `rent_collector`.
+    proposal_rent_payer: Option<Pubkey>,
 }
 
 impl ClaimRent {
@@ -117,11 +127,16 @@ impl ClaimRent {
             .await
             .map_err(|e| eyre::eyre!("Failed to load multisig: {}", e))?;
 
-        let rent_collector = multisig_data.rent_collector.ok_or_else(|| {
-            eyre::eyre!(
-                "This multisig has no rent_collector set; rent reclamation is disabled on-chain"
-            )
-        })?;
+        // Without a `rent_collector`, the rent is refunded to the accounts that paid it
+        // if the multisig opted into it.
+        let rent_collector = multisig_data.rent_collector;
+        if rent_collector.is_none() && !multisig_data.extension.refund_rent_to_payers {
+            println!(
+                "{}",
+                "Rent reclamation is disabled: the multisig has no rent collector and doesn't refund the rent payers.".red()
+            );
+            return Ok(());
+        }
 
         let max_index = multisig_data.transaction_index;
         if max_index == 0 {
@@ -138,7 +153,10 @@ impl ClaimRent {
         );
         println!("RPC:              {}", rpc_url_clone);
         println!("Multisig:         {}", multisig_pubkey);
-        println!("Rent collector:   {}", rent_collector);
+        match rent_collector {
+            Some(rent_collector) => println!("Rent collector:   {}", rent_collector),
+            None => println!("Rent collector:   (none, refunding the rent payers)"),
+        }
         println!(
             "Index range:      {} … {} (last {})",
             start, max_index, last_n
@@ -166,15 +184,20 @@ impl ClaimRent {
                 continue;
             }
 
-            let kind = if Batch::try_deserialize(&mut tx_acc.data.as_slice()).is_ok() {
-                TxAccountKind::Batch
-            } else if VaultTransaction::try_deserialize(&mut tx_acc.data.as_slice()).is_ok() {
-                TxAccountKind::Vault
-            } else if ConfigTransaction::try_deserialize(&mut tx_acc.data.as_slice()).is_ok() {
-                TxAccountKind::Config
-            } else {
-                continue;
-            };
+            let (kind, transaction_rent_payer) =
+                if let Ok(batch) = Batch::try_deserialize(&mut tx_acc.data.as_slice()) {
+                    (TxAccountKind::Batch, batch.extension.rent_payer)
+                } else if let Ok(transaction) =
+                    VaultTransaction::try_deserialize(&mut tx_acc.data.as_slice())
+                {
+                    (TxAccountKind::Vault, transaction.extension.rent_payer)
+                } else if let Ok(transaction) =
+                    ConfigTransaction::try_deserialize(&mut tx_acc.data.as_slice())
+                {
+                    (TxAccountKind::Config, transaction.extension.rent_payer)
+                } else {
+                    continue;
+                };
 
             let proposal = match proposal_acc {
                 Some(p) if !p.data.is_empty() => Proposal::try_deserialize(&mut p.data.as_slice())
@@ -227,6 +250,14 @@ impl ClaimRent {
                 continue;
             }
 
+            // Accounts without a recorded rent payer can only be closed to a `rent_collector`.
+            let (Ok(item_rent_collector), Ok(proposal_rent_destination)) = (
+                multisig_data.rent_destination(transaction_rent_payer),
+                multisig_data.rent_destination(proposal.extension.rent_payer),
+            ) else {
+                continue;
+            };
+
             let pl = proposal_acc.map(|a| a.lamports).unwrap_or(0);
             closable.push(CloseItem {
                 index: idx,
@@ -234,6 +265,9 @@ impl ClaimRent {
                 status: proposal.status.clone(),
                 proposal_lamports: pl,
                 transaction_lamports: tx_acc.lamports,
+                rent_collector: item_rent_collector,
+                proposal_rent_payer: (proposal_rent_destination != item_rent_collector)
+                    .then_some(proposal_rent_destination),
             });
         }
 
@@ -279,7 +313,53 @@ impl ClaimRent {
         let mut ix_groups: Vec<Vec<Instruction>> = vec![];
         let mut current: Vec<Instruction> = vec![];
 
+        // Vault transactions whose rent goes to a single destination, the `rent_collector`
+        // or the account that paid for both the transaction and the proposal, are closed many at once.
+        let closes_many =
+            |c: &CloseItem| c.kind == TxAccountKind::Vault && c.proposal_rent_payer.is_none();
+        let mut rent_destinations = closable
+            .iter()
+            .filter(|c| closes_many(c))
+            .map(|c| c.rent_collector)
+            .collect::<Vec<_>>();
+        rent_destinations.sort();
+        rent_destinations.dedup();
+
+        for rent_destination in rent_destinations {
+            let vault_items = closable
+                .iter()
+                .filter(|c| closes_many(c) && c.rent_collector == rent_destination)
+                .collect::<Vec<_>>();
+
+            for chunk in vault_items.chunks(MAX_CLOSE_PAIRS_PER_IX) {
+                let mut accounts = TransactionsAccountsCloseManyAccounts {
+                    multisig,
+                    rent_collector: rent_destination,
+                    system_program: system_program::id(),
+                }
+                .to_account_metas(Some(false));
+                for c in chunk {
+                    let proposal_pda = get_proposal_pda(&multisig, c.index, Some(&program_id));
+                    let transaction_pda =
+                        get_transaction_pda(&multisig, c.index, Some(&program_id));
+                    accounts.push(AccountMeta::new(proposal_pda.0, false));
+                    accounts.push(AccountMeta::new(transaction_pda.0, false));
+                }
+
+                ix_groups.push(vec![Instruction {
+                    accounts,
+                    data: TransactionsAccountsCloseManyData {}.data(),
+                    program_id,
+                }]);
+            }
+        }
+
         for c in &closable {
+            if closes_many(c) {
+                // Already closed many at once above.
+                continue;
+            }
+
             let proposal_pda = get_proposal_pda(&multisig, c.index, Some(&program_id));
             let transaction_pda = get_transaction_pda(&multisig, c.index, Some(&program_id));
 
@@ -288,9 +368,10 @@ impl ClaimRent {
                     accounts: VaultTransactionAccountsCloseAccounts {
                         multisig,
                         proposal: proposal_pda.0,
-                        rent_collector,
+                        rent_collector: c.rent_collector,
                         transaction: transaction_pda.0,
                         system_program: system_program::id(),
+                        proposal_rent_payer: c.proposal_rent_payer,
                     }
                     .to_account_metas(Some(false)),
                     data: VaultTransactionAccountsCloseData {}.data(),
@@ -300,9 +381,10 @@ impl ClaimRent {
                     accounts: ConfigTransactionAccountsCloseAccounts {
                         multisig,
                         proposal: proposal_pda.0,
-                        rent_collector,
+                        rent_collector: c.rent_collector,
                         transaction: transaction_pda.0,
                         system_program: system_program::id(),
+                        proposal_rent_payer: c.proposal_rent_payer,
                     }
                     .to_account_metas(Some(false)),
                     data: ConfigTransactionAccountsCloseData {}.data(),
@@ -313,8 +395,9 @@ impl ClaimRent {
                         multisig,
                         proposal: proposal_pda.0,
                         batch: transaction_pda.0,
-                        rent_collector,
+                        rent_collector: c.rent_collector,
                         system_program: system_program::id(),
+                        proposal_rent_payer: c.proposal_rent_payer,
                     }
                     .to_account_metas(Some(false)),
                     data: BatchAccountsCloseData {}.data(),
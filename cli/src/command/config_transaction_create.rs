@@ -5,6 +5,7 @@ use clap::Args;
 use colored::Colorize;
 use dialoguer::Confirm;
 use indicatif::ProgressBar;
+use solana_sdk::bs58;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
 use solana_sdk::hash::hash;
 use solana_sdk::instruction::Instruction;
@@ -20,15 +21,17 @@ use squads_multisig::solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use squads_multisig::squads_multisig_program::accounts::ConfigTransactionCreate as ConfigTransactionCreateAccounts;
 use squads_multisig::squads_multisig_program::accounts::ProposalCreate as ProposalCreateAccounts;
 
-use squads_multisig::squads_multisig_program::accounts::ProposalVote as ProposalVoteAccounts;
+use squads_multisig::squads_multisig_program::accounts::ProposalVoteV2 as ProposalVoteV2Accounts;
 use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
 use squads_multisig::squads_multisig_program::instruction::ConfigTransactionCreate as ConfigTransactionCreateData;
-use squads_multisig::squads_multisig_program::instruction::ProposalApprove;
+use squads_multisig::squads_multisig_program::instruction::ProposalApproveV2;
 use squads_multisig::squads_multisig_program::instruction::ProposalCreate as ProposalCreateData;
 use squads_multisig::squads_multisig_program::{
     ConfigTransactionCreateArgs, ProposalCreateArgs, ProposalVoteArgs,
 };
-use squads_multisig::state::{ConfigAction, Period, Permission, Permissions};
+use squads_multisig::state::{
+    AllowedProgram, ConfigAction, Period, Permission, Permissions, TwoPersonRule, VaultScope,
+};
 
 use crate::utils::{create_signer_from_path, send_and_confirm_transaction};
 
@@ -172,13 +175,16 @@ impl ConfigTransactionCreate {
 
         if approve {
             instructions.push(Instruction {
-                accounts: ProposalVoteAccounts {
+                accounts: ProposalVoteV2Accounts {
                     member: transaction_creator,
                     multisig,
                     proposal: proposal_pda.0,
+                    transaction: Some(transaction_pda.0),
+                    session_key: None,
+                    system_program: None,
                 }
                 .to_account_metas(Some(false)),
-                data: ProposalApprove {
+                data: ProposalApproveV2 {
                     args: ProposalVoteArgs { memo },
                 }
                 .data(),
@@ -304,10 +310,237 @@ fn parse_action(action_str: &str) -> Result<ConfigAction, String> {
                 .map_err(|_| "Invalid rent collector pubkey format")?;
             Ok(ConfigAction::SetRentCollector { new_rent_collector })
         }
+        Some("SetMemberVaultScope") => {
+            let member = parts
+                .get(1)
+                .ok_or("Member pubkey is required for SetMemberVaultScope action")?
+                .parse()
+                .map_err(|_| "Invalid member pubkey format")?;
+            // Omitting the permissions and vault indexes lifts the restriction.
+            let vault_scope = match (parts.get(2), parts.get(3)) {
+                (Some(permissions), Some(vault_indexes)) => {
+                    let mask = permissions
+                        .parse()
+                        .map_err(|_| "Invalid permissions format")?;
+                    let vault_indexes = vault_indexes
+                        .split(',')
+                        .map(|s| s.parse())
+                        .collect::<Result<Vec<u8>, _>>()
+                        .map_err(|_| "Invalid vault indexes format")?;
+                    Some(VaultScope::new(Permissions { mask }, &vault_indexes))
+                }
+                _ => None,
+            };
+            Ok(ConfigAction::SetMemberVaultScope {
+                member,
+                vault_scope,
+            })
+        }
+        Some("SetMemberValidUntil") => {
+            let member = parts
+                .get(1)
+                .ok_or("Member pubkey is required for SetMemberValidUntil action")?
+                .parse()
+                .map_err(|_| "Invalid member pubkey format")?;
+            // Omitting the timestamp makes the membership permanent.
+            let valid_until = parts
+                .get(2)
+                .map(|s| s.parse())
+                .transpose()
+                .map_err(|_| "Invalid valid_until format")?;
+            Ok(ConfigAction::SetMemberValidUntil {
+                member,
+                valid_until,
+            })
+        }
+        Some("SetMemberPasskey") => {
+            let member = parts
+                .get(1)
+                .ok_or("Member pubkey is required for SetMemberPasskey action")?
+                .parse()
+                .map_err(|_| "Invalid member pubkey format")?;
+            // Omitting the passkey removes it.
+            let passkey = parts
+                .get(2)
+                .map(|s| {
+                    bs58::decode(s)
+                        .into_vec()
+                        .ok()
+                        .and_then(|bytes| <[u8; 33]>::try_from(bytes).ok())
+                        .ok_or("Invalid passkey format, expected 33 base58-encoded bytes")
+                })
+                .transpose()?;
+            Ok(ConfigAction::SetMemberPasskey { member, passkey })
+        }
+        Some("RemoveAllowance") => {
+            let allowance = parts
+                .get(1)
+                .ok_or("Allowance pubkey is required for RemoveAllowance action")?
+                .parse()
+                .map_err(|_| "Invalid allowance pubkey format")?;
+            Ok(ConfigAction::RemoveAllowance { allowance })
+        }
+        Some("AddStream") => parse_add_stream(&parts[1..]),
+        Some("CancelStream") => {
+            let stream = parts
+                .get(1)
+                .ok_or("Stream pubkey is required for CancelStream action")?
+                .parse()
+                .map_err(|_| "Invalid stream pubkey format")?;
+            Ok(ConfigAction::CancelStream { stream })
+        }
+        Some("SetProgramAllowlist") => {
+            // Omitting the entries removes the allowlist.
+            let new_program_allowlist = parts
+                .get(1)
+                .map(|entries| {
+                    entries
+                        .split(',')
+                        .map(parse_allowed_program)
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()?;
+            Ok(ConfigAction::SetProgramAllowlist {
+                new_program_allowlist,
+            })
+        }
+        Some("SetTwoPersonRule") => {
+            let new_two_person_rule = match parts.get(1).copied() {
+                Some("Disabled") => TwoPersonRule::Disabled,
+                Some("ExecutorNotCreator") => TwoPersonRule::ExecutorNotCreator,
+                Some("ExecutorNotCreatorOrApprover") => TwoPersonRule::ExecutorNotCreatorOrApprover,
+                _ => {
+                    return Err("Two-person rule must be one of: Disabled, ExecutorNotCreator, ExecutorNotCreatorOrApprover".to_string());
+                }
+            };
+            Ok(ConfigAction::SetTwoPersonRule {
+                new_two_person_rule,
+            })
+        }
+        Some("SetRentRefund") => {
+            let new_refund_rent_to_payers = parts
+                .get(1)
+                .ok_or("Refund flag is required for SetRentRefund action")?
+                .parse()
+                .map_err(|_| "Refund flag must be true or false")?;
+            Ok(ConfigAction::SetRentRefund {
+                new_refund_rent_to_payers,
+            })
+        }
+        Some("SetRateLimit") => {
+            let period = parts
+                .get(1)
+                .ok_or("Period is required for SetRateLimit action")?
+                .parse()
+                .map_err(|_| "Invalid period format")?;
+            // `-` leaves the corresponding limit or over-limit condition unset.
+            let max_executions =
+                parse_optional(parts.get(2)).map_err(|_| "Invalid max executions format")?;
+            let max_lamports =
+                parse_optional(parts.get(3)).map_err(|_| "Invalid max lamports format")?;
+            let over_limit_threshold =
+                parse_optional(parts.get(4)).map_err(|_| "Invalid over-limit threshold format")?;
+            let over_limit_time_lock =
+                parse_optional(parts.get(5)).map_err(|_| "Invalid over-limit time lock format")?;
+            Ok(ConfigAction::SetRateLimit {
+                period,
+                max_executions,
+                max_lamports,
+                over_limit_threshold,
+                over_limit_time_lock,
+            })
+        }
+        Some("RemoveRateLimit") => Ok(ConfigAction::RemoveRateLimit),
+        Some("CloseMultisig") => {
+            let rent_destination = parts
+                .get(1)
+                .ok_or("Rent destination pubkey is required for CloseMultisig action")?
+                .parse()
+                .map_err(|_| "Invalid rent destination pubkey format")?;
+            // Omitting the vault indexes skips the vault checks.
+            let vault_indices = parts
+                .get(2)
+                .map(|vault_indexes| {
+                    vault_indexes
+                        .split(',')
+                        .map(|s| s.parse())
+                        .collect::<Result<Vec<u8>, _>>()
+                })
+                .transpose()
+                .map_err(|_| "Invalid vault indexes format")?
+                .unwrap_or_default();
+            Ok(ConfigAction::CloseMultisig {
+                rent_destination,
+                vault_indices,
+            })
+        }
         _ => Err("Invalid or unsupported action".to_string()),
     }
 }
 
+/// Parses `<PROGRAM_ID>[:<VAULT_INDEX>[:<ALLOW_AUTHORITY_CHANGES>]]`,
+/// where `*` as `<VAULT_INDEX>` means the entry applies to all vaults.
+fn parse_allowed_program(entry: &str) -> Result<AllowedProgram, String> {
+    let mut fields = entry.split(':');
+
+    let program_id = fields
+        .next()
+        .ok_or("Program ID is required for an allowlist entry")?
+        .parse()
+        .map_err(|_| "Invalid program ID format")?;
+    let vault_index = match fields.next() {
+        None | Some("*") => None,
+        Some(s) => Some(s.parse().map_err(|_| "Invalid vault index format")?),
+    };
+    let allow_authority_changes = fields
+        .next()
+        .map(|s| s.parse())
+        .transpose()
+        .map_err(|_| "Invalid allow authority changes format")?
+        .unwrap_or(false);
+
+    Ok(AllowedProgram {
+        program_id,
+        vault_index,
+        allow_authority_changes,
+    })
+}
+
+/// Parses an optional action argument, where a missing argument or `-` means `None`.
+fn parse_optional<T: FromStr>(part: Option<&&str>) -> Result<Option<T>, T::Err> {
+    match part {
+        None | Some(&"-") => Ok(None),
+        Some(part) => part.parse().map(Some),
+    }
+}
+
+fn parse_add_stream(parts: &[&str]) -> Result<ConfigAction, String> {
+    if parts.len() < 7 {
+        return Err("Not enough arguments for AddStream".to_string());
+    }
+
+    let create_key = parts[0].parse().map_err(|_| "Invalid create_key format")?;
+    let vault_index = parts[1].parse().map_err(|_| "Invalid vault_index format")?;
+    let recipient = parts[2].parse().map_err(|_| "Invalid recipient pubkey format")?;
+    let mint = parts[3].parse().map_err(|_| "Invalid mint format")?;
+    let total_amount = parts[4].parse().map_err(|_| "Invalid total amount format")?;
+    let start_time = parts[5].parse().map_err(|_| "Invalid start time format")?;
+    let end_time = parts[6].parse().map_err(|_| "Invalid end time format")?;
+    // `-` or omitting it means no cliff.
+    let cliff_time = parse_optional(parts.get(7)).map_err(|_| "Invalid cliff time format")?;
+
+    Ok(ConfigAction::AddStream {
+        create_key,
+        vault_index,
+        recipient,
+        mint,
+        total_amount,
+        start_time,
+        end_time,
+        cliff_time,
+    })
+}
+
 fn parse_add_spending_limit(parts: &[&str]) -> Result<ConfigAction, String> {
     if parts.len() < 7 {
         return Err("Not enough arguments for AddSpendingLimit".to_string());
@@ -1,5 +1,6 @@
 use clap::Args;
 use colored::Colorize;
+use solana_sdk::bs58;
 use solana_sdk::pubkey::Pubkey;
 use squads_multisig::anchor_lang::AccountDeserialize;
 use squads_multisig::solana_rpc_client::nonblocking::rpc_client::RpcClient;
@@ -163,6 +164,233 @@ impl DisplayConfigTransaction {
                         None => println!("  New Rent Collector: (disabled)"),
                     }
                 }
+                ConfigAction::SetMemberVaultScope {
+                    member,
+                    vault_scope,
+                } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Set Member Vault Scope", i + 1)
+                            .yellow()
+                            .bold()
+                    );
+                    println!("  Member: {}", member);
+                    match vault_scope {
+                        Some(scope) => {
+                            let vault_indexes = (0..=u8::MAX)
+                                .filter(|vault_index| scope.allows(*vault_index))
+                                .map(|vault_index| vault_index.to_string())
+                                .collect::<Vec<_>>();
+                            println!(
+                                "  Restricted Permissions: {}",
+                                format_permissions(scope.permissions)
+                            );
+                            println!("  Vaults: {}", vault_indexes.join(", "));
+                        }
+                        None => println!("  Vault Scope: (removed)"),
+                    }
+                }
+                ConfigAction::SetProgramAllowlist {
+                    new_program_allowlist,
+                } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Set Program Allowlist", i + 1)
+                            .yellow()
+                            .bold()
+                    );
+                    match new_program_allowlist {
+                        Some(entries) => {
+                            println!("  Allowed Programs:");
+                            for entry in entries {
+                                let vaults = match entry.vault_index {
+                                    Some(vault_index) => format!("vault {}", vault_index),
+                                    None => "all vaults".to_string(),
+                                };
+                                println!(
+                                    "    {} ({}, authority changes: {})",
+                                    entry.program_id, vaults, entry.allow_authority_changes
+                                );
+                            }
+                        }
+                        None => println!("  Program Allowlist: (removed)"),
+                    }
+                }
+                ConfigAction::SetRateLimit {
+                    period,
+                    max_executions,
+                    max_lamports,
+                    over_limit_threshold,
+                    over_limit_time_lock,
+                } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Set Rate Limit", i + 1).yellow().bold()
+                    );
+                    println!("  Period:               {} seconds", period);
+                    match max_executions {
+                        Some(max_executions) => {
+                            println!("  Max Executions:       {}", max_executions)
+                        }
+                        None => println!("  Max Executions:       (unlimited)"),
+                    }
+                    match max_lamports {
+                        Some(max_lamports) => println!("  Max Lamports:         {}", max_lamports),
+                        None => println!("  Max Lamports:         (unlimited)"),
+                    }
+                    match over_limit_threshold {
+                        Some(threshold) => println!("  Over-Limit Threshold: {}", threshold),
+                        None => println!("  Over-Limit Threshold: (none)"),
+                    }
+                    match over_limit_time_lock {
+                        Some(time_lock) => {
+                            println!("  Over-Limit Time Lock: {} seconds", time_lock)
+                        }
+                        None => println!("  Over-Limit Time Lock: (none)"),
+                    }
+                }
+                ConfigAction::RemoveRateLimit => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Remove Rate Limit", i + 1).yellow().bold()
+                    );
+                }
+                ConfigAction::SetTwoPersonRule {
+                    new_two_person_rule,
+                } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Set Two-Person Rule", i + 1)
+                            .yellow()
+                            .bold()
+                    );
+                    println!("  Two-Person Rule: {:?}", new_two_person_rule);
+                }
+                ConfigAction::SetRentRefund {
+                    new_refund_rent_to_payers,
+                } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Set Rent Refund", i + 1).yellow().bold()
+                    );
+                    println!("  Refund Rent To Payers: {}", new_refund_rent_to_payers);
+                }
+                ConfigAction::AddAllowance {
+                    create_key,
+                    vault_index,
+                    template,
+                    max_uses,
+                    period,
+                    expires_at,
+                    members,
+                } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Add Allowance", i + 1).yellow().bold()
+                    );
+                    println!("  Create Key:  {}", create_key);
+                    println!("  Vault Index: {}", vault_index);
+                    println!("  Program:     {}", template.program_id);
+                    println!("  Writable:    {:?}", template.writable_flags);
+                    for fixed_account in &template.fixed_accounts {
+                        println!(
+                            "  Account #{}:  {}",
+                            fixed_account.index, fixed_account.pubkey
+                        );
+                    }
+                    println!("  Data Pattern: {:?}", template.data_pattern);
+                    println!("  Data Mask:    {:?}", template.data_mask);
+                    println!("  Max Uses:    {}", max_uses);
+                    println!("  Period:      {}", format_period(*period));
+                    match expires_at {
+                        Some(expires_at) => println!("  Expires At:  {}", expires_at),
+                        None => println!("  Expires At:  (never)"),
+                    }
+                    println!("  Members:");
+                    for m in members {
+                        println!("    {}", m);
+                    }
+                }
+                ConfigAction::RemoveAllowance { allowance } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Remove Allowance", i + 1).yellow().bold()
+                    );
+                    println!("  Allowance: {}", allowance);
+                }
+                ConfigAction::AddStream {
+                    create_key,
+                    vault_index,
+                    recipient,
+                    mint,
+                    total_amount,
+                    start_time,
+                    end_time,
+                    cliff_time,
+                } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Add Stream", i + 1).yellow().bold()
+                    );
+                    println!("  Create Key:   {}", create_key);
+                    println!("  Vault Index:  {}", vault_index);
+                    println!("  Recipient:    {}", recipient);
+                    println!("  Mint:         {}", mint);
+                    println!("  Total Amount: {}", total_amount);
+                    println!("  Start Time:   {}", start_time);
+                    println!("  End Time:     {}", end_time);
+                    match cliff_time {
+                        Some(cliff_time) => println!("  Cliff Time:   {}", cliff_time),
+                        None => println!("  Cliff Time:   (none)"),
+                    }
+                }
+                ConfigAction::CancelStream { stream } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Cancel Stream", i + 1).yellow().bold()
+                    );
+                    println!("  Stream: {}", stream);
+                }
+                ConfigAction::CloseMultisig {
+                    rent_destination,
+                    vault_indices,
+                } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Close Multisig", i + 1).yellow().bold()
+                    );
+                    println!("  Rent Destination: {}", rent_destination);
+                    println!("  Empty Vaults:     {:?}", vault_indices);
+                }
+                ConfigAction::SetMemberValidUntil {
+                    member,
+                    valid_until,
+                } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Set Member Valid Until", i + 1)
+                            .yellow()
+                            .bold()
+                    );
+                    println!("  Member:      {}", member);
+                    match valid_until {
+                        Some(valid_until) => println!("  Valid Until: {}", valid_until),
+                        None => println!("  Valid Until: (permanent)"),
+                    }
+                }
+                ConfigAction::SetMemberPasskey { member, passkey } => {
+                    println!(
+                        "{}",
+                        format!("Action {}: Set Member Passkey", i + 1)
+                            .yellow()
+                            .bold()
+                    );
+                    println!("  Member:  {}", member);
+                    match passkey {
+                        Some(passkey) => println!("  Passkey: {}", bs58::encode(passkey).into_string()),
+                        None => println!("  Passkey: (removed)"),
+                    }
+                }
                 _ => {
                     println!(
                         "{}",
@@ -196,6 +196,13 @@ impl DisplayProposals {
                 }
             }
 
+            println!("Abstained:       {} member(s)", proposal.abstained.len());
+            if !proposal.abstained.is_empty() {
+                for pubkey in &proposal.abstained {
+                    println!("  - {}", pubkey);
+                }
+            }
+
             println!("Cancelled by:    {} member(s)", proposal.cancelled.len());
             if !proposal.cancelled.is_empty() {
                 for pubkey in &proposal.cancelled {
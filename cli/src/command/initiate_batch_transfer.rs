@@ -20,10 +20,10 @@ use squads_multisig::client::get_multisig;
 use squads_multisig::pda::{get_proposal_pda, get_transaction_pda, get_vault_pda};
 use squads_multisig::solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use squads_multisig::squads_multisig_program::accounts::ProposalCreate as ProposalCreateAccounts;
-use squads_multisig::squads_multisig_program::accounts::ProposalVote as ProposalVoteAccounts;
+use squads_multisig::squads_multisig_program::accounts::ProposalVoteV2 as ProposalVoteV2Accounts;
 use squads_multisig::squads_multisig_program::accounts::VaultTransactionCreate as VaultTransactionCreateAccounts;
 use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
-use squads_multisig::squads_multisig_program::instruction::ProposalApprove;
+use squads_multisig::squads_multisig_program::instruction::ProposalApproveV2;
 use squads_multisig::squads_multisig_program::instruction::ProposalCreate as ProposalCreateData;
 use squads_multisig::squads_multisig_program::instruction::VaultTransactionCreate as VaultTransactionCreateData;
 use squads_multisig::squads_multisig_program::ProposalCreateArgs;
@@ -333,13 +333,16 @@ impl InitiateBatchTransfer {
 
         if approve {
             instructions.push(Instruction {
-                accounts: ProposalVoteAccounts {
+                accounts: ProposalVoteV2Accounts {
                     member: transaction_creator,
                     multisig,
                     proposal: proposal_pda.0,
+                    transaction: Some(transaction_pda.0),
+                    session_key: None,
+                    system_program: None,
                 }
                 .to_account_metas(Some(false)),
-                data: ProposalApprove {
+                data: ProposalApproveV2 {
                     args: ProposalVoteArgs { memo },
                 }
                 .data(),
@@ -11,21 +11,24 @@ use solana_sdk::instruction::Instruction;
 use solana_sdk::message::v0::Message;
 use solana_sdk::message::VersionedMessage;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
 use solana_sdk::transaction::VersionedTransaction;
 
 use squads_multisig::anchor_lang::InstructionData;
-use squads_multisig::pda::get_proposal_pda;
+use squads_multisig::pda::{get_proposal_pda, get_session_key_pda, get_transaction_pda};
 use squads_multisig::solana_rpc_client::nonblocking::rpc_client::RpcClient;
-use squads_multisig::squads_multisig_program::accounts::ProposalVote as ProposalVoteAccounts;
+use squads_multisig::squads_multisig_program::accounts::ProposalVoteV2 as ProposalVoteV2Accounts;
 use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
-use squads_multisig::squads_multisig_program::instruction::ProposalApprove;
-use squads_multisig::squads_multisig_program::instruction::ProposalCancel;
-use squads_multisig::squads_multisig_program::instruction::ProposalReject;
+use squads_multisig::squads_multisig_program::instruction::ProposalAbstain;
+use squads_multisig::squads_multisig_program::instruction::ProposalApproveV2;
+use squads_multisig::squads_multisig_program::instruction::ProposalCancelV3;
+use squads_multisig::squads_multisig_program::instruction::ProposalRejectV2;
+use squads_multisig::squads_multisig_program::instruction::ProposalWithdrawVote;
 use squads_multisig::squads_multisig_program::ProposalVoteArgs;
 
 use crate::utils::{create_signer_from_path, send_and_confirm_transaction};
 
-/// Cast an approve or reject vote on an existing proposal.
+/// Cast an approve, reject or abstain vote on an existing proposal, or withdraw a vote.
 #[derive(Args)]
 pub struct ProposalVote {
     /// RPC URL
@@ -62,6 +65,10 @@ pub struct ProposalVote {
     /// Path to the Fee Payer Keypair
     #[arg(long)]
     fee_payer_keypair: Option<String>,
+
+    /// Vote with the keypair as a session key registered by this multisig member
+    #[arg(long)]
+    session_key_member: Option<String>,
 }
 
 impl ProposalVote {
@@ -76,6 +83,7 @@ impl ProposalVote {
             memo,
             priority_fee_lamports,
             fee_payer_keypair,
+            session_key_member,
         } = self;
 
         let program_id =
@@ -90,6 +98,11 @@ impl ProposalVote {
         let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
 
         let proposal_pda = get_proposal_pda(&multisig, transaction_index, Some(&program_id));
+        let transaction_pda = get_transaction_pda(&multisig, transaction_index, Some(&program_id));
+        let session_key_pda = session_key_member.map(|member| {
+            let member = Pubkey::from_str(&member).expect("Invalid session key member");
+            get_session_key_pda(&multisig, &member, &transaction_creator, Some(&program_id)).0
+        });
 
         let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
 
@@ -105,20 +118,28 @@ impl ProposalVote {
             .expect("Failed to get blockhash");
 
         let data = match action.to_lowercase().as_str() {
-            "approve" | "ap" => ProposalApprove {
+            "approve" | "ap" => ProposalApproveV2 {
+                args: ProposalVoteArgs { memo },
+            }
+            .data(),
+            "reject" | "rj" => ProposalRejectV2 {
+                args: ProposalVoteArgs { memo },
+            }
+            .data(),
+            "cancel" | "cl" => ProposalCancelV3 {
                 args: ProposalVoteArgs { memo },
             }
             .data(),
-            "reject" | "rj" => ProposalReject {
+            "abstain" | "ab" => ProposalAbstain {
                 args: ProposalVoteArgs { memo },
             }
             .data(),
-            "cancel" | "cl" => ProposalCancel {
+            "withdraw" | "wd" => ProposalWithdrawVote {
                 args: ProposalVoteArgs { memo },
             }
             .data(),
             _ => {
-                eprintln!("Invalid action. Please use one of: Approve, Reject, Cancel, Activate (or their short forms)");
+                eprintln!("Invalid action. Please use one of: Approve, Reject, Cancel, Abstain, Withdraw (or their short forms)");
                 std::process::exit(1);
             }
         };
@@ -132,10 +153,13 @@ impl ProposalVote {
                     priority_fee_lamports.unwrap_or(5000),
                 ),
                 Instruction {
-                    accounts: ProposalVoteAccounts {
+                    accounts: ProposalVoteV2Accounts {
                         member: transaction_creator,
                         multisig,
                         proposal: proposal_pda.0,
+                        transaction: Some(transaction_pda.0),
+                        session_key: session_key_pda,
+                        system_program: Some(system_program::ID),
                     }
                     .to_account_metas(Some(false)),
                     data,
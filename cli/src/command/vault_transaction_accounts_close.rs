@@ -53,6 +53,11 @@ pub struct VaultTransactionAccountsClose {
     #[arg(long)]
     rent_collector: String,
 
+    /// The account that paid the proposal rent, if the multisig has no rent collector
+    /// and it's different from the transaction rent payer passed as `--rent-collector`
+    #[arg(long)]
+    proposal_rent_payer: Option<String>,
+
     #[arg(long)]
     priority_fee_lamports: Option<u64>,
 }
@@ -67,6 +72,7 @@ impl VaultTransactionAccountsClose {
             multisig_pubkey,
             transaction_index,
             rent_collector,
+            proposal_rent_payer,
             priority_fee_lamports,
         } = self;
         let program_id =
@@ -79,6 +85,8 @@ impl VaultTransactionAccountsClose {
 
         let rent_collector_key =
             Pubkey::from_str(&rent_collector).expect("Invalid rent collector key");
+        let proposal_rent_payer_key = proposal_rent_payer
+            .map(|key| Pubkey::from_str(&key).expect("Invalid proposal rent payer key"));
 
         let transaction_creator_keypair = create_signer_from_path(keypair).unwrap();
         let transaction_creator = transaction_creator_keypair.pubkey();
@@ -138,6 +146,7 @@ impl VaultTransactionAccountsClose {
                         rent_collector: rent_collector_key,
                         transaction: transaction_pda.0,
                         system_program: system_program::id(),
+                        proposal_rent_payer: proposal_rent_payer_key,
                     }
                     .to_account_metas(Some(false)),
                     data: VaultTransactionAccountsCloseData {}.data(),
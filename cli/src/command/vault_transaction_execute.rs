@@ -12,7 +12,8 @@ use solana_sdk::pubkey::Pubkey;
 use solana_sdk::transaction::VersionedTransaction;
 use squads_multisig::anchor_lang::{AccountDeserialize, InstructionData};
 use squads_multisig::pda::{
-    get_ephemeral_signer_pda, get_proposal_pda, get_transaction_pda, get_vault_pda,
+    get_ephemeral_signer_pda, get_proposal_pda, get_rate_limit_pda, get_transaction_pda,
+    get_vault_pda,
 };
 use squads_multisig::solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use squads_multisig::squads_multisig_program::accounts::VaultTransactionExecute as VaultTransactionExecuteAccounts;
@@ -163,6 +164,12 @@ impl VaultTransactionExecute {
         }
         .to_account_metas(Some(false));
         vault_transaction_account_metas.extend(remaining_account_metas.0);
+
+        // The rate limit account follows the message accounts, only if the multisig has one.
+        let rate_limit_pda = get_rate_limit_pda(&multisig, Some(&program_id)).0;
+        if rpc_client.get_account(&rate_limit_pda).await.is_ok() {
+            vault_transaction_account_metas.push(AccountMeta::new(rate_limit_pda, false));
+        }
         let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
         progress.enable_steady_tick(Duration::from_millis(100));
 
@@ -92,4 +92,82 @@ pub enum MultisigError {
     FinalBufferSizeMismatch,
     #[msg("multisig_create has been deprecated. Use multisig_create_v2 instead.")]
     MultisigCreateDeprecated,
+    #[msg("Threshold must be reachable by the members that never expire")]
+    ThresholdUnreachable,
+    #[msg("Member's permission is not scoped to this vault")]
+    VaultOutOfScope,
+    #[msg("Found multiple program allowlist entries for the same program and vault")]
+    DuplicateAllowedProgram,
+    #[msg("Program is not in the multisig program allowlist")]
+    ProgramNotAllowed,
+    #[msg("Authority changes are not allowed for this program")]
+    AuthorityChangeNotAllowed,
+    #[msg("Found multiple token outflow limits for the same mint")]
+    DuplicateTokenOutflowLimit,
+    #[msg("Vault lamports outflow exceeds the balance guard")]
+    LamportsOutflowExceeded,
+    #[msg("Vault token outflow exceeds the balance guard")]
+    TokenOutflowExceeded,
+    #[msg("Vault or ephemeral signer PDA is no longer a system-owned account without data")]
+    SignerAccountStateChanged,
+    #[msg("Allowance max uses must be greater than 0")]
+    AllowanceInvalidMaxUses,
+    #[msg("Invalid instruction template")]
+    InvalidInstructionTemplate,
+    #[msg("Instruction doesn't match the allowance template")]
+    InstructionNotMatchingTemplate,
+    #[msg("Allowance has expired")]
+    AllowanceExpired,
+    #[msg("Allowance has no remaining uses in the current period")]
+    AllowanceExhausted,
+    #[msg("Vault transaction precondition doesn't hold")]
+    PreconditionFailed,
+    #[msg("Transaction can't be executed partially")]
+    PartialExecutionNotAllowed,
+    #[msg("Vault must be empty to close the multisig")]
+    VaultNotEmpty,
+    #[msg("Multisig can't be closed while it has open accounts")]
+    MultisigNotEmpty,
+    #[msg("CloseMultisig must be the last action of the config transaction")]
+    CloseMultisigNotLastAction,
+    #[msg("Only stale proposals can be rebased")]
+    ProposalNotStale,
+    #[msg("Member already abstained from voting on the transaction")]
+    AlreadyAbstained,
+    #[msg("Member has no vote to withdraw")]
+    NoVoteToWithdraw,
+    #[msg("The creator of the transaction can't execute it")]
+    ExecutorIsCreator,
+    #[msg("The members who approved the transaction can't execute it")]
+    ExecutorIsApprover,
+    #[msg("Rate limit period must be greater than 0")]
+    RateLimitInvalidPeriod,
+    #[msg("Execution exceeds the rate limit of the multisig")]
+    RateLimitExceeded,
+    #[msg("Stream amount must be greater than 0")]
+    StreamInvalidAmount,
+    #[msg("Stream must start before it ends, and its cliff must be within the vesting period")]
+    StreamInvalidSchedule,
+    #[msg("Stream has nothing vested to claim")]
+    StreamNothingToClaim,
+    #[msg("Session key is expired")]
+    SessionKeyExpired,
+    #[msg("Session key is not allowed to take this action")]
+    SessionKeyOutOfScope,
+    #[msg("Passkey member key must be the address derived from the passkey")]
+    PasskeyAddressMismatch,
+    #[msg("Passkey vote must be preceded by a secp256r1 signature verification instruction")]
+    PasskeyMissingSignature,
+    #[msg("Passkey signature doesn't match the member or the challenge")]
+    PasskeyInvalidSignature,
+    #[msg("Endorsement can't vote in the multisig it belongs to")]
+    EndorsementInvalidParent,
+    #[msg("Transaction message is still being moved in from the transaction buffer")]
+    TransactionMessagePending,
+    #[msg("Transaction has no message pending to be moved in from a transaction buffer")]
+    TransactionMessageNotPending,
+    #[msg("Multisig is closed")]
+    MultisigClosed,
+    #[msg("Passkey members can only have the Vote permission")]
+    PasskeyMemberNotVoteOnly,
 }
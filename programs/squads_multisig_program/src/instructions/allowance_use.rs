@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke_signed;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AllowanceUseArgs {
+    /// Data of the instruction to execute.
+    pub data: Vec<u8>,
+    /// Memo used for indexing.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct AllowanceUse<'info> {
+    /// The multisig account the `allowance` is for.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    pub member: Signer<'info>,
+
+    /// The Allowance account to use.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_ALLOWANCE,
+            allowance.create_key.key().as_ref(),
+        ],
+        bump = allowance.bump,
+    )]
+    pub allowance: Account<'info, Allowance>,
+
+    /// Multisig vault account to execute the instruction from.
+    /// CHECK: All the required checks are done by checking the seeds.
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_VAULT,
+            &allowance.vault_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// The program to invoke.
+    /// CHECK: Checked against the allowance template in `AllowanceUse::allowance_use`.
+    pub program: AccountInfo<'info>,
+
+    /// The rate limit of the multisig, the executed instruction counts against. Required if the multisig has one.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, multisig.key().as_ref(), SEED_RATE_LIMIT],
+        bump = rate_limit.bump,
+    )]
+    pub rate_limit: Option<Account<'info, RateLimit>>,
+    // `remaining_accounts` must include the accounts of the instruction to execute, in order.
+}
+
+impl<'info> AllowanceUse<'info> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            multisig,
+            member,
+            allowance,
+            ..
+        } = self;
+
+        // multisig
+        // Must not be closed.
+        require!(!multisig.is_closed(), MultisigError::MultisigClosed);
+
+        // member
+        require!(
+            allowance.members.contains(&member.key()),
+            MultisigError::Unauthorized
+        );
+        // Removing or expiring a member of the multisig revokes their allowances.
+        require!(
+            multisig
+                .is_active_member(member.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
+            MultisigError::NotAMember
+        );
+
+        // allowance
+        if let Some(expires_at) = allowance.expires_at {
+            require!(
+                Clock::get()?.unix_timestamp <= expires_at,
+                MultisigError::AllowanceExpired
+            );
+        }
+
+        // vault - checked in the #[account] attribute.
+
+        // program - checked against the template in the handler.
+
+        // rate_limit
+        multisig.check_rate_limit_account(self.rate_limit.as_ref())?;
+
+        Ok(())
+    }
+
+    /// Use an allowance to execute an instruction matching its template from a multisig vault.
+    #[access_control(ctx.accounts.validate())]
+    pub fn allowance_use(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: AllowanceUseArgs,
+    ) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let allowance = &mut ctx.accounts.allowance;
+        let vault = &ctx.accounts.vault;
+        let program = &ctx.accounts.program;
+
+        let multisig_key = multisig.key();
+        let vault_bump = ctx.bumps.vault;
+        let now = Clock::get()?.unix_timestamp;
+
+        // Reset `allowance.remaining_uses` if the `allowance.period` has passed.
+        if let Some(reset_period) = allowance.period.to_seconds() {
+            let passed_since_last_reset = now.checked_sub(allowance.last_reset).unwrap();
+
+            if passed_since_last_reset > reset_period {
+                allowance.remaining_uses = allowance.max_uses;
+
+                let periods_passed = passed_since_last_reset.checked_div(reset_period).unwrap();
+
+                // last_reset = last_reset + periods_passed * reset_period,
+                allowance.last_reset = allowance
+                    .last_reset
+                    .checked_add(periods_passed.checked_mul(reset_period).unwrap())
+                    .unwrap();
+            }
+        }
+
+        // Update `allowance.remaining_uses`.
+        allowance.remaining_uses = allowance
+            .remaining_uses
+            .checked_sub(1)
+            .ok_or(MultisigError::AllowanceExhausted)?;
+
+        let protected_accounts = &[
+            Some(multisig_key),
+            Some(allowance.key()),
+            ctx.accounts.rate_limit.as_ref().map(Key::key),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        let accounts = ctx
+            .remaining_accounts
+            .iter()
+            .map(|account| {
+                // Make sure we don't pass protected accounts as writable to the CPI call.
+                require!(
+                    !(account.is_writable && protected_accounts.contains(account.key)),
+                    MultisigError::ProtectedAccount
+                );
+
+                // The vault can't sign the outer transaction, it signs via `invoke_signed`.
+                let is_signer = account.is_signer || account.key == vault.key;
+
+                Ok(if account.is_writable {
+                    AccountMeta::new(*account.key, is_signer)
+                } else {
+                    AccountMeta::new_readonly(*account.key, is_signer)
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Make sure the instruction matches the allowance template.
+        allowance
+            .template
+            .check(program.key, &accounts, &args.data)?;
+
+        // Make sure the instruction is permitted by the program allowlist, if it's set.
+        if let Some(program_allowlist) = multisig.vault_program_allowlist(allowance.vault_index) {
+            check_program_allowlist(&program_allowlist, program.key, &args.data)?;
+        }
+
+        let vault_lamports_before = vault_lamports(vault.key, ctx.remaining_accounts);
+
+        let mut account_infos = ctx.remaining_accounts.to_vec();
+        account_infos.push(program.clone());
+
+        invoke_signed(
+            &Instruction {
+                program_id: *program.key,
+                accounts,
+                data: args.data,
+            },
+            &account_infos,
+            &[&[
+                SEED_PREFIX,
+                multisig_key.as_ref(),
+                SEED_VAULT,
+                &allowance.vault_index.to_le_bytes(),
+                &[vault_bump],
+            ]],
+        )?;
+
+        // Make sure the vault wasn't assigned to another program or allocated data on.
+        require!(
+            vault.owner == &system_program::ID && vault.data_is_empty(),
+            MultisigError::SignerAccountStateChanged
+        );
+
+        // Count the execution against the rate limit of the multisig, if it has one.
+        if let Some(rate_limit) = &mut ctx.accounts.rate_limit {
+            let vault_lamports_after = vault_lamports(vault.key, ctx.remaining_accounts);
+            rate_limit.check_and_record(
+                1,
+                vault_lamports_before.saturating_sub(vault_lamports_after),
+                None,
+                now,
+            )?;
+        }
+
+        Ok(())
+    }
+}
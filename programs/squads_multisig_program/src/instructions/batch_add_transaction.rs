@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::errors::*;
 use crate::state::*;
+use crate::utils::AccountExtension;
 use crate::TransactionMessage;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -11,6 +12,34 @@ pub struct BatchAddTransactionArgs {
     pub transaction_message: Vec<u8>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BatchAddTransactionArgsV2 {
+    /// Number of ephemeral signing PDAs required by the transaction.
+    pub ephemeral_signers: u8,
+    pub transaction_message: Vec<u8>,
+    /// Assertions on the vault balances checked after the transaction is executed.
+    pub balance_guards: Option<BalanceGuards>,
+    /// Whether the vault PDA is allowed to be assigned to another program
+    /// or to have data allocated on it during execution.
+    pub allow_vault_account_changes: bool,
+    /// Whether the ephemeral signer PDAs must stay owned by the System Program with no data
+    /// after execution. Off by default, as creating a mint or a token account at an ephemeral
+    /// signer is what they are for.
+    pub deny_ephemeral_signer_account_changes: bool,
+}
+
+impl From<BatchAddTransactionArgs> for BatchAddTransactionArgsV2 {
+    fn from(args: BatchAddTransactionArgs) -> Self {
+        Self {
+            ephemeral_signers: args.ephemeral_signers,
+            transaction_message: args.transaction_message,
+            balance_guards: None,
+            allow_vault_account_changes: false,
+            deny_ephemeral_signer_account_changes: false,
+        }
+    }
+}
+
 #[derive(Accounts)]
 #[instruction(args: BatchAddTransactionArgs)]
 pub struct BatchAddTransaction<'info> {
@@ -50,7 +79,78 @@ pub struct BatchAddTransaction<'info> {
     #[account(
         init,
         payer = rent_payer,
-        space = VaultBatchTransaction::size(args.ephemeral_signers, &args.transaction_message)?,
+        space = VaultBatchTransaction::size(
+            args.ephemeral_signers,
+            &args.transaction_message,
+            &None,
+        )?,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &batch.index.to_le_bytes(),
+            SEED_BATCH_TRANSACTION,
+            &batch.size.checked_add(1).unwrap().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction: Account<'info, VaultBatchTransaction>,
+
+    /// Member of the multisig.
+    pub member: Signer<'info>,
+
+    /// The payer for the batch transaction account rent.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// `BatchAddTransaction` with the arguments added after the original layout.
+#[derive(Accounts)]
+#[instruction(args: BatchAddTransactionArgsV2)]
+pub struct BatchAddTransactionV2<'info> {
+    /// Multisig account this batch belongs to.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// The proposal account associated with the batch.
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &batch.index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &batch.index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+    )]
+    pub batch: Account<'info, Batch>,
+
+    /// `VaultBatchTransaction` account to initialize and add to the `batch`.
+    #[account(
+        init,
+        payer = rent_payer,
+        space = VaultBatchTransaction::size(
+            args.ephemeral_signers,
+            &args.transaction_message,
+            &args.balance_guards,
+        )?,
         seeds = [
             SEED_PREFIX,
             multisig.key().as_ref(),
@@ -75,75 +175,161 @@ pub struct BatchAddTransaction<'info> {
 
 impl BatchAddTransaction<'_> {
     fn validate(&self) -> Result<()> {
+        validate_member(
+            &self.multisig,
+            &self.proposal,
+            &self.batch,
+            self.member.key(),
+        )
+    }
+
+    /// Add a transaction to the batch.
+    #[access_control(ctx.accounts.validate())]
+    pub fn batch_add_transaction(ctx: Context<Self>, args: BatchAddTransactionArgs) -> Result<()> {
         let Self {
             multisig,
-            member,
-            proposal,
             batch,
+            transaction,
+            rent_payer,
             ..
-        } = self;
-
-        // `member`
-        require!(
-            multisig.is_member(member.key()).is_some(),
-            MultisigError::NotAMember
-        );
-        require!(
-            multisig.member_has_permission(member.key(), Permission::Initiate),
-            MultisigError::Unauthorized
-        );
-        // Only batch creator can add transactions to it.
-        require!(member.key() == batch.creator, MultisigError::Unauthorized);
-
-        // `proposal`
-        require!(
-            matches!(proposal.status, ProposalStatus::Draft { .. }),
-            MultisigError::InvalidProposalStatus
-        );
-
-        // `batch` is validated by its seeds.
-
-        Ok(())
+        } = ctx.accounts;
+
+        add_transaction(
+            ctx.program_id,
+            multisig,
+            batch,
+            transaction,
+            ctx.bumps.transaction,
+            rent_payer.key(),
+            args.into(),
+        )
     }
+}
 
-    /// Add a transaction to the batch.
+impl BatchAddTransactionV2<'_> {
+    fn validate(&self) -> Result<()> {
+        validate_member(
+            &self.multisig,
+            &self.proposal,
+            &self.batch,
+            self.member.key(),
+        )
+    }
+
+    /// Add a transaction with balance guards and the account change flags to the batch.
     #[access_control(ctx.accounts.validate())]
-    pub fn batch_add_transaction(ctx: Context<Self>, args: BatchAddTransactionArgs) -> Result<()> {
-        let batch = &mut ctx.accounts.batch;
-        let transaction = &mut ctx.accounts.transaction;
+    pub fn batch_add_transaction_v2(
+        ctx: Context<Self>,
+        args: BatchAddTransactionArgsV2,
+    ) -> Result<()> {
+        let Self {
+            multisig,
+            batch,
+            transaction,
+            rent_payer,
+            ..
+        } = ctx.accounts;
+
+        add_transaction(
+            ctx.program_id,
+            multisig,
+            batch,
+            transaction,
+            ctx.bumps.transaction,
+            rent_payer.key(),
+            args,
+        )
+    }
+}
+
+fn validate_member(
+    multisig: &Multisig,
+    proposal: &Proposal,
+    batch: &Batch,
+    member: Pubkey,
+) -> Result<()> {
+    // `member`
+    require!(
+        multisig
+            .is_active_member(member, Clock::get()?.unix_timestamp)
+            .is_some(),
+        MultisigError::NotAMember
+    );
+    require!(
+        multisig.member_has_permission(member, Permission::Initiate),
+        MultisigError::Unauthorized
+    );
+    // Only batch creator can add transactions to it.
+    require!(member == batch.creator, MultisigError::Unauthorized);
+
+    // `proposal`
+    require!(
+        matches!(proposal.status, ProposalStatus::Draft { .. }),
+        MultisigError::InvalidProposalStatus
+    );
 
-        let batch_key = batch.key();
+    // `batch` is validated by its seeds.
 
-        let transaction_message =
-            TransactionMessage::deserialize(&mut args.transaction_message.as_slice())?;
+    Ok(())
+}
+
+/// Initializes the `transaction` and appends it to the `batch`.
+fn add_transaction(
+    program_id: &Pubkey,
+    multisig: &Multisig,
+    batch: &mut Account<Batch>,
+    transaction: &mut Account<VaultBatchTransaction>,
+    transaction_bump: u8,
+    rent_payer: Pubkey,
+    args: BatchAddTransactionArgsV2,
+) -> Result<()> {
+    let batch_key = batch.key();
 
-        let ephemeral_signer_bumps: Vec<u8> = (0..args.ephemeral_signers)
-            .map(|ephemeral_signer_index| {
-                let ephemeral_signer_seeds = &[
-                    SEED_PREFIX,
-                    batch_key.as_ref(),
-                    SEED_EPHEMERAL_SIGNER,
-                    &ephemeral_signer_index.to_le_bytes(),
-                ];
+    let transaction_message =
+        TransactionMessage::deserialize(&mut args.transaction_message.as_slice())?;
 
-                let (_, bump) =
-                    Pubkey::find_program_address(ephemeral_signer_seeds, ctx.program_id);
+    let ephemeral_signer_bumps: Vec<u8> = (0..args.ephemeral_signers)
+        .map(|ephemeral_signer_index| {
+            let ephemeral_signer_seeds = &[
+                SEED_PREFIX,
+                batch_key.as_ref(),
+                SEED_EPHEMERAL_SIGNER,
+                &ephemeral_signer_index.to_le_bytes(),
+            ];
 
-                bump
-            })
-            .collect();
+            let (_, bump) = Pubkey::find_program_address(ephemeral_signer_seeds, program_id);
 
-        transaction.bump = ctx.bumps.transaction;
-        transaction.ephemeral_signer_bumps = ephemeral_signer_bumps;
-        transaction.message = transaction_message.try_into()?;
+            bump
+        })
+        .collect();
 
-        // Increment the batch size.
-        batch.size = batch.size.checked_add(1).expect("overflow");
+    transaction.bump = transaction_bump;
+    transaction.ephemeral_signer_bumps = ephemeral_signer_bumps;
+    transaction.message = transaction_message.try_into()?;
 
-        // Logs for indexing.
-        msg!("batch index: {}", batch.index);
-        msg!("batch size: {}", batch.size);
+    // Make sure the transaction only invokes the allowed programs, if the allowlist is set.
+    if let Some(program_allowlist) = multisig.vault_program_allowlist(batch.vault_index) {
+        transaction
+            .message
+            .check_program_allowlist(&program_allowlist)?;
+    }
 
-        Ok(())
+    if let Some(balance_guards) = &args.balance_guards {
+        balance_guards.invariant()?;
     }
+    transaction.extension = AccountExtension::new(VaultBatchTransactionExtension {
+        balance_guards: args.balance_guards,
+        allow_vault_account_changes: args.allow_vault_account_changes,
+        deny_ephemeral_signer_account_changes: args.deny_ephemeral_signer_account_changes,
+        rent_payer,
+    });
+
+    // Increment the batch size.
+    batch.size = batch.size.checked_add(1).expect("overflow");
+
+    // Logs for indexing.
+    msg!("batch index: {}", batch.index);
+    msg!("batch size: {}", batch.size);
+
+    Ok(())
 }
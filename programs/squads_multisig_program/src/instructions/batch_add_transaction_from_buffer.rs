@@ -0,0 +1,145 @@
+use crate::errors::*;
+use crate::instructions::*;
+use crate::state::*;
+use anchor_lang::{prelude::*, system_program};
+
+#[derive(Accounts)]
+pub struct BatchAddTransactionFromBuffer<'info> {
+    // The context needed for the BatchAddTransactionV2 instruction
+    pub batch_add_transaction: BatchAddTransactionV2<'info>,
+
+    #[account(
+        mut,
+        // Only the creator and the co-writers can turn the buffer into a transaction
+        constraint = transaction_buffer.is_writer(&member.key()) @ MultisigError::Unauthorized,
+        seeds = [
+            SEED_PREFIX,
+            batch_add_transaction.multisig.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            transaction_buffer.creator.as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction_buffer: Box<Account<'info, TransactionBuffer>>,
+
+    // Anchor doesn't allow us to use the member inside of
+    // batch_add_transaction, so we just re-pass it here with the same constraint
+    #[account(
+        mut,
+        address = batch_add_transaction.member.key(),
+    )]
+    pub member: Signer<'info>,
+
+    /// The member who created the `transaction_buffer`, receives the buffer rent once it's closed.
+    /// Only required if `member` is one of the co-writers.
+    /// CHECK: Validated against `transaction_buffer.creator`.
+    #[account(mut)]
+    pub buffer_creator: Option<AccountInfo<'info>>,
+}
+
+impl<'info> BatchAddTransactionFromBuffer<'info> {
+    pub fn validate(&self, args: &BatchAddTransactionArgsV2) -> Result<()> {
+        let transaction_buffer_account = &self.transaction_buffer;
+
+        // Check that the transaction message is "empty"
+        require!(
+            args.transaction_message == vec![0, 0, 0, 0, 0, 0],
+            MultisigError::InvalidInstructionArgs
+        );
+
+        // Validate that the final hash matches the buffer
+        transaction_buffer_account.validate_hash()?;
+
+        // Validate that the final size is correct
+        transaction_buffer_account.validate_size()?;
+
+        // The batch transaction account is allocated within this instruction,
+        // so it can't hold buffers larger than MAX_BUFFER_SIZE bytes.
+        require!(
+            transaction_buffer_account.final_buffer_size as usize <= MAX_BUFFER_SIZE,
+            MultisigError::FinalBufferSizeExceeded
+        );
+        Ok(())
+    }
+    /// Add a transaction to the batch from a completed transaction buffer account.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn batch_add_transaction_from_buffer(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: BatchAddTransactionArgsV2,
+    ) -> Result<()> {
+        // Account infos necessary for reallocation
+        let batch_transaction_account_info = &ctx
+            .accounts
+            .batch_add_transaction
+            .transaction
+            .to_account_info();
+        let rent_payer_account_info = &ctx
+            .accounts
+            .batch_add_transaction
+            .rent_payer
+            .to_account_info();
+
+        let system_program = &ctx.accounts.batch_add_transaction.system_program.to_account_info();
+
+        // Read-only accounts
+        let transaction_buffer = &ctx.accounts.transaction_buffer;
+
+        // Calculate the new required length of the batch transaction account,
+        // since it was initialized with an empty transaction message
+        let new_len = VaultBatchTransaction::size(
+            args.ephemeral_signers,
+            transaction_buffer.buffer.as_slice(),
+            &args.balance_guards,
+        )?;
+
+        // Calculate the rent exemption for new length
+        let rent_exempt_lamports = Rent::get().unwrap().minimum_balance(new_len).max(1);
+
+        // Check the difference between the rent exemption and the current lamports
+        let top_up_lamports =
+            rent_exempt_lamports.saturating_sub(batch_transaction_account_info.lamports());
+
+        // System Transfer the remaining difference to the batch transaction account
+        let transfer_context = CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: rent_payer_account_info.clone(),
+                to: batch_transaction_account_info.clone(),
+            },
+        );
+        system_program::transfer(transfer_context, top_up_lamports)?;
+
+        // Reallocate the batch transaction account to the new length of the
+        // actual transaction message
+        batch_transaction_account_info.resize(new_len)?;
+
+        // Create the args for the batch add transaction instruction
+        let add_args = BatchAddTransactionArgsV2 {
+            ephemeral_signers: args.ephemeral_signers,
+            transaction_message: transaction_buffer.buffer.clone(),
+            balance_guards: args.balance_guards,
+            allow_vault_account_changes: args.allow_vault_account_changes,
+            deny_ephemeral_signer_account_changes: args.deny_ephemeral_signer_account_changes,
+        };
+        // Create the context for the batch add transaction instruction
+        let context = Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.batch_add_transaction,
+            ctx.remaining_accounts,
+            ctx.bumps.batch_add_transaction,
+        );
+
+        // Call the batch add transaction instruction
+        BatchAddTransactionV2::batch_add_transaction_v2(context, add_args)?;
+
+        // The buffer is no longer needed, the rent goes back to its creator.
+        TransactionBuffer::close_to_creator(
+            &ctx.accounts.transaction_buffer,
+            ctx.accounts.member.to_account_info(),
+            ctx.accounts.buffer_creator.clone(),
+        )?;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::errors::*;
 use crate::state::*;
+use crate::utils::AccountExtension;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct BatchCreateArgs {
@@ -44,26 +45,36 @@ pub struct BatchCreate<'info> {
 }
 
 impl BatchCreate<'_> {
-    fn validate(&self) -> Result<()> {
+    fn validate(&self, args: &BatchCreateArgs) -> Result<()> {
         let Self {
             multisig, creator, ..
         } = self;
 
         // creator
         require!(
-            multisig.is_member(creator.key()).is_some(),
+            multisig
+                .is_active_member(creator.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
             MultisigError::NotAMember
         );
         require!(
             multisig.member_has_permission(creator.key(), Permission::Initiate),
             MultisigError::Unauthorized
         );
+        require!(
+            multisig.member_has_vault_permission(
+                creator.key(),
+                Permission::Initiate,
+                args.vault_index
+            ),
+            MultisigError::VaultOutOfScope
+        );
 
         Ok(())
     }
 
     /// Create a new batch.
-    #[access_control(ctx.accounts.validate())]
+    #[access_control(ctx.accounts.validate(&args))]
     pub fn batch_create(ctx: Context<Self>, args: BatchCreateArgs) -> Result<()> {
         let multisig = &mut ctx.accounts.multisig;
         let creator = &mut ctx.accounts.creator;
@@ -90,6 +101,9 @@ impl BatchCreate<'_> {
         batch.vault_bump = vault_bump;
         batch.size = 0;
         batch.executed_transaction_index = 0;
+        batch.extension = AccountExtension::new(BatchExtension {
+            rent_payer: ctx.accounts.rent_payer.key(),
+        });
 
         batch.invariant()?;
 
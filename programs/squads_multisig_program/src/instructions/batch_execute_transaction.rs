@@ -61,6 +61,7 @@ pub struct BatchExecuteTransaction<'info> {
     // 1. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
     // 2. Accounts in the order they appear in `message.account_keys`.
     // 3. Accounts in the order they appear in `message.address_table_lookups`.
+    // 4. The `RateLimit` account of the multisig, writable, if it has one.
 }
 
 impl BatchExecuteTransaction<'_> {
@@ -69,18 +70,30 @@ impl BatchExecuteTransaction<'_> {
             multisig,
             member,
             proposal,
+            batch,
             ..
         } = self;
 
         // `member`
         require!(
-            multisig.is_member(member.key()).is_some(),
+            multisig
+                .is_active_member(member.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
             MultisigError::NotAMember
         );
         require!(
             multisig.member_has_permission(member.key(), Permission::Execute),
             MultisigError::Unauthorized
         );
+        require!(
+            multisig.member_has_vault_permission(
+                member.key(),
+                Permission::Execute,
+                batch.vault_index
+            ),
+            MultisigError::VaultOutOfScope
+        );
+        multisig.check_two_person_rule(member.key(), batch.creator, proposal)?;
 
         // `proposal`
         match proposal.status {
@@ -105,7 +118,7 @@ impl BatchExecuteTransaction<'_> {
     /// Execute a transaction from the batch.
     #[access_control(ctx.accounts.validate())]
     pub fn batch_execute_transaction(ctx: Context<Self>) -> Result<()> {
-        let multisig = &mut ctx.accounts.multisig;
+        let multisig = &ctx.accounts.multisig;
         let proposal = &mut ctx.accounts.proposal;
         let batch = &mut ctx.accounts.batch;
 
@@ -115,55 +128,37 @@ impl BatchExecuteTransaction<'_> {
         // Instead only make use of the returned `transaction` value.
         let transaction = ctx.accounts.transaction.take();
 
-        let multisig_key = multisig.key();
-        let batch_key = batch.key();
+        // The rate limit of the multisig follows the message accounts, if it has one.
+        let num_accounts = transaction.message.address_table_lookups.len()
+            + transaction.message.num_all_account_keys();
+        let rate_limit_info = ctx.remaining_accounts.get(num_accounts);
+        let mut rate_limit = multisig.load_rate_limit(&multisig.key(), rate_limit_info)?;
 
-        let vault_seeds = &[
-            SEED_PREFIX,
-            multisig_key.as_ref(),
-            SEED_VAULT,
-            &batch.vault_index.to_le_bytes(),
-            &[batch.vault_bump],
-        ];
-
-        let transaction_message = transaction.message;
-        let num_lookups = transaction_message.address_table_lookups.len();
-
-        let message_account_infos = ctx
-            .remaining_accounts
-            .get(num_lookups..)
-            .ok_or(MultisigError::InvalidNumberOfAccounts)?;
-        let address_lookup_table_account_infos = ctx
-            .remaining_accounts
-            .get(..num_lookups)
-            .ok_or(MultisigError::InvalidNumberOfAccounts)?;
-
-        let vault_pubkey = Pubkey::create_program_address(vault_seeds, ctx.program_id).unwrap();
-
-        let (ephemeral_signer_keys, ephemeral_signer_seeds) =
-            derive_ephemeral_signers(batch_key, &transaction.ephemeral_signer_bumps);
-
-        let executable_message = ExecutableTransactionMessage::new_validated(
-            transaction_message,
-            message_account_infos,
-            address_lookup_table_account_infos,
-            &vault_pubkey,
-            &ephemeral_signer_keys,
+        let vault_lamports_moved = execute_batch_transaction(
+            ctx.program_id,
+            multisig,
+            batch,
+            proposal.key(),
+            rate_limit
+                .as_ref()
+                .and(rate_limit_info)
+                .map(|info| *info.key),
+            transaction,
+            ctx.remaining_accounts
+                .get(..num_accounts)
+                .ok_or(MultisigError::InvalidNumberOfAccounts)?,
         )?;
 
-        let protected_accounts = &[proposal.key(), batch_key];
-
-        // Execute the transaction message instructions one-by-one.
-        // NOTE: `execute_message()` calls `self.to_instructions_and_accounts()`
-        // which in turn calls `take()` on
-        // `self.message.instructions`, therefore after this point no more
-        // references or usages of `self.message` should be made to avoid
-        // faulty behavior.
-        executable_message.execute_message(
-            vault_seeds,
-            &ephemeral_signer_seeds,
-            protected_accounts,
-        )?;
+        // Record the execution against the rate limit of the multisig, if it has one.
+        if let Some(rate_limit) = &mut rate_limit {
+            rate_limit.check_and_record(
+                1,
+                vault_lamports_moved,
+                Some(proposal),
+                Clock::get()?.unix_timestamp,
+            )?;
+            rate_limit.save(rate_limit_info.unwrap())?;
+        }
 
         // Increment the executed transaction index.
         batch.executed_transaction_index = batch
@@ -183,3 +178,91 @@ impl BatchExecuteTransaction<'_> {
         Ok(())
     }
 }
+
+/// Executes a transaction of the `batch`.
+/// `account_infos` must include the AddressLookupTable accounts followed by the message accounts,
+/// in the order expected by `batch_execute_transaction`.
+/// Returns the amount of lamports the transaction moved out of the vault.
+pub(crate) fn execute_batch_transaction(
+    program_id: &Pubkey,
+    multisig: &Account<Multisig>,
+    batch: &Account<Batch>,
+    proposal_key: Pubkey,
+    rate_limit_key: Option<Pubkey>,
+    transaction: VaultBatchTransaction,
+    account_infos: &[AccountInfo],
+) -> Result<u64> {
+    let multisig_key = multisig.key();
+    let batch_key = batch.key();
+
+    let vault_seeds = &[
+        SEED_PREFIX,
+        multisig_key.as_ref(),
+        SEED_VAULT,
+        &batch.vault_index.to_le_bytes(),
+        &[batch.vault_bump],
+    ];
+
+    let transaction_message = transaction.message;
+    let num_lookups = transaction_message.address_table_lookups.len();
+
+    let message_account_infos = account_infos
+        .get(num_lookups..)
+        .ok_or(MultisigError::InvalidNumberOfAccounts)?;
+    let address_lookup_table_account_infos = account_infos
+        .get(..num_lookups)
+        .ok_or(MultisigError::InvalidNumberOfAccounts)?;
+
+    let vault_pubkey = Pubkey::create_program_address(vault_seeds, program_id).unwrap();
+
+    let (ephemeral_signer_keys, ephemeral_signer_seeds) =
+        derive_ephemeral_signers(batch_key, &transaction.ephemeral_signer_bumps);
+
+    let executable_message = ExecutableTransactionMessage::new_validated(
+        transaction_message,
+        message_account_infos,
+        address_lookup_table_account_infos,
+        &vault_pubkey,
+        &ephemeral_signer_keys,
+    )?;
+
+    let protected_accounts = &[Some(proposal_key), Some(batch_key), rate_limit_key]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    // Take a snapshot of the vault balances if the transaction has balance guards.
+    let vault_balances = transaction.extension.balance_guards.as_ref().map(|_| {
+        VaultBalances::snapshot(&vault_pubkey, &executable_message.writable_accounts())
+    });
+
+    let program_allowlist = multisig.vault_program_allowlist(batch.vault_index);
+
+    let vault_lamports_before = vault_lamports(&vault_pubkey, message_account_infos);
+
+    // Execute the transaction message instructions one-by-one.
+    // NOTE: `execute_message()` calls `self.to_instructions_and_accounts()`
+    // which in turn calls `take()` on
+    // `self.message.instructions`, therefore after this point no more
+    // references or usages of `self.message` should be made to avoid
+    // faulty behavior.
+    executable_message.execute_message(
+        vault_seeds,
+        &ephemeral_signer_seeds,
+        protected_accounts,
+        program_allowlist.as_deref(),
+        transaction.extension.allow_vault_account_changes,
+        transaction.extension.deny_ephemeral_signer_account_changes,
+    )?;
+
+    // Make sure the transaction didn't move more out of the vault than its balance guards allow.
+    if let (Some(vault_balances), Some(balance_guards)) =
+        (&vault_balances, &transaction.extension.balance_guards)
+    {
+        vault_balances.check_balance_guards(balance_guards)?;
+    }
+
+    let vault_lamports_after = vault_lamports(&vault_pubkey, message_account_infos);
+
+    Ok(vault_lamports_before.saturating_sub(vault_lamports_after))
+}
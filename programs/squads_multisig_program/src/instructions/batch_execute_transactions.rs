@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::instructions::execute_batch_transaction;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct BatchExecuteTransactions<'info> {
+    /// Multisig account this batch belongs to.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// Member of the multisig.
+    pub member: Signer<'info>,
+
+    /// The proposal account associated with the batch.
+    /// If the last transaction in the batch is executed, the `proposal` status will be set to `Executed`.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &batch.index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &batch.index.to_le_bytes(),
+        ],
+        bump = batch.bump,
+    )]
+    pub batch: Account<'info, Batch>,
+
+    /// The rate limit of the multisig, checked and updated. Required if the multisig has one.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, multisig.key().as_ref(), SEED_RATE_LIMIT],
+        bump = rate_limit.bump,
+    )]
+    pub rate_limit: Option<Account<'info, RateLimit>>,
+    //
+    // `remaining_accounts` must include the following accounts for each of the consecutive
+    // batch transactions to execute, starting from the first one not executed yet:
+    // 1. The `VaultBatchTransaction` account.
+    // 2. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
+    // 3. Accounts in the order they appear in `message.account_keys`.
+    // 4. Accounts in the order they appear in `message.address_table_lookups`.
+}
+
+impl<'info> BatchExecuteTransactions<'info> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            multisig,
+            member,
+            proposal,
+            batch,
+            ..
+        } = self;
+
+        // `member`
+        require!(
+            multisig
+                .is_active_member(member.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
+            MultisigError::NotAMember
+        );
+        require!(
+            multisig.member_has_permission(member.key(), Permission::Execute),
+            MultisigError::Unauthorized
+        );
+        require!(
+            multisig.member_has_vault_permission(
+                member.key(),
+                Permission::Execute,
+                batch.vault_index
+            ),
+            MultisigError::VaultOutOfScope
+        );
+        multisig.check_two_person_rule(member.key(), batch.creator, proposal)?;
+
+        // `proposal`
+        match proposal.status {
+            ProposalStatus::Approved { timestamp } => {
+                require!(
+                    Clock::get()?.unix_timestamp - timestamp >= i64::from(multisig.time_lock),
+                    MultisigError::TimeLockNotReleased
+                );
+            }
+            _ => return err!(MultisigError::InvalidProposalStatus),
+        };
+        // Stale batch transaction proposals CAN be executed if they were approved
+        // before becoming stale, hence no check for staleness here.
+
+        // `batch` is validated by its seeds.
+
+        // `rate_limit`
+        multisig.check_rate_limit_account(self.rate_limit.as_ref())?;
+
+        Ok(())
+    }
+
+    /// Execute consecutive transactions from the batch, as many as are passed in `remaining_accounts`.
+    #[access_control(ctx.accounts.validate())]
+    pub fn batch_execute_transactions(ctx: Context<'_, '_, 'info, 'info, Self>) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let proposal = &mut ctx.accounts.proposal;
+        let batch = &mut ctx.accounts.batch;
+
+        let multisig_key = multisig.key();
+        let proposal_key = proposal.key();
+
+        require!(
+            !ctx.remaining_accounts.is_empty(),
+            MultisigError::InvalidNumberOfAccounts
+        );
+
+        let mut executions = 0;
+        let mut vault_lamports_moved = 0u64;
+
+        let mut remaining_accounts = ctx.remaining_accounts;
+        while let Some((transaction_info, rest)) = remaining_accounts.split_first() {
+            // There must be no more transactions passed than left in the batch.
+            require!(
+                batch.executed_transaction_index < batch.size,
+                MultisigError::InvalidNumberOfAccounts
+            );
+            let transaction_index = batch.executed_transaction_index.checked_add(1).unwrap();
+
+            let transaction = Account::<VaultBatchTransaction>::try_from(transaction_info)?;
+
+            // The transaction must be the next one in the batch.
+            let transaction_key = Pubkey::create_program_address(
+                &[
+                    SEED_PREFIX,
+                    multisig_key.as_ref(),
+                    SEED_TRANSACTION,
+                    &batch.index.to_le_bytes(),
+                    SEED_BATCH_TRANSACTION,
+                    &transaction_index.to_le_bytes(),
+                    &[transaction.bump],
+                ],
+                ctx.program_id,
+            )
+            .map_err(|_| MultisigError::InvalidAccount)?;
+            require_keys_eq!(
+                transaction_key,
+                *transaction_info.key,
+                MultisigError::InvalidAccount
+            );
+
+            let num_accounts = transaction.message.address_table_lookups.len()
+                + transaction.message.num_all_account_keys();
+            let account_infos = rest
+                .get(..num_accounts)
+                .ok_or(MultisigError::InvalidNumberOfAccounts)?;
+            remaining_accounts = &rest[num_accounts..];
+
+            let transaction_vault_lamports_moved = execute_batch_transaction(
+                ctx.program_id,
+                multisig,
+                batch,
+                proposal_key,
+                ctx.accounts.rate_limit.as_ref().map(Key::key),
+                transaction.into_inner(),
+                account_infos,
+            )?;
+            executions += 1;
+            vault_lamports_moved =
+                vault_lamports_moved.saturating_add(transaction_vault_lamports_moved);
+
+            batch.executed_transaction_index = transaction_index;
+        }
+
+        // Record the executions against the rate limit of the multisig, if it has one.
+        if let Some(rate_limit) = &mut ctx.accounts.rate_limit {
+            rate_limit.check_and_record(
+                executions,
+                vault_lamports_moved,
+                Some(proposal),
+                Clock::get()?.unix_timestamp,
+            )?;
+        }
+
+        // If the last transaction in the batch has been executed, set the proposal status to `Executed`.
+        if batch.executed_transaction_index == batch.size {
+            proposal.status = ProposalStatus::Executed {
+                timestamp: Clock::get()?.unix_timestamp,
+            };
+        }
+
+        batch.invariant()?;
+
+        Ok(())
+    }
+}
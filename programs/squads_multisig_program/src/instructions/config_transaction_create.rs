@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::errors::*;
 use crate::state::*;
+use crate::utils::AccountExtension;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ConfigTransactionCreateArgs {
@@ -54,7 +55,9 @@ impl ConfigTransactionCreate<'_> {
 
         // creator
         require!(
-            self.multisig.is_member(self.creator.key()).is_some(),
+            self.multisig
+                .is_active_member(self.creator.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
             MultisigError::NotAMember
         );
         require!(
@@ -78,6 +81,19 @@ impl ConfigTransactionCreate<'_> {
             }
         }
 
+        // CloseMultisig must be the last action, as no other action can be applied after it.
+        if let Some(index) = args
+            .actions
+            .iter()
+            .position(|action| matches!(action, ConfigAction::CloseMultisig { .. }))
+        {
+            require_eq!(
+                index,
+                args.actions.len() - 1,
+                MultisigError::CloseMultisigNotLastAction
+            );
+        }
+
         Ok(())
     }
 
@@ -102,6 +118,9 @@ impl ConfigTransactionCreate<'_> {
         transaction.index = transaction_index;
         transaction.bump = ctx.bumps.transaction;
         transaction.actions = args.actions;
+        transaction.extension = AccountExtension::new(ConfigTransactionExtension {
+            rent_payer: ctx.accounts.rent_payer.key(),
+        });
 
         // Updated last transaction index in the multisig account.
         multisig.transaction_index = transaction_index;
@@ -33,7 +33,9 @@ pub struct ConfigTransactionExecute<'info> {
     pub proposal: Account<'info, Proposal>,
 
     /// The transaction to execute.
+    /// Writable because `CloseMultisig` closes it along with the multisig.
     #[account(
+        mut,
         seeds = [
             SEED_PREFIX,
             multisig.key().as_ref(),
@@ -54,6 +56,9 @@ pub struct ConfigTransactionExecute<'info> {
     pub system_program: Option<Program<'info, System>>,
     // In case the transaction contains Add(Remove)SpendingLimit actions,
     // `remaining_accounts` must contain the SpendingLimit accounts to be initialized/closed.
+    // In case the transaction contains a CloseMultisig action, `transaction` must be writable, and
+    // `remaining_accounts` must contain the rent destination, the vaults to check and any other
+    // accounts that must not belong to the multisig anymore.
     // remaining_accounts
 }
 
@@ -63,18 +68,22 @@ impl<'info> ConfigTransactionExecute<'info> {
             multisig,
             proposal,
             member,
+            transaction,
             ..
         } = self;
 
         // member
         require!(
-            multisig.is_member(member.key()).is_some(),
+            multisig
+                .is_active_member(member.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
             MultisigError::NotAMember
         );
         require!(
             multisig.member_has_permission(member.key(), Permission::Execute),
             MultisigError::Unauthorized
         );
+        multisig.check_two_person_rule(member.key(), transaction.creator, proposal)?;
 
         // proposal
         match proposal.status {
@@ -87,10 +96,7 @@ impl<'info> ConfigTransactionExecute<'info> {
             _ => return err!(MultisigError::InvalidProposalStatus),
         }
         // Stale config transaction proposals CANNOT be executed even if approved.
-        require!(
-            proposal.transaction_index > multisig.stale_transaction_index,
-            MultisigError::StaleProposal
-        );
+        require!(!proposal.is_stale(multisig), MultisigError::StaleProposal);
 
         // `transaction` is validated by its seeds.
 
@@ -212,6 +218,15 @@ impl<'info> ConfigTransactionExecute<'info> {
 
                     spending_limit
                         .try_serialize(&mut &mut spending_limit_info.data.borrow_mut()[..])?;
+
+                    multisig.extension.spending_limit_count = multisig
+                        .extension
+                        .spending_limit_count
+                        .checked_add(1)
+                        .unwrap();
+
+                    // We don't need to invalidate prior transactions here because adding
+                    // a spending limit doesn't affect the consensus parameters of the multisig.
                 }
 
                 ConfigAction::RemoveSpendingLimit {
@@ -242,7 +257,12 @@ impl<'info> ConfigTransactionExecute<'info> {
 
                     spending_limit.close(rent_payer.to_account_info())?;
 
-                    // We don't need to invalidate prior transactions here because adding
+                    // Spending limits added by the `config_authority` before the multisig
+                    // became autonomous aren't counted.
+                    multisig.extension.spending_limit_count =
+                        multisig.extension.spending_limit_count.saturating_sub(1);
+
+                    // We don't need to invalidate prior transactions here because removing
                     // a spending limit doesn't affect the consensus parameters of the multisig.
                 }
 
@@ -252,13 +272,463 @@ impl<'info> ConfigTransactionExecute<'info> {
                     // We don't need to invalidate prior transactions here because changing
                     // `rent_collector` doesn't affect the consensus parameters of the multisig.
                 }
+
+                ConfigAction::SetMemberVaultScope {
+                    member,
+                    vault_scope,
+                } => {
+                    multisig.set_member_vault_scope(*member, *vault_scope)?;
+
+                    // Vault scopes change which members can vote on the transactions of a vault.
+                    multisig.invalidate_prior_transactions();
+                }
+
+                ConfigAction::SetMemberValidUntil {
+                    member,
+                    valid_until,
+                } => {
+                    multisig.set_member_valid_until(*member, *valid_until)?;
+
+                    multisig.invalidate_prior_transactions();
+                }
+
+                ConfigAction::SetMemberPasskey { member, passkey } => {
+                    multisig
+                        .set_member_passkey(*member, passkey.map(|pubkey| Passkey { pubkey }))?;
+
+                    // The passkey changes who can vote on behalf of the member.
+                    multisig.invalidate_prior_transactions();
+                }
+
+                ConfigAction::SetProgramAllowlist {
+                    new_program_allowlist,
+                } => {
+                    multisig.set_program_allowlist(new_program_allowlist.to_owned());
+
+                    // We don't need to invalidate prior transactions here because changing
+                    // `program_allowlist` doesn't affect the consensus parameters of the multisig,
+                    // and vault transactions are checked against the allowlist at execution.
+                }
+
+                ConfigAction::SetTwoPersonRule {
+                    new_two_person_rule,
+                } => {
+                    multisig.extension.two_person_rule = *new_two_person_rule;
+
+                    // We don't need to invalidate prior transactions here because changing
+                    // `two_person_rule` doesn't affect the consensus parameters of the multisig.
+                }
+
+                ConfigAction::SetRentRefund {
+                    new_refund_rent_to_payers,
+                } => {
+                    multisig.extension.refund_rent_to_payers = *new_refund_rent_to_payers;
+
+                    // We don't need to invalidate prior transactions here because changing
+                    // `refund_rent_to_payers` doesn't affect the consensus parameters of the multisig.
+                }
+
+                ConfigAction::AddAllowance {
+                    create_key,
+                    vault_index,
+                    template,
+                    max_uses,
+                    period,
+                    expires_at,
+                    members,
+                } => {
+                    let (allowance_key, allowance_bump) = Pubkey::find_program_address(
+                        &[
+                            SEED_PREFIX,
+                            multisig.key().as_ref(),
+                            SEED_ALLOWANCE,
+                            create_key.as_ref(),
+                        ],
+                        ctx.program_id,
+                    );
+
+                    // Find the Allowance account in `remaining_accounts`.
+                    let allowance_info = ctx
+                        .remaining_accounts
+                        .iter()
+                        .find(|acc| acc.key == &allowance_key)
+                        .ok_or(MultisigError::MissingAccount)?;
+
+                    // `rent_payer` and `system_program` must also be present.
+                    let rent_payer = &ctx
+                        .accounts
+                        .rent_payer
+                        .as_ref()
+                        .ok_or(MultisigError::MissingAccount)?;
+                    let system_program = &ctx
+                        .accounts
+                        .system_program
+                        .as_ref()
+                        .ok_or(MultisigError::MissingAccount)?;
+
+                    // Initialize the Allowance account.
+                    create_account(
+                        rent_payer,
+                        allowance_info,
+                        system_program,
+                        &id(),
+                        &rent,
+                        Allowance::size(template, members.len()),
+                        vec![
+                            SEED_PREFIX.to_vec(),
+                            multisig.key().as_ref().to_vec(),
+                            SEED_ALLOWANCE.to_vec(),
+                            create_key.as_ref().to_vec(),
+                            vec![allowance_bump],
+                        ],
+                    )?;
+
+                    let mut members = members.to_vec();
+                    // Make sure members are sorted.
+                    members.sort();
+
+                    // Serialize the Allowance data into the account info.
+                    let allowance = Allowance {
+                        multisig: multisig.key().to_owned(),
+                        create_key: create_key.to_owned(),
+                        vault_index: *vault_index,
+                        template: template.to_owned(),
+                        max_uses: *max_uses,
+                        period: *period,
+                        remaining_uses: *max_uses,
+                        last_reset: Clock::get()?.unix_timestamp,
+                        expires_at: *expires_at,
+                        bump: allowance_bump,
+                        members,
+                    };
+
+                    allowance.invariant()?;
+
+                    allowance.try_serialize(&mut &mut allowance_info.data.borrow_mut()[..])?;
+
+                    multisig.extension.allowance_count =
+                        multisig.extension.allowance_count.checked_add(1).unwrap();
+
+                    // We don't need to invalidate prior transactions here because adding
+                    // an allowance doesn't affect the consensus parameters of the multisig.
+                }
+
+                ConfigAction::RemoveAllowance {
+                    allowance: allowance_key,
+                } => {
+                    // Find the Allowance account in `remaining_accounts`.
+                    let allowance_info = ctx
+                        .remaining_accounts
+                        .iter()
+                        .find(|acc| acc.key == allowance_key)
+                        .ok_or(MultisigError::MissingAccount)?;
+
+                    // `rent_payer` must also be present.
+                    let rent_payer = &ctx
+                        .accounts
+                        .rent_payer
+                        .as_ref()
+                        .ok_or(MultisigError::MissingAccount)?;
+
+                    let allowance = Account::<Allowance>::try_from(allowance_info)?;
+
+                    // Allowance must belong to the `multisig`.
+                    require_keys_eq!(
+                        allowance.multisig,
+                        multisig.key(),
+                        MultisigError::InvalidAccount
+                    );
+
+                    allowance.close(rent_payer.to_account_info())?;
+
+                    multisig.extension.allowance_count =
+                        multisig.extension.allowance_count.checked_sub(1).unwrap();
+
+                    // We don't need to invalidate prior transactions here because removing
+                    // an allowance doesn't affect the consensus parameters of the multisig.
+                }
+
+                ConfigAction::AddStream {
+                    create_key,
+                    vault_index,
+                    recipient,
+                    mint,
+                    total_amount,
+                    start_time,
+                    end_time,
+                    cliff_time,
+                } => {
+                    let (stream_key, stream_bump) = Pubkey::find_program_address(
+                        &[
+                            SEED_PREFIX,
+                            multisig.key().as_ref(),
+                            SEED_STREAM,
+                            create_key.as_ref(),
+                        ],
+                        ctx.program_id,
+                    );
+
+                    // Find the Stream account in `remaining_accounts`.
+                    let stream_info = ctx
+                        .remaining_accounts
+                        .iter()
+                        .find(|acc| acc.key == &stream_key)
+                        .ok_or(MultisigError::MissingAccount)?;
+
+                    // `rent_payer` and `system_program` must also be present.
+                    let rent_payer = &ctx
+                        .accounts
+                        .rent_payer
+                        .as_ref()
+                        .ok_or(MultisigError::MissingAccount)?;
+                    let system_program = &ctx
+                        .accounts
+                        .system_program
+                        .as_ref()
+                        .ok_or(MultisigError::MissingAccount)?;
+
+                    // Initialize the Stream account.
+                    create_account(
+                        rent_payer,
+                        stream_info,
+                        system_program,
+                        &id(),
+                        &rent,
+                        8 + Stream::INIT_SPACE,
+                        vec![
+                            SEED_PREFIX.to_vec(),
+                            multisig.key().as_ref().to_vec(),
+                            SEED_STREAM.to_vec(),
+                            create_key.as_ref().to_vec(),
+                            vec![stream_bump],
+                        ],
+                    )?;
+
+                    // Serialize the Stream data into the account info.
+                    let stream = Stream {
+                        multisig: multisig.key().to_owned(),
+                        create_key: create_key.to_owned(),
+                        vault_index: *vault_index,
+                        recipient: *recipient,
+                        mint: *mint,
+                        total_amount: *total_amount,
+                        claimed_amount: 0,
+                        start_time: *start_time,
+                        end_time: *end_time,
+                        cliff_time: *cliff_time,
+                        bump: stream_bump,
+                    };
+
+                    stream.invariant()?;
+
+                    stream.try_serialize(&mut &mut stream_info.data.borrow_mut()[..])?;
+
+                    multisig.extension.stream_count =
+                        multisig.extension.stream_count.checked_add(1).unwrap();
+
+                    // We don't need to invalidate prior transactions here because adding
+                    // a stream doesn't affect the consensus parameters of the multisig.
+                }
+
+                ConfigAction::CancelStream { stream: stream_key } => {
+                    // Find the Stream account in `remaining_accounts`.
+                    let stream_info = ctx
+                        .remaining_accounts
+                        .iter()
+                        .find(|acc| acc.key == stream_key)
+                        .ok_or(MultisigError::MissingAccount)?;
+
+                    let mut stream = Account::<Stream>::try_from(stream_info)?;
+
+                    // Stream must belong to the `multisig`.
+                    require_keys_eq!(
+                        stream.multisig,
+                        multisig.key(),
+                        MultisigError::InvalidAccount
+                    );
+
+                    // The unvested remainder is simply never paid out of the vault.
+                    // Close the stream right away if the recipient has already claimed everything vested,
+                    // otherwise keep it around until they claim the rest.
+                    if stream.cancel(Clock::get()?.unix_timestamp) {
+                        // `rent_payer` must be present to close the stream.
+                        let rent_payer = &ctx
+                            .accounts
+                            .rent_payer
+                            .as_ref()
+                            .ok_or(MultisigError::MissingAccount)?;
+
+                        stream.close(rent_payer.to_account_info())?;
+
+                        multisig.extension.stream_count =
+                            multisig.extension.stream_count.checked_sub(1).unwrap();
+                    } else {
+                        stream.invariant()?;
+                        stream.exit(ctx.program_id)?;
+                    }
+
+                    // We don't need to invalidate prior transactions here because cancelling
+                    // a stream doesn't affect the consensus parameters of the multisig.
+                }
+
+                ConfigAction::SetRateLimit {
+                    period,
+                    max_executions,
+                    max_lamports,
+                    over_limit_threshold,
+                    over_limit_time_lock,
+                } => {
+                    let (rate_limit_key, rate_limit_bump) = Pubkey::find_program_address(
+                        &[SEED_PREFIX, multisig.key().as_ref(), SEED_RATE_LIMIT],
+                        ctx.program_id,
+                    );
+
+                    // Find the RateLimit account in `remaining_accounts`.
+                    let rate_limit_info = ctx
+                        .remaining_accounts
+                        .iter()
+                        .find(|acc| acc.key == &rate_limit_key)
+                        .ok_or(MultisigError::MissingAccount)?;
+
+                    let rate_limit = match RateLimit::load(rate_limit_info)? {
+                        // Update the existing RateLimit, keeping the usage of the current period.
+                        Some(rate_limit) => {
+                            rate_limit
+                                .check_change_allowed(proposal, Clock::get()?.unix_timestamp)?;
+
+                            RateLimit {
+                                period: *period,
+                                max_executions: *max_executions,
+                                max_lamports: *max_lamports,
+                                over_limit_threshold: *over_limit_threshold,
+                                over_limit_time_lock: *over_limit_time_lock,
+                                ..rate_limit
+                            }
+                        }
+                        None => {
+                            // `rent_payer` and `system_program` must also be present.
+                            let rent_payer = &ctx
+                                .accounts
+                                .rent_payer
+                                .as_ref()
+                                .ok_or(MultisigError::MissingAccount)?;
+                            let system_program = &ctx
+                                .accounts
+                                .system_program
+                                .as_ref()
+                                .ok_or(MultisigError::MissingAccount)?;
+
+                            // Initialize the RateLimit account.
+                            create_account(
+                                rent_payer,
+                                rate_limit_info,
+                                system_program,
+                                &id(),
+                                &rent,
+                                8 + RateLimit::INIT_SPACE,
+                                vec![
+                                    SEED_PREFIX.to_vec(),
+                                    multisig.key().as_ref().to_vec(),
+                                    SEED_RATE_LIMIT.to_vec(),
+                                    vec![rate_limit_bump],
+                                ],
+                            )?;
+
+                            RateLimit {
+                                multisig: multisig.key(),
+                                period: *period,
+                                max_executions: *max_executions,
+                                max_lamports: *max_lamports,
+                                over_limit_threshold: *over_limit_threshold,
+                                over_limit_time_lock: *over_limit_time_lock,
+                                period_start: Clock::get()?.unix_timestamp,
+                                executions: 0,
+                                lamports: 0,
+                                previous_executions: 0,
+                                previous_lamports: 0,
+                                bump: rate_limit_bump,
+                            }
+                        }
+                    };
+
+                    rate_limit.invariant()?;
+
+                    rate_limit.save(rate_limit_info)?;
+
+                    multisig.extension.has_rate_limit = true;
+
+                    // We don't need to invalidate prior transactions here because the rate limit
+                    // doesn't affect the consensus parameters of the multisig.
+                }
+
+                ConfigAction::RemoveRateLimit => {
+                    let (rate_limit_key, _) = Pubkey::find_program_address(
+                        &[SEED_PREFIX, multisig.key().as_ref(), SEED_RATE_LIMIT],
+                        ctx.program_id,
+                    );
+
+                    // Find the RateLimit account in `remaining_accounts`.
+                    let rate_limit_info = ctx
+                        .remaining_accounts
+                        .iter()
+                        .find(|acc| acc.key == &rate_limit_key)
+                        .ok_or(MultisigError::MissingAccount)?;
+
+                    // `rent_payer` must also be present.
+                    let rent_payer = &ctx
+                        .accounts
+                        .rent_payer
+                        .as_ref()
+                        .ok_or(MultisigError::MissingAccount)?;
+
+                    let rate_limit = Account::<RateLimit>::try_from(rate_limit_info)?;
+
+                    rate_limit.check_change_allowed(proposal, Clock::get()?.unix_timestamp)?;
+
+                    rate_limit.close(rent_payer.to_account_info())?;
+
+                    multisig.extension.has_rate_limit = false;
+
+                    // We don't need to invalidate prior transactions here because the rate limit
+                    // doesn't affect the consensus parameters of the multisig.
+                }
+
+                ConfigAction::CloseMultisig {
+                    rent_destination,
+                    vault_indices,
+                } => {
+                    let rent_destination_info = ctx
+                        .remaining_accounts
+                        .iter()
+                        .find(|acc| acc.key == rent_destination)
+                        .ok_or(MultisigError::MissingAccount)?;
+
+                    // The transaction and its proposal couldn't be closed once the multisig is gone,
+                    // so we close them as well.
+                    let transaction_info = transaction.to_account_info();
+                    let proposal_info = proposal.to_account_info();
+                    close(proposal_info.clone(), rent_destination_info.clone())?;
+                    close(transaction_info.clone(), rent_destination_info.clone())?;
+
+                    let accounts = ctx
+                        .remaining_accounts
+                        .iter()
+                        .cloned()
+                        .chain([transaction_info, proposal_info])
+                        .collect::<Vec<_>>();
+
+                    // CloseMultisig is always the last action, so there's nothing left to do.
+                    return Multisig::close(multisig, vault_indices, rent_destination, &accounts);
+                }
             }
         }
 
-        // Make sure the multisig account can fit the updated state: added members or newly set rent_collector.
+        // Make sure the multisig account can fit the updated state: added members, newly set rent_collector
+        // or program_allowlist.
         Multisig::realloc_if_needed(
             multisig.to_account_info(),
             multisig.members.len(),
+            &multisig.extension,
             ctx.accounts
                 .rent_payer
                 .as_ref()
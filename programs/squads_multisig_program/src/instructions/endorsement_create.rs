@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::instructions::Vote;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EndorsementCreateArgs {
+    /// Index of the vault that is the member of the parent multisig.
+    pub vault_index: u8,
+    /// The parent multisig to vote in.
+    pub parent_multisig: Pubkey,
+    /// Index of the parent multisig transaction whose proposal to vote on.
+    pub parent_transaction_index: u64,
+    /// The vote to cast on the parent proposal.
+    pub vote: Vote,
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct EndorsementCreate<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = 8 + Endorsement::INIT_SPACE,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &multisig.transaction_index.checked_add(1).unwrap().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+
+    /// The member of the multisig that is creating the endorsement.
+    pub creator: Signer<'info>,
+
+    /// The payer for the endorsement account rent.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl EndorsementCreate<'_> {
+    fn validate(&self, args: &EndorsementCreateArgs) -> Result<()> {
+        let Self {
+            multisig, creator, ..
+        } = self;
+
+        // creator
+        require!(
+            multisig
+                .is_active_member(creator.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
+            MultisigError::NotAMember
+        );
+        require!(
+            multisig.member_has_permission(creator.key(), Permission::Initiate),
+            MultisigError::Unauthorized
+        );
+        require!(
+            multisig.member_has_vault_permission(
+                creator.key(),
+                Permission::Initiate,
+                args.vault_index
+            ),
+            MultisigError::VaultOutOfScope
+        );
+
+        // parent_multisig
+        require_keys_neq!(
+            args.parent_multisig,
+            multisig.key(),
+            MultisigError::EndorsementInvalidParent
+        );
+
+        Ok(())
+    }
+
+    /// Create a new endorsement: a transaction that, once approved and executed,
+    /// casts the vote of a vault of the multisig on a proposal of the parent multisig it's a member of.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn endorsement_create(ctx: Context<Self>, args: EndorsementCreateArgs) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        let endorsement = &mut ctx.accounts.endorsement;
+
+        let multisig_key = multisig.key();
+
+        let vault_seeds = &[
+            SEED_PREFIX,
+            multisig_key.as_ref(),
+            SEED_VAULT,
+            &args.vault_index.to_le_bytes(),
+        ];
+        let (_, vault_bump) = Pubkey::find_program_address(vault_seeds, ctx.program_id);
+
+        // Increment the transaction index.
+        let transaction_index = multisig.transaction_index.checked_add(1).unwrap();
+
+        // Initialize the endorsement fields.
+        endorsement.multisig = multisig_key;
+        endorsement.creator = ctx.accounts.creator.key();
+        endorsement.index = transaction_index;
+        endorsement.bump = ctx.bumps.endorsement;
+        endorsement.vault_index = args.vault_index;
+        endorsement.vault_bump = vault_bump;
+        endorsement.parent_multisig = args.parent_multisig;
+        endorsement.parent_transaction_index = args.parent_transaction_index;
+        endorsement.vote = args.vote;
+        endorsement.rent_payer = ctx.accounts.rent_payer.key();
+
+        // Updated last transaction index in the multisig account.
+        multisig.transaction_index = transaction_index;
+
+        multisig.invariant()?;
+
+        // Logs for indexing.
+        msg!("transaction index: {}", transaction_index);
+
+        Ok(())
+    }
+}
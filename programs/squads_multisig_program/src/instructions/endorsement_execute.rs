@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::instructions::{cast_vote, validate_vote};
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct EndorsementExecute<'info> {
+    /// The multisig the endorsement belongs to.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    /// The proposal account associated with the endorsement.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &endorsement.index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// The endorsement to execute.
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &endorsement.index.to_le_bytes(),
+        ],
+        bump = endorsement.bump,
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+
+    /// Member of the multisig. Pays for the reallocation of the `parent_proposal` if needed.
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    /// The parent multisig the vault of the endorsement is a member of.
+    #[account(
+        address = endorsement.parent_multisig @ MultisigError::InvalidAccount,
+        seeds = [SEED_PREFIX, SEED_MULTISIG, parent_multisig.create_key.as_ref()],
+        bump = parent_multisig.bump,
+    )]
+    pub parent_multisig: Box<Account<'info, Multisig>>,
+
+    /// The parent multisig proposal to vote on.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            parent_multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &endorsement.parent_transaction_index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = parent_proposal.bump,
+    )]
+    pub parent_proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The transaction the `parent_proposal` is for.
+    /// Required if the `Vote` permission of any member of the parent multisig is scoped to particular vaults.
+    /// CHECK: validated in `transaction_vault_index()`.
+    pub parent_transaction: Option<AccountInfo<'info>>,
+}
+
+impl EndorsementExecute<'_> {
+    fn validate(&self, program_id: &Pubkey) -> Result<()> {
+        let Self {
+            multisig,
+            proposal,
+            endorsement,
+            member,
+            parent_multisig,
+            parent_proposal,
+            parent_transaction,
+            ..
+        } = self;
+
+        // `member`
+        require!(
+            multisig
+                .is_active_member(member.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
+            MultisigError::NotAMember
+        );
+        require!(
+            multisig.member_has_permission(member.key(), Permission::Execute),
+            MultisigError::Unauthorized
+        );
+        require!(
+            multisig.member_has_vault_permission(
+                member.key(),
+                Permission::Execute,
+                endorsement.vault_index
+            ),
+            MultisigError::VaultOutOfScope
+        );
+        multisig.check_two_person_rule(member.key(), endorsement.creator, proposal)?;
+
+        // `proposal`
+        match proposal.status {
+            ProposalStatus::Approved { timestamp } => {
+                require!(
+                    Clock::get()?.unix_timestamp - timestamp >= i64::from(multisig.time_lock),
+                    MultisigError::TimeLockNotReleased
+                );
+            }
+            _ => return err!(MultisigError::InvalidProposalStatus),
+        };
+        // Stale endorsement proposals CAN be executed if they were approved
+        // before becoming stale, hence no check for staleness here.
+
+        // `endorsement` is validated by its seeds.
+
+        // `parent_proposal`: the vault must be able to cast the vote on it.
+        validate_vote(
+            parent_multisig,
+            endorsement_vault(program_id, multisig.key(), endorsement)?,
+            parent_proposal,
+            parent_transaction.as_ref(),
+            endorsement.vote,
+        )
+    }
+
+    /// Execute an endorsement, casting the vote of its vault on the parent multisig proposal.
+    /// The endorsement must be `Approved`.
+    #[access_control(ctx.accounts.validate(ctx.program_id))]
+    pub fn endorsement_execute(ctx: Context<Self>) -> Result<()> {
+        let vault = endorsement_vault(
+            ctx.program_id,
+            ctx.accounts.multisig.key(),
+            &ctx.accounts.endorsement,
+        )?;
+        let parent_multisig = &ctx.accounts.parent_multisig;
+        let parent_proposal = &mut ctx.accounts.parent_proposal;
+
+        cast_vote(
+            parent_multisig,
+            parent_proposal,
+            ctx.accounts.parent_transaction.as_ref(),
+            vault,
+            ctx.accounts.endorsement.vote,
+        )?;
+
+        // Reallocate the parent proposal size if needed.
+        Proposal::realloc_if_needed(
+            parent_proposal.to_account_info(),
+            parent_multisig.members.len(),
+            Some(ctx.accounts.member.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        // Mark the endorsement proposal as executed.
+        ctx.accounts.proposal.status = ProposalStatus::Executed {
+            timestamp: Clock::get()?.unix_timestamp,
+        };
+
+        Ok(())
+    }
+}
+
+/// Returns the address of the vault the `endorsement` votes with.
+fn endorsement_vault(
+    program_id: &Pubkey,
+    multisig_key: Pubkey,
+    endorsement: &Endorsement,
+) -> Result<Pubkey> {
+    Pubkey::create_program_address(
+        &[
+            SEED_PREFIX,
+            multisig_key.as_ref(),
+            SEED_VAULT,
+            &endorsement.vault_index.to_le_bytes(),
+            &[endorsement.vault_bump],
+        ],
+        program_id,
+    )
+    .map_err(|_| MultisigError::InvalidAccount.into())
+}
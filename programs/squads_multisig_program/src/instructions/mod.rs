@@ -1,45 +1,81 @@
+pub use allowance_use::*;
 pub use batch_add_transaction::*;
+pub use batch_add_transaction_from_buffer::*;
 pub use batch_create::*;
 pub use batch_execute_transaction::*;
+pub use batch_execute_transactions::*;
 pub use config_transaction_create::*;
 pub use config_transaction_execute::*;
+pub use endorsement_create::*;
+pub use endorsement_execute::*;
+pub use multisig_add_allowance::*;
 pub use multisig_add_spending_limit::*;
 pub use multisig_config::*;
 pub use multisig_create::*;
+pub use multisig_record_closed_transactions::*;
+pub use multisig_remove_allowance::*;
+pub use multisig_remove_expired_members::*;
+pub use multisig_remove_rate_limit::*;
 pub use multisig_remove_spending_limit::*;
+pub use multisig_set_rate_limit::*;
 pub use program_config::*;
 pub use program_config_init::*;
 pub use proposal_activate::*;
 pub use proposal_create::*;
+pub use proposal_rebase::*;
 pub use proposal_vote::*;
+pub use proposal_vote_passkey::*;
+pub use session_key_create::*;
+pub use session_key_revoke::*;
 pub use spending_limit_use::*;
+pub use stream_claim::*;
 pub use transaction_accounts_close::*;
+pub use transaction_buffer_add_co_writers::*;
 pub use transaction_buffer_close::*;
 pub use transaction_buffer_create::*;
 pub use transaction_buffer_extend::*;
 pub use vault_transaction_create::*;
 pub use vault_transaction_create_from_buffer::*;
 pub use vault_transaction_execute::*;
+pub use vault_transaction_extend_from_buffer::*;
 
+mod allowance_use;
 mod batch_add_transaction;
+mod batch_add_transaction_from_buffer;
 mod batch_create;
 mod batch_execute_transaction;
+mod batch_execute_transactions;
 mod config_transaction_create;
 mod config_transaction_execute;
+mod endorsement_create;
+mod endorsement_execute;
+mod multisig_add_allowance;
 mod multisig_add_spending_limit;
 mod multisig_config;
 mod multisig_create;
+mod multisig_record_closed_transactions;
+mod multisig_remove_allowance;
+mod multisig_remove_expired_members;
+mod multisig_remove_rate_limit;
 mod multisig_remove_spending_limit;
+mod multisig_set_rate_limit;
 mod program_config;
 mod program_config_init;
 mod proposal_activate;
 mod proposal_create;
+mod proposal_rebase;
 mod proposal_vote;
+mod proposal_vote_passkey;
+mod session_key_create;
+mod session_key_revoke;
 mod spending_limit_use;
+mod stream_claim;
 mod transaction_accounts_close;
+mod transaction_buffer_add_co_writers;
 mod transaction_buffer_close;
 mod transaction_buffer_create;
 mod transaction_buffer_extend;
 mod vault_transaction_create;
 mod vault_transaction_create_from_buffer;
 mod vault_transaction_execute;
+mod vault_transaction_extend_from_buffer;
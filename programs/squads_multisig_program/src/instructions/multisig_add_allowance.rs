@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultisigAddAllowanceArgs {
+    /// Key that is used to seed the Allowance PDA.
+    pub create_key: Pubkey,
+    /// The index of the vault that the allowance is for.
+    pub vault_index: u8,
+    /// The template the executed instructions must match.
+    pub template: InstructionTemplate,
+    /// The number of times the allowance can be used in a period.
+    pub max_uses: u32,
+    /// The reset period of the allowance.
+    /// When it passes, the remaining uses are reset, unless it's `Period::OneTime`.
+    pub period: Period,
+    /// Unix timestamp after which the allowance can no longer be used.
+    pub expires_at: Option<i64>,
+    /// Members of the Allowance that can use it.
+    /// These can be any pubkey capable of signing and are NOT tied to multisig
+    /// membership; this list is managed independently of the multisig's members.
+    pub members: Vec<Pubkey>,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: MultisigAddAllowanceArgs)]
+pub struct MultisigAddAllowance<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    multisig: Account<'info, Multisig>,
+
+    /// Multisig `config_authority` that must authorize the configuration change.
+    pub config_authority: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_ALLOWANCE,
+            args.create_key.as_ref(),
+        ],
+        bump,
+        space = Allowance::size(&args.template, args.members.len()),
+        payer = rent_payer
+    )]
+    pub allowance: Account<'info, Allowance>,
+
+    /// This is usually the same as `config_authority`, but can be a different account if needed.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl MultisigAddAllowance<'_> {
+    fn validate(&self) -> Result<()> {
+        // config_authority
+        require_keys_eq!(
+            self.config_authority.key(),
+            self.multisig.config_authority,
+            MultisigError::Unauthorized
+        );
+
+        // `allowance` is partially checked via its seeds.
+
+        Ok(())
+    }
+
+    /// Create a new allowance for the controlled multisig.
+    /// NOTE: This instruction must be called only by the `config_authority` if one is set (Controlled Multisig).
+    ///       Uncontrolled Mustisigs should use `config_transaction_create` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_add_allowance(
+        ctx: Context<Self>,
+        args: MultisigAddAllowanceArgs,
+    ) -> Result<()> {
+        let allowance = &mut ctx.accounts.allowance;
+
+        // Make sure there are no duplicate keys in this direct invocation by sorting so the invariant will catch
+        let mut sorted_members = args.members;
+        sorted_members.sort();
+
+        allowance.multisig = ctx.accounts.multisig.key();
+        allowance.create_key = args.create_key;
+        allowance.vault_index = args.vault_index;
+        allowance.template = args.template;
+        allowance.max_uses = args.max_uses;
+        allowance.period = args.period;
+        allowance.remaining_uses = args.max_uses;
+        allowance.last_reset = Clock::get()?.unix_timestamp;
+        allowance.expires_at = args.expires_at;
+        allowance.bump = ctx.bumps.allowance;
+        allowance.members = sorted_members;
+
+        allowance.invariant()?;
+
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.extension.allowance_count =
+            multisig.extension.allowance_count.checked_add(1).unwrap();
+
+        // Make sure the multisig account can fit the extension.
+        Multisig::realloc_if_needed(
+            multisig.to_account_info(),
+            multisig.members.len(),
+            &multisig.extension,
+            Some(ctx.accounts.rent_payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        multisig.invariant()
+    }
+}
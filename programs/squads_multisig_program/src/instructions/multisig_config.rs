@@ -45,6 +45,67 @@ pub struct MultisigSetRentCollectorArgs {
     pub memo: Option<String>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultisigSetMemberVaultScopeArgs {
+    pub member: Pubkey,
+    /// `None` lifts the vault restrictions of the `member`.
+    pub vault_scope: Option<VaultScope>,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultisigSetMemberValidUntilArgs {
+    pub member: Pubkey,
+    /// Unix timestamp after which the `member` is treated as a non-member.
+    /// `None` makes the membership permanent.
+    pub valid_until: Option<i64>,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultisigSetMemberPasskeyArgs {
+    /// The member voting with the passkey, must be the address derived from the passkey.
+    pub member: Pubkey,
+    /// Compressed secp256r1 public key of the passkey.
+    /// `None` removes the passkey of the `member`.
+    pub passkey: Option<[u8; 33]>,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultisigSetProgramAllowlistArgs {
+    pub program_allowlist: Option<Vec<AllowedProgram>>,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultisigSetTwoPersonRuleArgs {
+    pub two_person_rule: TwoPersonRule,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultisigSetRentRefundArgs {
+    pub refund_rent_to_payers: bool,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultisigCloseArgs {
+    /// The account to send the rent to.
+    pub rent_destination: Pubkey,
+    /// The vaults that must be empty for the multisig to be closed.
+    pub vault_indices: Vec<u8>,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
 #[derive(Accounts)]
 pub struct MultisigConfig<'info> {
     #[account(
@@ -100,6 +161,7 @@ impl MultisigConfig<'_> {
         Multisig::realloc_if_needed(
             multisig.to_account_info(),
             multisig.members.len(),
+            &multisig.extension,
             ctx.accounts
                 .rent_payer
                 .as_ref()
@@ -216,6 +278,7 @@ impl MultisigConfig<'_> {
         Multisig::realloc_if_needed(
             multisig.to_account_info(),
             multisig.members.len(),
+            &multisig.extension,
             ctx.accounts
                 .rent_payer
                 .as_ref()
@@ -233,4 +296,238 @@ impl MultisigConfig<'_> {
 
         Ok(())
     }
+    /// Restrict the permissions of a member to a subset of the vaults, or lift the restriction.
+    ///
+    /// NOTE: This instruction must be called only by the `config_authority` if one is set (Controlled Multisig).
+    ///       Uncontrolled Mustisigs should use `config_transaction_create` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_set_member_vault_scope(
+        ctx: Context<Self>,
+        args: MultisigSetMemberVaultScopeArgs,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+
+        multisig.set_member_vault_scope(args.member, args.vault_scope)?;
+
+        multisig.invalidate_prior_transactions();
+
+        // Make sure the multisig account can fit the member settings.
+        Multisig::realloc_if_needed(
+            multisig.to_account_info(),
+            multisig.members.len(),
+            &multisig.extension,
+            ctx.accounts
+                .rent_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        multisig.invariant()?;
+
+        Ok(())
+    }
+
+    /// Set the timestamp after which a member is treated as a non-member, or make the membership permanent.
+    ///
+    /// NOTE: This instruction must be called only by the `config_authority` if one is set (Controlled Multisig).
+    ///       Uncontrolled Mustisigs should use `config_transaction_create` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_set_member_valid_until(
+        ctx: Context<Self>,
+        args: MultisigSetMemberValidUntilArgs,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+
+        multisig.set_member_valid_until(args.member, args.valid_until)?;
+
+        multisig.invalidate_prior_transactions();
+
+        // Make sure the multisig account can fit the member settings.
+        Multisig::realloc_if_needed(
+            multisig.to_account_info(),
+            multisig.members.len(),
+            &multisig.extension,
+            ctx.accounts
+                .rent_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        multisig.invariant()?;
+
+        Ok(())
+    }
+
+    /// Set the passkey a member votes with, or remove it.
+    ///
+    /// NOTE: This instruction must be called only by the `config_authority` if one is set (Controlled Multisig).
+    ///       Uncontrolled Mustisigs should use `config_transaction_create` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_set_member_passkey(
+        ctx: Context<Self>,
+        args: MultisigSetMemberPasskeyArgs,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+
+        multisig.set_member_passkey(args.member, args.passkey.map(|pubkey| Passkey { pubkey }))?;
+
+        multisig.invalidate_prior_transactions();
+
+        // Make sure the multisig account can fit the member settings.
+        Multisig::realloc_if_needed(
+            multisig.to_account_info(),
+            multisig.members.len(),
+            &multisig.extension,
+            ctx.accounts
+                .rent_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        multisig.invariant()?;
+
+        Ok(())
+    }
+    /// Set the multisig `program_allowlist` and reallocate space if necessary.
+    ///
+    /// NOTE: This instruction must be called only by the `config_authority` if one is set (Controlled Multisig).
+    ///       Uncontrolled Mustisigs should use `config_transaction_create` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_set_program_allowlist(
+        ctx: Context<Self>,
+        args: MultisigSetProgramAllowlistArgs,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+
+        multisig.set_program_allowlist(args.program_allowlist);
+
+        // Make sure the multisig account can fit the newly set program_allowlist.
+        Multisig::realloc_if_needed(
+            multisig.to_account_info(),
+            multisig.members.len(),
+            &multisig.extension,
+            ctx.accounts
+                .rent_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        // We don't need to invalidate prior transactions here because changing
+        // `program_allowlist` doesn't affect the consensus parameters of the multisig.
+
+        multisig.invariant()?;
+
+        Ok(())
+    }
+
+    /// Set the multisig `two_person_rule`.
+    ///
+    /// NOTE: This instruction must be called only by the `config_authority` if one is set (Controlled Multisig).
+    ///       Uncontrolled Mustisigs should use `config_transaction_create` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_set_two_person_rule(
+        ctx: Context<Self>,
+        args: MultisigSetTwoPersonRuleArgs,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+
+        multisig.extension.two_person_rule = args.two_person_rule;
+
+        // Make sure the multisig account can fit the extension, if it's the first time it's set.
+        Multisig::realloc_if_needed(
+            multisig.to_account_info(),
+            multisig.members.len(),
+            &multisig.extension,
+            ctx.accounts
+                .rent_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        // We don't need to invalidate prior transactions here because changing
+        // `two_person_rule` doesn't affect the consensus parameters of the multisig.
+
+        multisig.invariant()?;
+
+        Ok(())
+    }
+
+    /// Set whether the rent of closed transaction accounts is refunded to the accounts that paid it
+    /// when the multisig has no `rent_collector`.
+    ///
+    /// NOTE: This instruction must be called only by the `config_authority` if one is set (Controlled Multisig).
+    ///       Uncontrolled Mustisigs should use `config_transaction_create` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_set_rent_refund(
+        ctx: Context<Self>,
+        args: MultisigSetRentRefundArgs,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+
+        multisig.extension.refund_rent_to_payers = args.refund_rent_to_payers;
+
+        // Make sure the multisig account can fit the extension, if it's the first time it's set.
+        Multisig::realloc_if_needed(
+            multisig.to_account_info(),
+            multisig.members.len(),
+            &multisig.extension,
+            ctx.accounts
+                .rent_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        // We don't need to invalidate prior transactions here because changing
+        // `refund_rent_to_payers` doesn't affect the consensus parameters of the multisig.
+
+        multisig.invariant()?;
+
+        Ok(())
+    }
+}
+
+impl<'info> MultisigConfig<'info> {
+    /// Close the multisig account and send its rent to `rent_destination`, leaving a tombstone in its place.
+    /// All the transactions must be closed, the vaults at `vault_indices` must be empty,
+    /// the rate limit, allowances and streams must be removed,
+    /// and none of `remaining_accounts` can be an open account of the multisig.
+    ///
+    /// NOTE: This instruction must be called only by the `config_authority` if one is set (Controlled Multisig).
+    ///       Uncontrolled Mustisigs should use `config_transaction_create` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_close(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: MultisigCloseArgs,
+    ) -> Result<()> {
+        Multisig::close(
+            &mut ctx.accounts.multisig,
+            &args.vault_indices,
+            &args.rent_destination,
+            ctx.remaining_accounts,
+        )
+    }
 }
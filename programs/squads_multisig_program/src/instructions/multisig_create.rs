@@ -5,6 +5,7 @@ use solana_program::native_token::LAMPORTS_PER_SOL;
 
 use crate::errors::MultisigError;
 use crate::state::*;
+use crate::utils::AccountExtension;
 
 // Dummy Account context for multisigCreate, since Anchor doesn't allow empty instructions.
 #[derive(Accounts)]
@@ -46,7 +47,7 @@ pub struct MultisigCreateV2<'info> {
     #[account(
         init,
         payer = creator,
-        space = Multisig::size(args.members.len()),
+        space = Multisig::size(args.members.len(), &AccountExtension::default()),
         seeds = [SEED_PREFIX, SEED_MULTISIG, create_key.key().as_ref()],
         bump
     )]
@@ -94,6 +95,7 @@ impl MultisigCreateV2<'_> {
         multisig.bump = ctx.bumps.multisig;
         multisig.members = members;
         multisig.rent_collector = args.rent_collector;
+        multisig.extension = AccountExtension::default();
 
         multisig.invariant()?;
 
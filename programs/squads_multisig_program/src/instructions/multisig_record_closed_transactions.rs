@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct MultisigRecordClosedTransactions<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// The account that will be charged in case the multisig account needs to reallocate space
+    /// to store the closed transaction index.
+    #[account(mut)]
+    pub rent_payer: Option<Signer<'info>>,
+
+    /// We might need it in case reallocation is needed.
+    pub system_program: Option<Program<'info, System>>,
+    // `remaining_accounts` must include the transaction and proposal accounts
+    // of the transactions following `multisig.extension.closed_transaction_index`.
+}
+
+impl MultisigRecordClosedTransactions<'_> {
+    fn validate(&self) -> Result<()> {
+        require!(!self.multisig.is_closed(), MultisigError::MultisigClosed);
+
+        Ok(())
+    }
+
+    /// Record the transactions whose accounts have all been closed,
+    /// so the multisig can be closed without passing them again.
+    /// This instruction is permissionless, as it only records the state of the closed accounts.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_record_closed_transactions(ctx: Context<Self>) -> Result<()> {
+        let multisig_key = ctx.accounts.multisig.key();
+        let multisig = &mut ctx.accounts.multisig;
+
+        multisig.record_closed_transactions(&multisig_key, ctx.remaining_accounts);
+
+        Multisig::realloc_if_needed(
+            multisig.to_account_info(),
+            multisig.members.len(),
+            &multisig.extension,
+            ctx.accounts
+                .rent_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        multisig.invariant()?;
+
+        // Logs for indexing.
+        msg!(
+            "closed transaction index: {}",
+            multisig.extension.closed_transaction_index
+        );
+
+        Ok(())
+    }
+}
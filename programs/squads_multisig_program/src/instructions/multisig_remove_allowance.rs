@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultisigRemoveAllowanceArgs {
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct MultisigRemoveAllowance<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    multisig: Account<'info, Multisig>,
+
+    /// Multisig `config_authority` that must authorize the configuration change.
+    pub config_authority: Signer<'info>,
+
+    #[account(mut, close = rent_collector)]
+    pub allowance: Account<'info, Allowance>,
+
+    /// This is usually the same as `config_authority`, but can be a different account if needed.
+    /// CHECK: can be any account.
+    #[account(mut)]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+impl MultisigRemoveAllowance<'_> {
+    fn validate(&self) -> Result<()> {
+        // config_authority
+        require_keys_eq!(
+            self.config_authority.key(),
+            self.multisig.config_authority,
+            MultisigError::Unauthorized
+        );
+
+        // `allowance`
+        require_keys_eq!(
+            self.allowance.multisig,
+            self.multisig.key(),
+            MultisigError::InvalidAccount
+        );
+
+        Ok(())
+    }
+
+    /// Remove the allowance from the controlled multisig.
+    /// NOTE: This instruction must be called only by the `config_authority` if one is set (Controlled Multisig).
+    ///       Uncontrolled Mustisigs should use `config_transaction_create` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_remove_allowance(
+        ctx: Context<Self>,
+        _args: MultisigRemoveAllowanceArgs,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        multisig.extension.allowance_count =
+            multisig.extension.allowance_count.checked_sub(1).unwrap();
+
+        multisig.invariant()
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct MultisigRemoveExpiredMembers<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+}
+
+impl MultisigRemoveExpiredMembers<'_> {
+    /// Remove the members whose `valid_until` timestamp has passed.
+    /// This instruction is permissionless, as expired members are already treated as non-members
+    /// by the rest of the program, and removing them only brings the state in line with that.
+    /// For the same reason, prior transactions are not invalidated: the expired members could no
+    /// longer vote on them anyway.
+    pub fn multisig_remove_expired_members(ctx: Context<Self>) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+
+        let removed = multisig.remove_expired_members(Clock::get()?.unix_timestamp);
+
+        multisig.invariant()?;
+
+        // Logs for indexing.
+        msg!("removed members: {}", removed);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultisigRemoveRateLimitArgs {
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct MultisigRemoveRateLimit<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    multisig: Account<'info, Multisig>,
+
+    /// Multisig `config_authority` that must authorize the configuration change.
+    pub config_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = rent_collector,
+        seeds = [SEED_PREFIX, multisig.key().as_ref(), SEED_RATE_LIMIT],
+        bump = rate_limit.bump,
+    )]
+    pub rate_limit: Account<'info, RateLimit>,
+
+    /// This is usually the same as `config_authority`, but can be a different account if needed.
+    /// CHECK: can be any account.
+    #[account(mut)]
+    pub rent_collector: AccountInfo<'info>,
+}
+
+impl MultisigRemoveRateLimit<'_> {
+    fn validate(&self) -> Result<()> {
+        // config_authority
+        require_keys_eq!(
+            self.config_authority.key(),
+            self.multisig.config_authority,
+            MultisigError::Unauthorized
+        );
+
+        // `rate_limit` is checked via its seeds.
+
+        Ok(())
+    }
+
+    /// Remove the rate limit of the controlled multisig.
+    /// NOTE: This instruction must be called only by the `config_authority` if one is set (Controlled Multisig).
+    ///       Uncontrolled Mustisigs should use `config_transaction_create` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_remove_rate_limit(
+        ctx: Context<Self>,
+        _args: MultisigRemoveRateLimitArgs,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+
+        multisig.extension.has_rate_limit = false;
+
+        multisig.invariant()
+    }
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::id;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MultisigSetRateLimitArgs {
+    /// Length of the rate limit period in seconds.
+    pub period: i64,
+    /// The number of vault transactions that can be executed in a period.
+    pub max_executions: Option<u32>,
+    /// The amount of lamports that can be moved out of the vaults in a period.
+    /// Only the native lamports of the vaults are counted, not their token balances.
+    pub max_lamports: Option<u64>,
+    /// The number of approvals a proposal needs to be executed over the limits.
+    pub over_limit_threshold: Option<u16>,
+    /// How many seconds must pass between the approval of a proposal and its execution over the limits.
+    pub over_limit_time_lock: Option<u32>,
+    /// Memo is used for indexing only.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct MultisigSetRateLimit<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    multisig: Account<'info, Multisig>,
+
+    /// Multisig `config_authority` that must authorize the configuration change.
+    pub config_authority: Signer<'info>,
+
+    /// CHECK: validated by its seeds, initialized here if the multisig has no rate limit yet.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, multisig.key().as_ref(), SEED_RATE_LIMIT],
+        bump,
+    )]
+    pub rate_limit: AccountInfo<'info>,
+
+    /// This is usually the same as `config_authority`, but can be a different account if needed.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl MultisigSetRateLimit<'_> {
+    fn validate(&self) -> Result<()> {
+        // config_authority
+        require_keys_eq!(
+            self.config_authority.key(),
+            self.multisig.config_authority,
+            MultisigError::Unauthorized
+        );
+
+        // `rate_limit` is checked via its seeds.
+
+        Ok(())
+    }
+
+    /// Set the rate limit of the controlled multisig, creating the rate limit account if it doesn't exist.
+    /// NOTE: This instruction must be called only by the `config_authority` if one is set (Controlled Multisig).
+    ///       Uncontrolled Mustisigs should use `config_transaction_create` instead.
+    #[access_control(ctx.accounts.validate())]
+    pub fn multisig_set_rate_limit(
+        ctx: Context<Self>,
+        args: MultisigSetRateLimitArgs,
+    ) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        let rate_limit_info = &ctx.accounts.rate_limit;
+
+        let rate_limit = match RateLimit::load(rate_limit_info)? {
+            // Update the existing RateLimit, keeping the usage of the current period.
+            Some(rate_limit) => RateLimit {
+                period: args.period,
+                max_executions: args.max_executions,
+                max_lamports: args.max_lamports,
+                over_limit_threshold: args.over_limit_threshold,
+                over_limit_time_lock: args.over_limit_time_lock,
+                ..rate_limit
+            },
+            None => {
+                create_account(
+                    &ctx.accounts.rent_payer,
+                    rate_limit_info,
+                    &ctx.accounts.system_program,
+                    &id(),
+                    &Rent::get()?,
+                    8 + RateLimit::INIT_SPACE,
+                    vec![
+                        SEED_PREFIX.to_vec(),
+                        multisig.key().as_ref().to_vec(),
+                        SEED_RATE_LIMIT.to_vec(),
+                        vec![ctx.bumps.rate_limit],
+                    ],
+                )?;
+
+                RateLimit {
+                    multisig: multisig.key(),
+                    period: args.period,
+                    max_executions: args.max_executions,
+                    max_lamports: args.max_lamports,
+                    over_limit_threshold: args.over_limit_threshold,
+                    over_limit_time_lock: args.over_limit_time_lock,
+                    period_start: Clock::get()?.unix_timestamp,
+                    executions: 0,
+                    lamports: 0,
+                    previous_executions: 0,
+                    previous_lamports: 0,
+                    bump: ctx.bumps.rate_limit,
+                }
+            }
+        };
+
+        rate_limit.invariant()?;
+
+        rate_limit.save(rate_limit_info)?;
+
+        multisig.extension.has_rate_limit = true;
+
+        // Make sure the multisig account can fit the extension.
+        Multisig::realloc_if_needed(
+            multisig.to_account_info(),
+            multisig.members.len(),
+            &multisig.extension,
+            Some(ctx.accounts.rent_payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        multisig.invariant()
+    }
+}
@@ -39,7 +39,9 @@ impl ProposalActivate<'_> {
 
         // `member`
         require!(
-            multisig.is_member(member.key()).is_some(),
+            multisig
+                .is_active_member(member.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
             MultisigError::NotAMember
         );
         require!(
@@ -53,10 +55,7 @@ impl ProposalActivate<'_> {
             matches!(proposal.status, ProposalStatus::Draft { .. }),
             MultisigError::InvalidProposalStatus
         );
-        require!(
-            proposal.transaction_index > multisig.stale_transaction_index,
-            MultisigError::StaleProposal
-        );
+        require!(!proposal.is_stale(multisig), MultisigError::StaleProposal);
 
         Ok(())
     }
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::errors::*;
 use crate::state::*;
+use crate::utils::AccountExtension;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ProposalCreateArgs {
@@ -65,10 +66,18 @@ impl ProposalCreate<'_> {
             MultisigError::StaleProposal
         );
 
+        // We can't create a proposal for a transaction whose accounts are closed.
+        require!(
+            args.transaction_index > multisig.extension.closed_transaction_index,
+            MultisigError::InvalidTransactionIndex
+        );
+
         // creator
         // Has to be a member.
         require!(
-            self.multisig.is_member(self.creator.key()).is_some(),
+            self.multisig
+                .is_active_member(self.creator.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
             MultisigError::NotAMember
         );
 
@@ -105,6 +114,12 @@ impl ProposalCreate<'_> {
         proposal.approved = vec![];
         proposal.rejected = vec![];
         proposal.cancelled = vec![];
+        proposal.extension = AccountExtension::new(ProposalExtension {
+            rent_payer: ctx.accounts.rent_payer.key(),
+            rebased_invalidation_count: None,
+            abstained: vec![],
+            passkey_nonce: 0,
+        });
 
         Ok(())
     }
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposalRebaseArgs {
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct ProposalRebase<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// The member rebasing the proposal, pays for the proposal reallocation if the multisig has more members now,
+    /// and for storing the multisig extension if it isn't yet.
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &proposal.transaction_index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// The vault transaction the `proposal` is for.
+    /// Only vault transactions can be rebased: config transactions are created against a particular config.
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &proposal.transaction_index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+    )]
+    pub transaction: Account<'info, VaultTransaction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl ProposalRebase<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            multisig,
+            proposal,
+            member,
+            transaction,
+            ..
+        } = self;
+
+        // `member`
+        require!(
+            multisig
+                .is_active_member(member.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
+            MultisigError::NotAMember
+        );
+        require!(
+            // Rebasing restarts the voting, so it's up to the members who can vote on the proposal.
+            multisig.member_has_permission(member.key(), Permission::Vote),
+            MultisigError::Unauthorized
+        );
+        require!(
+            multisig.member_has_vault_permission(
+                member.key(),
+                Permission::Vote,
+                transaction.vault_index
+            ),
+            MultisigError::VaultOutOfScope
+        );
+
+        // `proposal`
+        // Approved stale proposals can still be executed, so only the proposals
+        // that are still collecting votes can be rebased.
+        require!(
+            matches!(
+                proposal.status,
+                ProposalStatus::Draft { .. } | ProposalStatus::Active { .. }
+            ),
+            MultisigError::InvalidProposalStatus
+        );
+        require!(proposal.is_stale(multisig), MultisigError::ProposalNotStale);
+
+        Ok(())
+    }
+
+    /// Rebase a stale vault transaction proposal onto the current multisig config.
+    /// The proposal becomes `Active` again with all the votes cleared, and records the current
+    /// `invalidation_count` of the multisig so that it's only made stale again by the config changes
+    /// that happen after the rebase. The transaction keeps its index.
+    #[access_control(ctx.accounts.validate())]
+    pub fn proposal_rebase(ctx: Context<Self>, _args: ProposalRebaseArgs) -> Result<()> {
+        let multisig = &mut ctx.accounts.multisig;
+        let proposal = &mut ctx.accounts.proposal;
+
+        // `invalidation_count` is only bumped once the multisig stores its extension.
+        if !multisig.extension.is_stored() {
+            multisig.extension = AccountExtension::new(MultisigExtension::default());
+            Multisig::realloc_if_needed(
+                multisig.to_account_info(),
+                multisig.members.len(),
+                &multisig.extension,
+                Some(ctx.accounts.member.to_account_info()),
+                Some(ctx.accounts.system_program.to_account_info()),
+            )?;
+        }
+
+        proposal.extension.rebased_invalidation_count = Some(multisig.extension.invalidation_count);
+        proposal.status = ProposalStatus::Active {
+            timestamp: Clock::get()?.unix_timestamp,
+        };
+        proposal.approved = vec![];
+        proposal.rejected = vec![];
+        proposal.cancelled = vec![];
+        proposal.extension.abstained = vec![];
+
+        // The proposal must fit the votes of all the current members.
+        let members_len = multisig.members.len();
+        let proposal_account_info = proposal.to_account_info();
+        Proposal::realloc_if_needed(
+            proposal_account_info,
+            members_len,
+            Some(ctx.accounts.member.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::errors::*;
 use crate::state::*;
+use crate::utils::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ProposalVoteArgs {
@@ -41,85 +42,118 @@ pub struct ProposalCancelV2<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// `ProposalVote` with the accounts needed for vault-scoped voters and session keys.
+#[derive(Accounts)]
+pub struct ProposalVoteV2<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &proposal.transaction_index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// The transaction the `proposal` is for.
+    /// Required if the `Vote` permission of any member is scoped to particular vaults.
+    /// CHECK: validated in `transaction_vault_index()`.
+    pub transaction: Option<AccountInfo<'info>>,
+
+    /// The session key of a multisig member, if the `member` signer is a session key voting on their behalf.
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_SESSION_KEY,
+            session_key.member.as_ref(),
+            member.key().as_ref(),
+        ],
+        bump = session_key.bump,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// Required if the proposal must be reallocated to record the vote, paid by the `member`.
+    pub system_program: Option<Program<'info, System>>,
+}
+
+#[derive(Accounts)]
+pub struct ProposalVoteMany<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// The member voting, pays for the reallocation of the proposals if needed.
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    // `remaining_accounts` must include the writable `Proposal` accounts to vote on,
+    // each followed by the transaction it's for if the `Vote` permission of any member
+    // is scoped to particular vaults.
+}
+
 impl ProposalVote<'_> {
+    /// The `transaction` of the proposal is not passed, so voting fails with `MissingAccount`
+    /// if the `Vote` permission of any member is scoped to particular vaults.
+    /// `ProposalVoteV2` must be used then.
     fn validate(&self, vote: Vote) -> Result<()> {
         let Self {
             multisig,
             proposal,
             member,
-            ..
         } = self;
 
-        // member
-        require!(
-            multisig.is_member(member.key()).is_some(),
-            MultisigError::NotAMember
-        );
-        require!(
-            multisig.member_has_permission(member.key(), Permission::Vote),
-            MultisigError::Unauthorized
-        );
-
-        // proposal
-        match vote {
-            Vote::Approve | Vote::Reject => {
-                require!(
-                    matches!(proposal.status, ProposalStatus::Active { .. }),
-                    MultisigError::InvalidProposalStatus
-                );
-                // CANNOT approve or reject a stale proposal
-                require!(
-                    proposal.transaction_index > multisig.stale_transaction_index,
-                    MultisigError::StaleProposal
-                );
-            }
-            Vote::Cancel => {
-                require!(
-                    matches!(proposal.status, ProposalStatus::Approved { .. }),
-                    MultisigError::InvalidProposalStatus
-                );
-                // CAN cancel a stale proposal.
-            }
-        }
-
-        Ok(())
+        validate_vote(multisig, member.key(), proposal, None, vote)
     }
 
     /// Approve a multisig proposal on behalf of the `member`.
     /// The proposal must be `Active`.
     #[access_control(ctx.accounts.validate(Vote::Approve))]
     pub fn proposal_approve(ctx: Context<Self>, _args: ProposalVoteArgs) -> Result<()> {
-        let multisig = &mut ctx.accounts.multisig;
+        let member = ctx.accounts.member.key();
+        let multisig = &ctx.accounts.multisig;
         let proposal = &mut ctx.accounts.proposal;
-        let member = &mut ctx.accounts.member;
-
-        proposal.approve(member.key(), usize::from(multisig.threshold))?;
 
-        Ok(())
+        cast_vote(multisig, proposal, None, member, Vote::Approve)
     }
 
     /// Reject a multisig proposal on behalf of the `member`.
     /// The proposal must be `Active`.
     #[access_control(ctx.accounts.validate(Vote::Reject))]
     pub fn proposal_reject(ctx: Context<Self>, _args: ProposalVoteArgs) -> Result<()> {
-        let multisig = &mut ctx.accounts.multisig;
+        let member = ctx.accounts.member.key();
+        let multisig = &ctx.accounts.multisig;
         let proposal = &mut ctx.accounts.proposal;
-        let member = &mut ctx.accounts.member;
-
-        let cutoff = Multisig::cutoff(multisig);
-
-        proposal.reject(member.key(), cutoff)?;
 
-        Ok(())
+        cast_vote(multisig, proposal, None, member, Vote::Reject)
     }
 
     /// Cancel a multisig proposal on behalf of the `member`.
     /// The proposal must be `Approved`.
+    /// Partially executed batches can be cancelled too, which aborts their remaining transactions:
+    /// `batch_execute_transaction` only executes batches of `Approved` proposals.
+    /// There's no way to abort a batch automatically when one of its transactions fails,
+    /// because the failure reverts the whole Solana transaction.
     #[access_control(ctx.accounts.validate(Vote::Cancel))]
     pub fn proposal_cancel(ctx: Context<Self>, _args: ProposalVoteArgs) -> Result<()> {
+        let member = &mut ctx.accounts.member;
         let multisig = &mut ctx.accounts.multisig;
         let proposal = &mut ctx.accounts.proposal;
-        let member = &mut ctx.accounts.member;
 
         proposal
             .cancelled
@@ -161,8 +195,305 @@ impl<'info> ProposalCancelV2<'info> {
     }
 }
 
+impl ProposalVoteV2<'_> {
+    fn validate(&self, vote: Vote) -> Result<()> {
+        let Self {
+            multisig,
+            proposal,
+            transaction,
+            session_key,
+            ..
+        } = self;
+
+        // session_key
+        if let Some(session_key) = session_key {
+            session_key.check_action(vote.session_action(), Clock::get()?.unix_timestamp)?;
+            session_key.check_transaction(transaction.as_ref(), proposal.transaction_index)?;
+        }
+
+        validate_vote(multisig, self.voter(), proposal, transaction.as_ref(), vote)
+    }
+
+    /// The member the vote is cast for: the `member` signer itself,
+    /// or the member it's acting for if it's a session key.
+    fn voter(&self) -> Pubkey {
+        match &self.session_key {
+            Some(session_key) => session_key.member,
+            None => self.member.key(),
+        }
+    }
+
+    /// Records the validated `vote` and reallocates the proposal if it no longer fits the votes.
+    fn vote(&mut self, vote: Vote) -> Result<()> {
+        let voter = self.voter();
+        let multisig = &self.multisig;
+        let proposal = &mut self.proposal;
+
+        cast_vote(multisig, proposal, self.transaction.as_ref(), voter, vote)?;
+
+        Proposal::realloc_if_needed(
+            proposal.to_account_info(),
+            multisig.members.len(),
+            Some(self.member.to_account_info()),
+            self.system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
+        Ok(())
+    }
+
+    /// Approve a multisig proposal on behalf of the `member`.
+    /// The proposal must be `Active`.
+    #[access_control(ctx.accounts.validate(Vote::Approve))]
+    pub fn proposal_approve_v2(ctx: Context<Self>, _args: ProposalVoteArgs) -> Result<()> {
+        ctx.accounts.vote(Vote::Approve)
+    }
+
+    /// Reject a multisig proposal on behalf of the `member`.
+    /// The proposal must be `Active`.
+    #[access_control(ctx.accounts.validate(Vote::Reject))]
+    pub fn proposal_reject_v2(ctx: Context<Self>, _args: ProposalVoteArgs) -> Result<()> {
+        ctx.accounts.vote(Vote::Reject)
+    }
+
+    /// Abstain from voting on a multisig proposal on behalf of the `member`.
+    /// The proposal must be `Active`.
+    #[access_control(ctx.accounts.validate(Vote::Abstain))]
+    pub fn proposal_abstain(ctx: Context<Self>, _args: ProposalVoteArgs) -> Result<()> {
+        ctx.accounts.vote(Vote::Abstain)
+    }
+
+    /// Withdraw the vote of the `member` from a multisig proposal.
+    /// Approvals, rejections and abstentions can be withdrawn from `Active` proposals,
+    /// cancellations from `Approved` ones.
+    #[access_control(ctx.accounts.validate(Vote::Withdraw))]
+    pub fn proposal_withdraw_vote(ctx: Context<Self>, _args: ProposalVoteArgs) -> Result<()> {
+        ctx.accounts.vote(Vote::Withdraw)
+    }
+
+    /// Cancel a multisig proposal on behalf of the `member`.
+    /// The proposal must be `Approved`.
+    /// Same as `proposal_cancel_v2`, with the accounts of `ProposalVoteV2`.
+    #[access_control(ctx.accounts.validate(Vote::Cancel))]
+    pub fn proposal_cancel_v3(ctx: Context<Self>, _args: ProposalVoteArgs) -> Result<()> {
+        ctx.accounts.vote(Vote::Cancel)
+    }
+}
+
+impl<'info> ProposalVoteMany<'info> {
+    /// Cast the same `vote` on each of the proposals in `remaining_accounts`.
+    /// Every proposal goes through the same checks as with `ProposalVote`.
+    fn vote_many(ctx: Context<'_, '_, 'info, 'info, Self>, vote: Vote) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let member = &ctx.accounts.member;
+
+        // The transactions are only needed to find the vaults the votes are counted for.
+        let chunk_size = if multisig.has_vault_scoped_voters() { 2 } else { 1 };
+
+        require!(
+            !ctx.remaining_accounts.is_empty()
+                && ctx.remaining_accounts.len() % chunk_size == 0,
+            MultisigError::InvalidNumberOfAccounts
+        );
+
+        for accounts in ctx.remaining_accounts.chunks(chunk_size) {
+            let proposal_info = &accounts[0];
+            let transaction_info = accounts.get(1);
+
+            require!(proposal_info.is_writable, MultisigError::InvalidAccount);
+
+            // Owner and discriminator are checked here.
+            let mut proposal = Account::<Proposal>::try_from(proposal_info)?;
+
+            // The proposal must belong to the `multisig`.
+            require_keys_eq!(
+                proposal.multisig,
+                multisig.key(),
+                MultisigError::InvalidAccount
+            );
+
+            validate_vote(multisig, member.key(), &proposal, transaction_info, vote)?;
+
+            cast_vote(multisig, &mut proposal, transaction_info, member.key(), vote)?;
+
+            // Reallocate the proposal size if needed, before writing the votes.
+            Proposal::realloc_if_needed(
+                proposal_info.clone(),
+                multisig.members.len(),
+                Some(member.to_account_info()),
+                Some(ctx.accounts.system_program.to_account_info()),
+            )?;
+
+            proposal.try_serialize(&mut &mut proposal_info.data.borrow_mut()[..])?;
+        }
+
+        Ok(())
+    }
+
+    /// Approve several multisig proposals on behalf of the `member`.
+    /// The proposals must be `Active`.
+    pub fn proposal_approve_many(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        _args: ProposalVoteArgs,
+    ) -> Result<()> {
+        Self::vote_many(ctx, Vote::Approve)
+    }
+
+    /// Reject several multisig proposals on behalf of the `member`.
+    /// The proposals must be `Active`.
+    pub fn proposal_reject_many(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        _args: ProposalVoteArgs,
+    ) -> Result<()> {
+        Self::vote_many(ctx, Vote::Reject)
+    }
+
+    /// Cancel several multisig proposals on behalf of the `member`.
+    /// The proposals must be `Approved`.
+    pub fn proposal_cancel_many(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        _args: ProposalVoteArgs,
+    ) -> Result<()> {
+        Self::vote_many(ctx, Vote::Cancel)
+    }
+}
+
+/// Checks that `member` can cast `vote` on `proposal`.
+/// `transaction` is required if the `Vote` permission of any member is scoped to particular vaults.
+pub(crate) fn validate_vote(
+    multisig: &Account<Multisig>,
+    member: Pubkey,
+    proposal: &Proposal,
+    transaction: Option<&AccountInfo>,
+    vote: Vote,
+) -> Result<()> {
+    // member
+    require!(
+        multisig
+            .is_active_member(member, Clock::get()?.unix_timestamp)
+            .is_some(),
+        MultisigError::NotAMember
+    );
+    require!(
+        multisig.member_has_permission(member, Permission::Vote),
+        MultisigError::Unauthorized
+    );
+    // If the member can only vote for some of the vaults,
+    // check the vault of the transaction the proposal is for.
+    if let Some(vault_scope) = multisig.member_vault_scope(member, Permission::Vote) {
+        if let Some(vault_index) = proposal_vault_index(multisig, proposal, transaction)? {
+            require!(
+                vault_scope.allows(vault_index),
+                MultisigError::VaultOutOfScope
+            );
+        }
+    }
+
+    // proposal
+    match vote {
+        Vote::Approve | Vote::Reject | Vote::Abstain => {
+            require!(
+                matches!(proposal.status, ProposalStatus::Active { .. }),
+                MultisigError::InvalidProposalStatus
+            );
+            // CANNOT approve, reject or abstain on a stale proposal
+            require!(!proposal.is_stale(multisig), MultisigError::StaleProposal);
+        }
+        Vote::Cancel => {
+            require!(
+                matches!(proposal.status, ProposalStatus::Approved { .. }),
+                MultisigError::InvalidProposalStatus
+            );
+            // CAN cancel a stale proposal.
+        }
+        Vote::Withdraw => {
+            require!(
+                matches!(
+                    proposal.status,
+                    ProposalStatus::Active { .. } | ProposalStatus::Approved { .. }
+                ),
+                MultisigError::InvalidProposalStatus
+            );
+            // CAN withdraw a vote from a stale proposal.
+        }
+    }
+
+    Ok(())
+}
+
+/// Records the `vote` of `member` on `proposal`, which must have been validated with `validate_vote`.
+/// `transaction` is required if the `Vote` permission of any member is scoped to particular vaults.
+pub(crate) fn cast_vote(
+    multisig: &Account<Multisig>,
+    proposal: &mut Proposal,
+    transaction: Option<&AccountInfo>,
+    member: Pubkey,
+    vote: Vote,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    // The votes of the members that have been removed or have expired no longer count.
+    let is_active_member = |k: &Pubkey| multisig.is_active_member(*k, now).is_some();
+
+    match vote {
+        Vote::Approve => {
+            proposal.approved.retain(is_active_member);
+            proposal.approve(member, usize::from(multisig.threshold))
+        }
+        Vote::Reject => {
+            proposal.rejected.retain(is_active_member);
+            proposal.extension.abstained.retain(is_active_member);
+            let vault_index = proposal_vault_index(multisig, proposal, transaction)?;
+            proposal.reject(member, multisig.cutoff(vault_index, now))
+        }
+        Vote::Abstain => {
+            proposal.rejected.retain(is_active_member);
+            proposal.extension.abstained.retain(is_active_member);
+            let vault_index = proposal_vault_index(multisig, proposal, transaction)?;
+            proposal.abstain(member, multisig.cutoff(vault_index, now))
+        }
+        Vote::Cancel => {
+            proposal.cancelled.retain(is_active_member);
+            proposal.cancel(member, usize::from(multisig.threshold))
+        }
+        Vote::Withdraw => proposal.withdraw_vote(member),
+    }
+}
+
+/// The index of the vault the `transaction` of `proposal` is bound to, which decides who can vote on it.
+/// `None` if no member's `Vote` permission is scoped to particular vaults, as the vault doesn't matter then,
+/// or if the transaction is not bound to a vault.
+fn proposal_vault_index(
+    multisig: &Account<Multisig>,
+    proposal: &Proposal,
+    transaction: Option<&AccountInfo>,
+) -> Result<Option<u8>> {
+    if !multisig.has_vault_scoped_voters() {
+        return Ok(None);
+    }
+
+    let transaction = transaction.ok_or(MultisigError::MissingAccount)?;
+    transaction_vault_index(transaction, &multisig.key(), proposal.transaction_index)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy)]
 pub enum Vote {
     Approve,
     Reject,
     Cancel,
+    Abstain,
+    Withdraw,
+}
+
+impl Vote {
+    /// The session key action needed to cast the vote.
+    fn session_action(self) -> SessionAction {
+        match self {
+            Vote::Approve => SessionAction::Approve,
+            Vote::Reject => SessionAction::Reject,
+            Vote::Cancel => SessionAction::Cancel,
+            Vote::Abstain => SessionAction::Abstain,
+            Vote::Withdraw => SessionAction::WithdrawVote,
+        }
+    }
 }
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use solana_sdk_ids::sysvar::instructions as instructions_sysvar;
+
+use crate::errors::*;
+use crate::instructions::{cast_vote, validate_vote, Vote};
+use crate::state::*;
+use crate::utils::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposalVotePasskeyArgs {
+    /// The key of the passkey member voting: the address derived from their passkey.
+    pub member: Pubkey,
+    /// The vote to cast.
+    pub vote: Vote,
+    /// The WebAuthn client data JSON signed by the passkey, containing the challenge for the vote.
+    pub client_data_json: Vec<u8>,
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct ProposalVotePasskey<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &proposal.transaction_index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// Submits the vote and pays for the reallocation of the proposal if needed.
+    /// Doesn't need to be a member, the vote is authorized by the passkey signature.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: checked by its address, used to find the secp256r1 signature verification instruction.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The transaction the `proposal` is for.
+    /// Required if the `Vote` permission of any member is scoped to particular vaults.
+    /// CHECK: validated in `transaction_vault_index()`.
+    pub transaction: Option<AccountInfo<'info>>,
+}
+
+impl ProposalVotePasskey<'_> {
+    fn validate(&self, args: &ProposalVotePasskeyArgs) -> Result<()> {
+        let Self {
+            multisig,
+            proposal,
+            instructions_sysvar,
+            transaction,
+            ..
+        } = self;
+
+        // member
+        let passkey = multisig
+            .member_settings(args.member)
+            .and_then(|settings| settings.passkey)
+            .ok_or(MultisigError::Unauthorized)?;
+
+        // The passkey must have signed the challenge for this vote.
+        verify_passkey_signature(
+            instructions_sysvar,
+            &passkey.pubkey,
+            &args.client_data_json,
+            &passkey.challenge(
+                &multisig.key(),
+                proposal.transaction_index,
+                args.vote,
+                proposal.extension.passkey_nonce,
+            ),
+        )?;
+
+        validate_vote(
+            multisig,
+            args.member,
+            proposal,
+            transaction.as_ref(),
+            args.vote,
+        )
+    }
+
+    /// Cast a vote on a multisig proposal on behalf of a passkey member.
+    /// The vote is authorized by the secp256r1 signature verification instruction preceding this one,
+    /// which must verify a WebAuthn assertion by the member's passkey over the challenge
+    /// returned by `Passkey::challenge()` for the vote.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn proposal_vote_passkey(ctx: Context<Self>, args: ProposalVotePasskeyArgs) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let proposal = &mut ctx.accounts.proposal;
+        let transaction = ctx.accounts.transaction.as_ref();
+
+        cast_vote(multisig, proposal, transaction, args.member, args.vote)?;
+
+        // Increment the nonce of the proposal, so the signed challenge can't be used again.
+        proposal.extension.passkey_nonce = proposal.extension.passkey_nonce.checked_add(1).unwrap();
+
+        // Reallocate the proposal size if needed.
+        Proposal::realloc_if_needed(
+            proposal.to_account_info(),
+            multisig.members.len(),
+            Some(ctx.accounts.payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SessionKeyCreateArgs {
+    /// The key that will sign on behalf of the `member`.
+    pub key: Pubkey,
+    /// The actions the session key can take on behalf of the `member`.
+    pub actions: SessionActions,
+    /// If set, the session key can only act on vault transactions with balance guards
+    /// limiting the lamports moved out of the vault to at most this amount.
+    pub max_lamports_outflow: Option<u64>,
+    /// Unix timestamp after which the session key can no longer be used.
+    pub expires_at: i64,
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: SessionKeyCreateArgs)]
+pub struct SessionKeyCreate<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        init,
+        payer = member,
+        space = 8 + SessionKey::INIT_SPACE,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_SESSION_KEY,
+            member.key().as_ref(),
+            args.key.as_ref(),
+        ],
+        bump
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    /// The member of the multisig registering the session key, pays for the session key account rent.
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl SessionKeyCreate<'_> {
+    fn validate(&self, args: &SessionKeyCreateArgs) -> Result<()> {
+        let Self {
+            multisig, member, ..
+        } = self;
+
+        let now = Clock::get()?.unix_timestamp;
+
+        // member
+        require!(
+            multisig.is_active_member(member.key(), now).is_some(),
+            MultisigError::NotAMember
+        );
+
+        // A member can't register themselves, the session key would be acting on its own behalf.
+        require_keys_neq!(args.key, member.key(), MultisigError::InvalidAccount);
+
+        require!(args.expires_at > now, MultisigError::SessionKeyExpired);
+
+        Ok(())
+    }
+
+    /// Register a session key that can take the specified actions on behalf of the `member` until it expires.
+    /// The session key is checked against the permissions of the `member` on every use.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn session_key_create(ctx: Context<Self>, args: SessionKeyCreateArgs) -> Result<()> {
+        let session_key = &mut ctx.accounts.session_key;
+
+        session_key.multisig = ctx.accounts.multisig.key();
+        session_key.member = ctx.accounts.member.key();
+        session_key.key = args.key;
+        session_key.actions = args.actions;
+        session_key.max_lamports_outflow = args.max_lamports_outflow;
+        session_key.expires_at = args.expires_at;
+        session_key.bump = ctx.bumps.session_key;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SessionKeyRevokeArgs {
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct SessionKeyRevoke<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        // Rent gets returned to the member
+        close = member,
+        // Only the member the session key acts for can revoke it
+        constraint = session_key.member == member.key() @ MultisigError::Unauthorized,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_SESSION_KEY,
+            member.key().as_ref(),
+            session_key.key.as_ref(),
+        ],
+        bump = session_key.bump
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    /// The member that registered the session key.
+    #[account(mut)]
+    pub member: Signer<'info>,
+}
+
+impl SessionKeyRevoke<'_> {
+    /// Revoke a session key, closing its account.
+    /// Can be done anytime by the member, even if they're no longer part of the multisig.
+    pub fn session_key_revoke(_ctx: Context<Self>, _args: SessionKeyRevokeArgs) -> Result<()> {
+        Ok(())
+    }
+}
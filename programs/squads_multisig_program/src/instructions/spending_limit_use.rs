@@ -84,17 +84,25 @@ pub struct SpendingLimitUse<'info> {
 
     /// In case `spending_limit.mint` is an SPL token.
     pub token_program: Option<Interface<'info, TokenInterface>>,
+    //
+    // `remaining_accounts` must include the `RateLimit` account of the multisig, writable, if it has one.
+    // Only the lamports transferred count against its `max_lamports`.
 }
 
 impl SpendingLimitUse<'_> {
     fn validate(&self) -> Result<()> {
         let Self {
+            multisig,
             member,
             spending_limit,
             mint,
             ..
         } = self;
 
+        // multisig
+        // A spending limit left open can't be used once the multisig is closed.
+        require!(!multisig.is_closed(), MultisigError::MultisigClosed);
+
         // member
         require!(
             spending_limit.members.contains(&member.key()),
@@ -169,66 +177,118 @@ impl SpendingLimitUse<'_> {
             .checked_sub(args.amount)
             .ok_or(MultisigError::SpendingLimitExceeded)?;
 
+        // Count the transfer against the rate limit of the multisig, if it has one.
+        let rate_limit_info = ctx.remaining_accounts.first();
+        if let Some(mut rate_limit) = ctx
+            .accounts
+            .multisig
+            .load_rate_limit(&multisig_key, rate_limit_info)?
+        {
+            let lamports = if spending_limit.mint == Pubkey::default() {
+                args.amount
+            } else {
+                0
+            };
+            rate_limit.check_and_record(1, lamports, None, now)?;
+            rate_limit.save(rate_limit_info.unwrap())?;
+        }
+
         // Transfer tokens.
-        if spending_limit.mint == Pubkey::default() {
+        VaultTransfer {
+            vault,
+            destination,
+            system_program: ctx.accounts.system_program.as_ref(),
+            mint: ctx.accounts.mint.as_ref(),
+            vault_token_account: ctx.accounts.vault_token_account.as_ref(),
+            destination_token_account: ctx.accounts.destination_token_account.as_ref(),
+            token_program: ctx.accounts.token_program.as_ref(),
+        }
+        .transfer(
+            spending_limit.mint,
+            args.amount,
+            args.decimals,
+            &[
+                SEED_PREFIX,
+                multisig_key.as_ref(),
+                SEED_VAULT,
+                &spending_limit.vault_index.to_le_bytes(),
+                &[vault_bump],
+            ],
+        )?;
+
+        Ok(())
+    }
+}
+
+/// The accounts needed to transfer SOL or SPL tokens out of a multisig vault.
+pub(crate) struct VaultTransfer<'a, 'info> {
+    pub vault: &'a AccountInfo<'info>,
+    pub destination: &'a AccountInfo<'info>,
+    /// In case the transferred mint is SOL.
+    pub system_program: Option<&'a Program<'info, System>>,
+    /// The mint of the tokens to transfer in case it's an SPL token.
+    pub mint: Option<&'a InterfaceAccount<'info, Mint>>,
+    /// Vault token account to transfer tokens from in case the mint is an SPL token.
+    pub vault_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    /// Destination token account in case the mint is an SPL token.
+    pub destination_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    /// In case the mint is an SPL token.
+    pub token_program: Option<&'a Interface<'info, TokenInterface>>,
+}
+
+impl VaultTransfer<'_, '_> {
+    /// Transfer `amount` of `mint` from the vault to the destination, signing with `vault_seeds`.
+    /// `mint` is `Pubkey::default()` for SOL.
+    pub fn transfer(
+        &self,
+        mint: Pubkey,
+        amount: u64,
+        decimals: u8,
+        vault_seeds: &[&[u8]],
+    ) -> Result<()> {
+        let Self {
+            vault,
+            destination,
+            ..
+        } = self;
+
+        if mint == Pubkey::default() {
             // Transfer using the system_program::transfer.
-            let system_program = &ctx
-                .accounts
-                .system_program
-                .as_ref()
-                .ok_or(MultisigError::MissingAccount)?;
+            let system_program = self.system_program.ok_or(MultisigError::MissingAccount)?;
 
             // Sanity check for the decimals. Similar to the one in token_interface::transfer_checked.
-            require!(args.decimals == 9, MultisigError::DecimalsMismatch);
+            require!(decimals == 9, MultisigError::DecimalsMismatch);
 
             anchor_lang::system_program::transfer(
                 CpiContext::new_with_signer(
                     system_program.to_account_info(),
                     anchor_lang::system_program::Transfer {
-                        from: vault.clone(),
-                        to: destination.clone(),
+                        from: (*vault).clone(),
+                        to: (*destination).clone(),
                     },
-                    &[&[
-                        SEED_PREFIX,
-                        multisig_key.as_ref(),
-                        SEED_VAULT,
-                        &spending_limit.vault_index.to_le_bytes(),
-                        &[vault_bump],
-                    ]],
+                    &[vault_seeds],
                 ),
-                args.amount,
+                amount,
             )?
         } else {
             // Transfer using the token_program::transfer_checked.
-            let mint = &ctx
-                .accounts
-                .mint
-                .as_ref()
-                .ok_or(MultisigError::MissingAccount)?;
-            let vault_token_account = &ctx
-                .accounts
+            let mint_account = self.mint.ok_or(MultisigError::MissingAccount)?;
+            let vault_token_account = self
                 .vault_token_account
-                .as_ref()
                 .ok_or(MultisigError::MissingAccount)?;
-            let destination_token_account = &ctx
-                .accounts
+            let destination_token_account = self
                 .destination_token_account
-                .as_ref()
-                .ok_or(MultisigError::MissingAccount)?;
-            let token_program = &ctx
-                .accounts
-                .token_program
-                .as_ref()
                 .ok_or(MultisigError::MissingAccount)?;
+            let token_program = self.token_program.ok_or(MultisigError::MissingAccount)?;
 
             msg!(
                 "token_program {} mint {} vault {} destination {} amount {} decimals {}",
                 &token_program.key,
-                &mint.key(),
+                &mint_account.key(),
                 &vault.key,
                 &destination.key,
-                &args.amount,
-                &args.decimals
+                &amount,
+                &decimals
             );
 
             token_interface::transfer_checked(
@@ -236,20 +296,14 @@ impl SpendingLimitUse<'_> {
                     token_program.to_account_info(),
                     TransferChecked {
                         from: vault_token_account.to_account_info(),
-                        mint: mint.to_account_info(),
+                        mint: mint_account.to_account_info(),
                         to: destination_token_account.to_account_info(),
-                        authority: vault.clone(),
+                        authority: (*vault).clone(),
                     },
-                    &[&[
-                        SEED_PREFIX,
-                        multisig_key.as_ref(),
-                        SEED_VAULT,
-                        &spending_limit.vault_index.to_le_bytes(),
-                        &[vault_bump],
-                    ]],
+                    &[vault_seeds],
                 ),
-                args.amount,
-                args.decimals,
+                amount,
+                decimals,
             )?;
         }
 
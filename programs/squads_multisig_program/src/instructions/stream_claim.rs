@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::*;
+use crate::instructions::VaultTransfer;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StreamClaimArgs {
+    /// Decimals of the token mint. Used for double-checking against incorrect order of magnitude of the claimed amount.
+    pub decimals: u8,
+    /// Memo used for indexing.
+    pub memo: Option<String>,
+}
+
+#[derive(Accounts)]
+pub struct StreamClaim<'info> {
+    /// The multisig account the `stream` is for.
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    /// The recipient of the `stream`, receiving the claimed tokens.
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    /// The Stream account to claim from.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_STREAM,
+            stream.create_key.key().as_ref(),
+        ],
+        bump = stream.bump,
+        has_one = recipient @ MultisigError::Unauthorized,
+    )]
+    pub stream: Account<'info, Stream>,
+
+    /// Multisig vault account funding the stream.
+    /// CHECK: All the required checks are done by checking the seeds.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_VAULT,
+            &stream.vault_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub vault: AccountInfo<'info>,
+
+    /// In case `stream.mint` is SOL.
+    pub system_program: Option<Program<'info, System>>,
+
+    /// The mint of the tokens to transfer in case `stream.mint` is an SPL token.
+    /// CHECK: We do the checks in `StreamClaim::validate`.
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    /// Multisig vault token account to transfer tokens from in case `stream.mint` is an SPL token.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = vault,
+    )]
+    pub vault_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Recipient token account in case `stream.mint` is an SPL token.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = recipient,
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// In case `stream.mint` is an SPL token.
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// The rate limit of the multisig, the claim counts against. Required if the multisig has one.
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, multisig.key().as_ref(), SEED_RATE_LIMIT],
+        bump = rate_limit.bump,
+    )]
+    pub rate_limit: Option<Account<'info, RateLimit>>,
+}
+
+impl StreamClaim<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            multisig,
+            stream,
+            mint,
+            ..
+        } = self;
+
+        // multisig
+        // Streams stop paying out once the multisig is closed.
+        require!(!multisig.is_closed(), MultisigError::MultisigClosed);
+
+        // recipient - checked in the #[account] attribute.
+
+        // stream - checked in the #[account] attribute.
+
+        // mint
+        if stream.mint == Pubkey::default() {
+            // Stream is for SOL, there should be no mint account in this case.
+            require!(mint.is_none(), MultisigError::InvalidMint);
+        } else {
+            // Stream is for an SPL token, `mint` must match `stream.mint`.
+            require!(
+                stream.mint == mint.as_ref().unwrap().key(),
+                MultisigError::InvalidMint
+            );
+        }
+
+        // vault - checked in the #[account] attribute.
+
+        // vault_token_account - checked in the #[account] attribute.
+
+        // recipient_token_account - checked in the #[account] attribute.
+
+        // rate_limit
+        multisig.check_rate_limit_account(self.rate_limit.as_ref())?;
+
+        Ok(())
+    }
+
+    /// Claim the vested and not yet claimed amount of a stream, transferring it from the multisig vault to the recipient.
+    #[access_control(ctx.accounts.validate())]
+    pub fn stream_claim(ctx: Context<Self>, args: StreamClaimArgs) -> Result<()> {
+        let stream = &mut ctx.accounts.stream;
+
+        let multisig_key = ctx.accounts.multisig.key();
+        let vault_bump = ctx.bumps.vault;
+
+        let now = Clock::get()?.unix_timestamp;
+        let amount = stream.claimable_amount(now);
+        require_neq!(amount, 0, MultisigError::StreamNothingToClaim);
+
+        stream.claimed_amount = stream.claimed_amount.checked_add(amount).unwrap();
+
+        stream.invariant()?;
+
+        // Count the claim against the rate limit of the multisig, if it has one.
+        if let Some(rate_limit) = &mut ctx.accounts.rate_limit {
+            let lamports = if stream.mint == Pubkey::default() {
+                amount
+            } else {
+                0
+            };
+            rate_limit.check_and_record(1, lamports, None, now)?;
+        }
+
+        // Transfer tokens.
+        VaultTransfer {
+            vault: &ctx.accounts.vault,
+            destination: &ctx.accounts.recipient,
+            system_program: ctx.accounts.system_program.as_ref(),
+            mint: ctx.accounts.mint.as_ref(),
+            vault_token_account: ctx.accounts.vault_token_account.as_ref(),
+            destination_token_account: ctx.accounts.recipient_token_account.as_ref(),
+            token_program: ctx.accounts.token_program.as_ref(),
+        }
+        .transfer(
+            stream.mint,
+            amount,
+            args.decimals,
+            &[
+                SEED_PREFIX,
+                multisig_key.as_ref(),
+                SEED_VAULT,
+                &stream.vault_index.to_le_bytes(),
+                &[vault_bump],
+            ],
+        )?;
+
+        Ok(())
+    }
+}
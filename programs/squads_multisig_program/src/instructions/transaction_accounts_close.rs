@@ -1,10 +1,16 @@
 //! Contains instructions for closing accounts related to ConfigTransactions,
-//! VaultTransactions and Batches.
+//! VaultTransactions, Batches and Endorsements.
 //!
 //! The differences between the 3 is minor but still exist. For example,
 //! a ConfigTransaction's accounts can always be closed if the proposal is stale,
 //! while for VaultTransactions and Batches it's not allowed if the proposal is stale but Approved,
-//! because they still can be executed in such a case.
+//! because they still can be executed in such a case. Endorsements follow the VaultTransaction rules.
+//!
+//! The rent goes to the multisig `rent_collector` if it's set, otherwise each account's rent
+//! is refunded to the account that paid it if the multisig opted into `refund_rent_to_payers`.
+//! See `Multisig::rent_destination`.
+//!
+//! `transactions_accounts_close_many` closes the accounts of multiple VaultTransactions at once.
 //!
 //! The other reason we have 3 different instructions is purely related to Anchor API which
 //! allows adding the `close` attribute only to `Account<'info, XXX>` types, which forces us
@@ -15,12 +21,36 @@ use crate::errors::*;
 use crate::state::*;
 use crate::utils;
 
+/// Returns the account the `proposal` rent should be refunded to, see `Multisig::rent_destination`:
+/// `rent_collector` if it's the destination, otherwise `proposal_rent_payer`.
+fn proposal_rent_destination<'info>(
+    multisig: &Multisig,
+    proposal: &Proposal,
+    rent_collector: &AccountInfo<'info>,
+    proposal_rent_payer: &Option<AccountInfo<'info>>,
+) -> Result<AccountInfo<'info>> {
+    let rent_destination = multisig.rent_destination(proposal.extension.rent_payer)?;
+    if rent_destination == rent_collector.key() {
+        return Ok(rent_collector.clone());
+    }
+
+    let proposal_rent_payer = proposal_rent_payer
+        .as_ref()
+        .ok_or(MultisigError::MissingAccount)?;
+    require_keys_eq!(
+        proposal_rent_payer.key(),
+        rent_destination,
+        MultisigError::InvalidRentCollector
+    );
+
+    Ok(proposal_rent_payer.clone())
+}
+
 #[derive(Accounts)]
 pub struct ConfigTransactionAccountsClose<'info> {
     #[account(
         seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
         bump = multisig.bump,
-        constraint = multisig.rent_collector.is_some() @ MultisigError::RentReclamationDisabled,
     )]
     pub multisig: Account<'info, Multisig>,
 
@@ -47,15 +77,22 @@ pub struct ConfigTransactionAccountsClose<'info> {
     )]
     pub transaction: Account<'info, ConfigTransaction>,
 
-    /// The rent collector.
+    /// The rent collector, or the account that paid the `transaction` rent
+    /// if the multisig refunds rent to the payers.
     /// CHECK: We only need to validate the address.
     #[account(
         mut,
-        address = multisig.rent_collector.unwrap().key() @ MultisigError::InvalidRentCollector,
+        address = multisig.rent_destination(transaction.extension.rent_payer)? @ MultisigError::InvalidRentCollector,
     )]
     pub rent_collector: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// The account that paid the `proposal` rent. Only required if the multisig refunds rent to the payers
+    /// and the `proposal` rent was paid by another account than the `rent_collector` one.
+    /// CHECK: Validated against `proposal.extension.rent_payer`.
+    #[account(mut)]
+    pub proposal_rent_payer: Option<AccountInfo<'info>>,
 }
 
 impl ConfigTransactionAccountsClose<'_> {
@@ -108,10 +145,16 @@ impl ConfigTransactionAccountsClose<'_> {
         require!(can_close, MultisigError::InvalidProposalStatus);
 
         // Close the `proposal` account if exists.
-        if proposal_account.is_some() {
+        if let Some(proposal_account) = &proposal_account {
+            let proposal_rent_destination = proposal_rent_destination(
+                multisig,
+                proposal_account,
+                rent_collector,
+                &ctx.accounts.proposal_rent_payer,
+            )?;
             utils::close(
                 ctx.accounts.proposal.to_account_info(),
-                rent_collector.to_account_info(),
+                proposal_rent_destination,
             )?;
         }
 
@@ -125,7 +168,6 @@ pub struct VaultTransactionAccountsClose<'info> {
     #[account(
         seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
         bump = multisig.bump,
-        constraint = multisig.rent_collector.is_some() @ MultisigError::RentReclamationDisabled,
     )]
     pub multisig: Account<'info, Multisig>,
 
@@ -152,43 +194,30 @@ pub struct VaultTransactionAccountsClose<'info> {
     )]
     pub transaction: Account<'info, VaultTransaction>,
 
-    /// The rent collector.
+    /// The rent collector, or the account that paid the `transaction` rent
+    /// if the multisig refunds rent to the payers.
     /// CHECK: We only need to validate the address.
     #[account(
         mut,
-        address = multisig.rent_collector.unwrap().key() @ MultisigError::InvalidRentCollector,
+        address = multisig.rent_destination(transaction.extension.rent_payer)? @ MultisigError::InvalidRentCollector,
     )]
     pub rent_collector: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// The account that paid the `proposal` rent. Only required if the multisig refunds rent to the payers
+    /// and the `proposal` rent was paid by another account than the `rent_collector` one.
+    /// CHECK: Validated against `proposal.extension.rent_payer`.
+    #[account(mut)]
+    pub proposal_rent_payer: Option<AccountInfo<'info>>,
 }
 
 impl VaultTransactionAccountsClose<'_> {
-    /// Closes a `VaultTransaction` and the corresponding `Proposal`.
-    /// `transaction` can be closed if either:
-    /// - the `proposal` is in a terminal state: `Executed`, `Rejected`, or `Cancelled`.
-    /// - the `proposal` is stale and not `Approved`.
-    pub fn vault_transaction_accounts_close(
-        ctx: Context<VaultTransactionAccountsClose>,
-    ) -> Result<()> {
-        let multisig = &ctx.accounts.multisig;
-        let transaction = &ctx.accounts.transaction;
-        let proposal = &mut ctx.accounts.proposal;
-        let rent_collector = &ctx.accounts.rent_collector;
-
-        let is_stale = transaction.index <= multisig.stale_transaction_index;
-
-        let proposal_account = if proposal.data.borrow().is_empty() {
-            None
-        } else {
-            Some(Proposal::try_deserialize(
-                &mut &**proposal.data.borrow_mut(),
-            )?)
-        };
-
-        #[allow(deprecated)]
-        let can_close = if let Some(proposal_account) = &proposal_account {
-            match proposal_account.status {
+    /// Whether a `VaultTransaction` with the given `proposal` can be closed.
+    #[allow(deprecated)]
+    fn can_close(proposal: Option<&Proposal>, is_stale: bool) -> bool {
+        if let Some(proposal) = proposal {
+            match proposal.status {
                 // Draft proposals can only be closed if stale,
                 // so they can't be activated anymore.
                 ProposalStatus::Draft { .. } => is_stale,
@@ -210,15 +239,51 @@ impl VaultTransactionAccountsClose<'_> {
         } else {
             // If no Proposal account exists then the VaultTransaction can only be closed if stale
             is_stale
+        }
+    }
+
+    /// Closes a `VaultTransaction` and the corresponding `Proposal`.
+    /// `transaction` can be closed if either:
+    /// - the `proposal` is in a terminal state: `Executed`, `Rejected`, or `Cancelled`.
+    /// - the `proposal` is stale and not `Approved`.
+    pub fn vault_transaction_accounts_close(
+        ctx: Context<VaultTransactionAccountsClose>,
+    ) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let transaction = &ctx.accounts.transaction;
+        let proposal = &mut ctx.accounts.proposal;
+        let rent_collector = &ctx.accounts.rent_collector;
+
+        let proposal_account = if proposal.data.borrow().is_empty() {
+            None
+        } else {
+            Some(Proposal::try_deserialize(
+                &mut &**proposal.data.borrow_mut(),
+            )?)
         };
 
-        require!(can_close, MultisigError::InvalidProposalStatus);
+        // A rebased proposal is stale only if the config changed after the rebase.
+        let is_stale = proposal_account.as_ref().map_or(
+            transaction.index <= multisig.stale_transaction_index,
+            |proposal| proposal.is_stale(multisig),
+        );
+
+        require!(
+            Self::can_close(proposal_account.as_ref(), is_stale),
+            MultisigError::InvalidProposalStatus
+        );
 
         // Close the `proposal` account if exists.
-        if proposal_account.is_some() {
+        if let Some(proposal_account) = &proposal_account {
+            let proposal_rent_destination = proposal_rent_destination(
+                multisig,
+                proposal_account,
+                rent_collector,
+                &ctx.accounts.proposal_rent_payer,
+            )?;
             utils::close(
                 ctx.accounts.proposal.to_account_info(),
-                rent_collector.to_account_info(),
+                proposal_rent_destination,
             )?;
         }
 
@@ -227,13 +292,109 @@ impl VaultTransactionAccountsClose<'_> {
     }
 }
 
+#[derive(Accounts)]
+pub struct EndorsementAccountsClose<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// CHECK: `seeds` and `bump` verify that the account is the canonical Proposal,
+    ///         the logic within `endorsement_accounts_close` does the rest of the checks.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &endorsement.index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump,
+    )]
+    pub proposal: AccountInfo<'info>,
+
+    /// Endorsement corresponding to the `proposal`.
+    #[account(
+        mut,
+        has_one = multisig @ MultisigError::TransactionForAnotherMultisig,
+        close = rent_collector
+    )]
+    pub endorsement: Account<'info, Endorsement>,
+
+    /// The rent collector, or the account that paid the `endorsement` rent
+    /// if the multisig refunds rent to the payers.
+    /// CHECK: We only need to validate the address.
+    #[account(
+        mut,
+        address = multisig.rent_destination(endorsement.rent_payer)? @ MultisigError::InvalidRentCollector,
+    )]
+    pub rent_collector: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The account that paid the `proposal` rent. Only required if the multisig refunds rent to the payers
+    /// and the `proposal` rent was paid by another account than the `rent_collector` one.
+    /// CHECK: Validated against `proposal.extension.rent_payer`.
+    #[account(mut)]
+    pub proposal_rent_payer: Option<AccountInfo<'info>>,
+}
+
+impl EndorsementAccountsClose<'_> {
+    /// Closes an `Endorsement` and the corresponding `Proposal`.
+    /// `endorsement` can be closed on the same conditions as a `VaultTransaction`,
+    /// since an `Approved` endorsement can still be executed even if stale.
+    pub fn endorsement_accounts_close(ctx: Context<Self>) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let endorsement = &ctx.accounts.endorsement;
+        let proposal = &mut ctx.accounts.proposal;
+        let rent_collector = &ctx.accounts.rent_collector;
+
+        let proposal_account = if proposal.data.borrow().is_empty() {
+            None
+        } else {
+            Some(Proposal::try_deserialize(
+                &mut &**proposal.data.borrow_mut(),
+            )?)
+        };
+
+        // A rebased proposal is stale only if the config changed after the rebase.
+        let is_stale = proposal_account.as_ref().map_or(
+            endorsement.index <= multisig.stale_transaction_index,
+            |proposal| proposal.is_stale(multisig),
+        );
+
+        require!(
+            VaultTransactionAccountsClose::can_close(proposal_account.as_ref(), is_stale),
+            MultisigError::InvalidProposalStatus
+        );
+
+        // Close the `proposal` account if exists.
+        if let Some(proposal_account) = &proposal_account {
+            let proposal_rent_destination = proposal_rent_destination(
+                multisig,
+                proposal_account,
+                rent_collector,
+                &ctx.accounts.proposal_rent_payer,
+            )?;
+            utils::close(
+                ctx.accounts.proposal.to_account_info(),
+                proposal_rent_destination,
+            )?;
+        }
+
+        // Anchor will close the `endorsement` account for us.
+        Ok(())
+    }
+}
+
 //region VaultBatchTransactionAccountClose
 #[derive(Accounts)]
 pub struct VaultBatchTransactionAccountClose<'info> {
     #[account(
         seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
         bump = multisig.bump,
-        constraint = multisig.rent_collector.is_some() @ MultisigError::RentReclamationDisabled,
     )]
     pub multisig: Account<'info, Multisig>,
 
@@ -258,11 +419,12 @@ pub struct VaultBatchTransactionAccountClose<'info> {
     )]
     pub transaction: Account<'info, VaultBatchTransaction>,
 
-    /// The rent collector.
+    /// The rent collector, or the account that paid the `transaction` rent
+    /// if the multisig refunds rent to the payers.
     /// CHECK: We only need to validate the address.
     #[account(
         mut,
-        address = multisig.rent_collector.unwrap().key() @ MultisigError::InvalidRentCollector,
+        address = multisig.rent_destination(transaction.extension.rent_payer)? @ MultisigError::InvalidRentCollector,
     )]
     pub rent_collector: AccountInfo<'info>,
 
@@ -340,6 +502,8 @@ impl VaultBatchTransactionAccountClose<'_> {
     /// and the operation is only allowed if any of the following conditions is met:
     /// - the `proposal` is in a terminal state: `Executed`, `Rejected`, or `Cancelled`.
     /// - the `proposal` is stale and not `Approved`.
+    /// A batch whose proposal was cancelled after some of its transactions were executed
+    /// can be closed the same way, including the transactions that were never executed.
     #[access_control(ctx.accounts.validate())]
     pub fn vault_batch_transaction_account_close(ctx: Context<Self>) -> Result<()> {
         let batch = &mut ctx.accounts.batch;
@@ -359,7 +523,6 @@ pub struct BatchAccountsClose<'info> {
     #[account(
         seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
         bump = multisig.bump,
-        constraint = multisig.rent_collector.is_some() @ MultisigError::RentReclamationDisabled,
     )]
     pub multisig: Account<'info, Multisig>,
 
@@ -387,15 +550,22 @@ pub struct BatchAccountsClose<'info> {
     )]
     pub batch: Account<'info, Batch>,
 
-    /// The rent collector.
+    /// The rent collector, or the account that paid the `batch` rent
+    /// if the multisig refunds rent to the payers.
     /// CHECK: We only need to validate the address.
     #[account(
         mut,
-        address = multisig.rent_collector.unwrap().key() @ MultisigError::InvalidRentCollector,
+        address = multisig.rent_destination(batch.extension.rent_payer)? @ MultisigError::InvalidRentCollector,
     )]
     pub rent_collector: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// The account that paid the `proposal` rent. Only required if the multisig refunds rent to the payers
+    /// and the `proposal` rent was paid by another account than the `rent_collector` one.
+    /// CHECK: Validated against `proposal.extension.rent_payer`.
+    #[account(mut)]
+    pub proposal_rent_payer: Option<AccountInfo<'info>>,
 }
 
 impl BatchAccountsClose<'_> {
@@ -452,10 +622,16 @@ impl BatchAccountsClose<'_> {
         require_eq!(batch.size, 0, MultisigError::BatchNotEmpty);
 
         // Close the `proposal` account if exists.
-        if proposal_account.is_some() {
+        if let Some(proposal_account) = &proposal_account {
+            let proposal_rent_destination = proposal_rent_destination(
+                multisig,
+                proposal_account,
+                rent_collector,
+                &ctx.accounts.proposal_rent_payer,
+            )?;
             utils::close(
                 ctx.accounts.proposal.to_account_info(),
-                rent_collector.to_account_info(),
+                proposal_rent_destination,
             )?;
         }
 
@@ -464,3 +640,117 @@ impl BatchAccountsClose<'_> {
     }
 }
 //endregion
+
+//region TransactionsAccountsCloseMany
+#[derive(Accounts)]
+pub struct TransactionsAccountsCloseMany<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// The rent collector, or the account that paid the rent of all the closed accounts
+    /// if the multisig refunds rent to the payers.
+    /// CHECK: Validated against the rent destination of each closed account.
+    #[account(mut)]
+    pub rent_collector: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    // `remaining_accounts` must include the following pairs of writable accounts
+    // for each of the vault transactions to close:
+    // 1. The `Proposal` account, which may not exist if no proposal was created for the transaction.
+    // 2. The `VaultTransaction` account corresponding to the `Proposal`.
+}
+
+impl<'info> TransactionsAccountsCloseMany<'info> {
+    /// Closes multiple `VaultTransaction`s and the corresponding `Proposal`s at once,
+    /// returning the rent to the `rent_collector`.
+    /// Each transaction can be closed on the same conditions as in `vault_transaction_accounts_close`,
+    /// and the rent of all the accounts must go to the same destination, see `Multisig::rent_destination`.
+    pub fn transactions_accounts_close_many(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+    ) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let rent_collector = &ctx.accounts.rent_collector;
+
+        let multisig_key = multisig.key();
+
+        require!(
+            !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() % 2 == 0,
+            MultisigError::InvalidNumberOfAccounts
+        );
+
+        for accounts in ctx.remaining_accounts.chunks(2) {
+            let (proposal_info, transaction_info) = (&accounts[0], &accounts[1]);
+
+            let transaction = Account::<VaultTransaction>::try_from(transaction_info)?;
+            require_keys_eq!(
+                transaction.multisig,
+                multisig_key,
+                MultisigError::TransactionForAnotherMultisig
+            );
+
+            let proposal_seeds = &[
+                SEED_PREFIX,
+                multisig_key.as_ref(),
+                SEED_TRANSACTION,
+                &transaction.index.to_le_bytes(),
+                SEED_PROPOSAL,
+            ];
+
+            let proposal_account = if proposal_info.data_is_empty() {
+                // Make sure the missing proposal is the canonical one for the transaction.
+                let (proposal_key, _) = Pubkey::find_program_address(proposal_seeds, ctx.program_id);
+                require_keys_eq!(proposal_key, *proposal_info.key, MultisigError::InvalidAccount);
+
+                None
+            } else {
+                let proposal_account =
+                    Proposal::try_deserialize(&mut &**proposal_info.data.borrow())?;
+
+                // Make sure the proposal is the canonical one for the transaction.
+                let proposal_key = Pubkey::create_program_address(
+                    &[&proposal_seeds[..], &[&[proposal_account.bump]]].concat(),
+                    ctx.program_id,
+                )
+                .map_err(|_| MultisigError::InvalidAccount)?;
+                require_keys_eq!(proposal_key, *proposal_info.key, MultisigError::InvalidAccount);
+
+                Some(proposal_account)
+            };
+
+            // A rebased proposal is stale only if the config changed after the rebase.
+            let is_stale = proposal_account.as_ref().map_or(
+                transaction.index <= multisig.stale_transaction_index,
+                |proposal| proposal.is_stale(multisig),
+            );
+
+            require!(
+                VaultTransactionAccountsClose::can_close(proposal_account.as_ref(), is_stale),
+                MultisigError::InvalidProposalStatus
+            );
+
+            require_keys_eq!(
+                multisig.rent_destination(transaction.extension.rent_payer)?,
+                rent_collector.key(),
+                MultisigError::InvalidRentCollector
+            );
+
+            // Close the `proposal` account if exists.
+            if let Some(proposal_account) = &proposal_account {
+                require_keys_eq!(
+                    multisig.rent_destination(proposal_account.extension.rent_payer)?,
+                    rent_collector.key(),
+                    MultisigError::InvalidRentCollector
+                );
+                utils::close(proposal_info.clone(), rent_collector.clone())?;
+            }
+
+            utils::close(transaction_info.clone(), rent_collector.clone())?;
+        }
+
+        Ok(())
+    }
+}
+//endregion
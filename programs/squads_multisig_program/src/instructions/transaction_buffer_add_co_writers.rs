@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TransactionBufferAddCoWritersArgs {
+    /// Members besides the creator allowed to extend the buffer and create the transaction from it.
+    /// They must have `Initiate` permission. The integrity is still protected by `final_buffer_hash`.
+    pub co_writers: Vec<Pubkey>,
+}
+
+#[derive(Accounts)]
+pub struct TransactionBufferAddCoWriters<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        // Only the creator can add co-writers to the buffer
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            creator.key().as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub transaction_buffer: Account<'info, TransactionBuffer>,
+
+    /// The member of the multisig that created the TransactionBuffer.
+    pub creator: Signer<'info>,
+
+    /// The payer for the additional rent of the co-writers.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl TransactionBufferAddCoWriters<'_> {
+    fn validate(&self, args: &TransactionBufferAddCoWritersArgs) -> Result<()> {
+        let Self {
+            multisig, creator, ..
+        } = self;
+
+        // creator is (still) a member in the multisig
+        require!(
+            multisig
+                .is_active_member(creator.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
+            MultisigError::NotAMember
+        );
+
+        // creator (still) has initiate permissions
+        require!(
+            multisig.member_has_permission(creator.key(), Permission::Initiate),
+            MultisigError::Unauthorized
+        );
+
+        // co-writers are members with initiate permissions
+        for co_writer in &args.co_writers {
+            require!(
+                multisig.is_member(*co_writer).is_some(),
+                MultisigError::NotAMember
+            );
+            require!(
+                multisig.member_has_permission(*co_writer, Permission::Initiate),
+                MultisigError::Unauthorized
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Allow other members to extend the buffer and create the transaction from it.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn transaction_buffer_add_co_writers(
+        ctx: Context<Self>,
+        args: TransactionBufferAddCoWritersArgs,
+    ) -> Result<()> {
+        // Mutable Accounts
+        let transaction_buffer = &mut ctx.accounts.transaction_buffer;
+
+        transaction_buffer
+            .extension
+            .co_writers
+            .extend(args.co_writers);
+
+        // Make sure the account fits the co-writers.
+        TransactionBuffer::realloc_if_needed(
+            transaction_buffer.to_account_info(),
+            transaction_buffer.buffer.len(),
+            &transaction_buffer.extension,
+            Some(ctx.accounts.rent_payer.to_account_info()),
+            Some(ctx.accounts.system_program.to_account_info()),
+        )?;
+
+        // Invariant function on the transaction buffer
+        transaction_buffer.invariant()?;
+
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::*;
-use crate::state::MAX_BUFFER_SIZE;
+use crate::state::MAX_FINAL_BUFFER_SIZE;
 use crate::state::*;
+use crate::utils::AccountExtension;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct TransactionBufferCreateArgs {
@@ -30,7 +31,10 @@ pub struct TransactionBufferCreate<'info> {
     #[account(
         init,
         payer = rent_payer,
-        space = TransactionBuffer::size(args.final_buffer_size)?,
+        space = TransactionBuffer::initial_size(
+            args.final_buffer_size,
+            &AccountExtension::default(),
+        )?,
         seeds = [
             SEED_PREFIX,
             multisig.key().as_ref(),
@@ -60,7 +64,9 @@ impl TransactionBufferCreate<'_> {
 
         // creator is a member in the multisig
         require!(
-            multisig.is_member(creator.key()).is_some(),
+            multisig
+                .is_active_member(creator.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
             MultisigError::NotAMember
         );
         // creator has initiate permissions
@@ -69,9 +75,9 @@ impl TransactionBufferCreate<'_> {
             MultisigError::Unauthorized
         );
 
-        // Final Buffer Size must not exceed 4000 bytes
+        // Final Buffer Size must not exceed MAX_FINAL_BUFFER_SIZE bytes
         require!(
-            args.final_buffer_size as usize <= MAX_BUFFER_SIZE,
+            args.final_buffer_size as usize <= MAX_FINAL_BUFFER_SIZE,
             MultisigError::FinalBufferSizeExceeded
         );
         Ok(())
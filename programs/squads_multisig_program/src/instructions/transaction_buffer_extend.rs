@@ -20,21 +20,29 @@ pub struct TransactionBufferExtend<'info> {
 
     #[account(
         mut,
-        // Only the creator can extend the buffer
-        constraint = transaction_buffer.creator == creator.key() @ MultisigError::Unauthorized,
+        // Only the creator and the co-writers can extend the buffer
+        constraint = transaction_buffer.is_writer(&creator.key()) @ MultisigError::Unauthorized,
         seeds = [
             SEED_PREFIX,
             multisig.key().as_ref(),
             SEED_TRANSACTION_BUFFER,
-            creator.key().as_ref(),
+            transaction_buffer.creator.as_ref(),
             &transaction_buffer.buffer_index.to_le_bytes()
         ],
         bump
     )]
     pub transaction_buffer: Account<'info, TransactionBuffer>,
 
-    /// The member of the multisig that created the TransactionBuffer.
+    /// The member of the multisig extending the TransactionBuffer.
+    /// Must be the creator of the TransactionBuffer or one of its co-writers.
     pub creator: Signer<'info>,
+
+    /// The payer for the additional rent, required once the buffer outgrows its initial allocation.
+    #[account(mut)]
+    pub rent_payer: Option<Signer<'info>>,
+
+    /// Required once the buffer outgrows its initial allocation.
+    pub system_program: Option<Program<'info, System>>,
 }
 
 impl TransactionBufferExtend<'_> {
@@ -46,13 +54,15 @@ impl TransactionBufferExtend<'_> {
             ..
         } = self;
 
-        // creator is still a member in the multisig
+        // creator is (still) a member in the multisig
         require!(
-            multisig.is_member(creator.key()).is_some(),
+            multisig
+                .is_active_member(creator.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
             MultisigError::NotAMember
         );
 
-        // creator still has initiate permissions
+        // creator (still) has initiate permissions
         require!(
             multisig.member_has_permission(creator.key(), Permission::Initiate),
             MultisigError::Unauthorized
@@ -88,6 +98,22 @@ impl TransactionBufferExtend<'_> {
         // Required Data
         let buffer_slice_extension = args.buffer;
 
+        // Make sure the account fits the extended buffer,
+        // large buffers are grown as they're extended.
+        TransactionBuffer::realloc_if_needed(
+            transaction_buffer.to_account_info(),
+            transaction_buffer.buffer.len() + buffer_slice_extension.len(),
+            &transaction_buffer.extension,
+            ctx.accounts
+                .rent_payer
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+            ctx.accounts
+                .system_program
+                .as_ref()
+                .map(ToAccountInfo::to_account_info),
+        )?;
+
         // Extend the Buffer inside the TransactionBuffer
         transaction_buffer
             .buffer
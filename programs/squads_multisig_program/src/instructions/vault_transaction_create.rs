@@ -14,6 +14,42 @@ pub struct VaultTransactionCreateArgs {
     pub memo: Option<String>,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultTransactionCreateArgsV2 {
+    /// Index of the vault this transaction belongs to.
+    pub vault_index: u8,
+    /// Number of ephemeral signing PDAs required by the transaction.
+    pub ephemeral_signers: u8,
+    pub transaction_message: Vec<u8>,
+    /// Assertions on the vault balances checked after the transaction is executed.
+    pub balance_guards: Option<BalanceGuards>,
+    /// Whether the vault PDA is allowed to be assigned to another program
+    /// or to have data allocated on it during execution.
+    pub allow_vault_account_changes: bool,
+    /// Whether the ephemeral signer PDAs must stay owned by the System Program with no data
+    /// after execution. Off by default, as creating a mint or a token account at an ephemeral
+    /// signer is what they are for.
+    pub deny_ephemeral_signer_account_changes: bool,
+    /// Checks on the state of accounts that must hold right before the transaction is executed.
+    pub preconditions: Vec<Precondition>,
+    pub memo: Option<String>,
+}
+
+impl From<VaultTransactionCreateArgs> for VaultTransactionCreateArgsV2 {
+    fn from(args: VaultTransactionCreateArgs) -> Self {
+        Self {
+            vault_index: args.vault_index,
+            ephemeral_signers: args.ephemeral_signers,
+            transaction_message: args.transaction_message,
+            balance_guards: None,
+            allow_vault_account_changes: false,
+            deny_ephemeral_signer_account_changes: false,
+            preconditions: vec![],
+            memo: args.memo,
+        }
+    }
+}
+
 #[derive(Accounts)]
 #[instruction(args: VaultTransactionCreateArgs)]
 pub struct VaultTransactionCreate<'info> {
@@ -27,7 +63,52 @@ pub struct VaultTransactionCreate<'info> {
     #[account(
         init,
         payer = rent_payer,
-        space = VaultTransaction::size(args.ephemeral_signers, &args.transaction_message)?,
+        space = VaultTransaction::size(
+            args.ephemeral_signers,
+            &args.transaction_message,
+            &None,
+            &[],
+        )?,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &multisig.transaction_index.checked_add(1).unwrap().to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction: Account<'info, VaultTransaction>,
+
+    /// The member of the multisig that is creating the transaction.
+    pub creator: Signer<'info>,
+
+    /// The payer for the transaction account rent.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// `VaultTransactionCreate` with the arguments and accounts added after the original layout.
+#[derive(Accounts)]
+#[instruction(args: VaultTransactionCreateArgsV2)]
+pub struct VaultTransactionCreateV2<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        init,
+        payer = rent_payer,
+        space = VaultTransaction::size(
+            args.ephemeral_signers,
+            &args.transaction_message,
+            &args.balance_guards,
+            &args.preconditions,
+        )?,
         seeds = [
             SEED_PREFIX,
             multisig.key().as_ref(),
@@ -46,89 +127,199 @@ pub struct VaultTransactionCreate<'info> {
     pub rent_payer: Signer<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// The session key of a multisig member, if the `creator` signer is a session key acting on their behalf.
+    #[account(
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_SESSION_KEY,
+            session_key.member.as_ref(),
+            creator.key().as_ref(),
+        ],
+        bump = session_key.bump,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
 }
 
 impl<'info> VaultTransactionCreate<'info> {
-    pub fn validate(&self) -> Result<()> {
-        let Self {
-            multisig, creator, ..
-        } = self;
-
-        // creator
-        require!(
-            multisig.is_member(creator.key()).is_some(),
-            MultisigError::NotAMember
-        );
-        require!(
-            multisig.member_has_permission(creator.key(), Permission::Initiate),
-            MultisigError::Unauthorized
-        );
-
-        Ok(())
+    pub fn validate(&self, args: &VaultTransactionCreateArgs) -> Result<()> {
+        validate_creator(&self.multisig, self.creator.key(), args.vault_index)
     }
 
     /// Create a new vault transaction.
-    #[access_control(ctx.accounts.validate())]
+    #[access_control(ctx.accounts.validate(&args))]
     pub fn vault_transaction_create(
         ctx: Context<Self>,
         args: VaultTransactionCreateArgs,
     ) -> Result<()> {
-        let multisig = &mut ctx.accounts.multisig;
-        let transaction = &mut ctx.accounts.transaction;
-        let creator = &mut ctx.accounts.creator;
+        let Self {
+            multisig,
+            transaction,
+            creator,
+            rent_payer,
+            ..
+        } = ctx.accounts;
 
-        let transaction_message =
-            TransactionMessage::deserialize(&mut args.transaction_message.as_slice())?;
+        create_vault_transaction(
+            ctx.program_id,
+            multisig,
+            transaction,
+            ctx.bumps.transaction,
+            creator.key(),
+            rent_payer.key(),
+            args.into(),
+        )
+    }
+}
 
-        let multisig_key = multisig.key();
-        let transaction_key = transaction.key();
+impl<'info> VaultTransactionCreateV2<'info> {
+    pub fn validate(&self, args: &VaultTransactionCreateArgsV2) -> Result<()> {
+        // session_key
+        if let Some(session_key) = &self.session_key {
+            session_key.check_action(SessionAction::Initiate, Clock::get()?.unix_timestamp)?;
+            session_key.check_balance_guards(args.balance_guards.as_ref())?;
+        }
 
-        let vault_seeds = &[
-            SEED_PREFIX,
-            multisig_key.as_ref(),
-            SEED_VAULT,
-            &args.vault_index.to_le_bytes(),
-        ];
-        let (_, vault_bump) = Pubkey::find_program_address(vault_seeds, ctx.program_id);
-
-        let ephemeral_signer_bumps: Vec<u8> = (0..args.ephemeral_signers)
-            .map(|ephemeral_signer_index| {
-                let ephemeral_signer_seeds = &[
-                    SEED_PREFIX,
-                    transaction_key.as_ref(),
-                    SEED_EPHEMERAL_SIGNER,
-                    &ephemeral_signer_index.to_le_bytes(),
-                ];
-
-                let (_, bump) =
-                    Pubkey::find_program_address(ephemeral_signer_seeds, ctx.program_id);
-                bump
-            })
-            .collect();
-
-        // Increment the transaction index.
-        let transaction_index = multisig.transaction_index.checked_add(1).unwrap();
-
-        // Initialize the transaction fields.
-        transaction.multisig = multisig_key;
-        transaction.creator = creator.key();
-        transaction.index = transaction_index;
-        transaction.bump = ctx.bumps.transaction;
-        transaction.vault_index = args.vault_index;
-        transaction.vault_bump = vault_bump;
-        transaction.ephemeral_signer_bumps = ephemeral_signer_bumps;
-        transaction.message = transaction_message.try_into()?;
-
-        // Updated last transaction index in the multisig account.
-        multisig.transaction_index = transaction_index;
-
-        multisig.invariant()?;
-
-        // Logs for indexing.
-        msg!("transaction index: {}", transaction_index);
-
-        Ok(())
+        validate_creator(&self.multisig, self.creator_member(), args.vault_index)
+    }
+
+    /// The member creating the transaction: the `creator` signer itself,
+    /// or the member it's acting for if it's a session key.
+    fn creator_member(&self) -> Pubkey {
+        match &self.session_key {
+            Some(session_key) => session_key.member,
+            None => self.creator.key(),
+        }
     }
+
+    /// Create a new vault transaction with balance guards, preconditions
+    /// and the account change flags, optionally through a session key.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn vault_transaction_create_v2(
+        ctx: Context<Self>,
+        args: VaultTransactionCreateArgsV2,
+    ) -> Result<()> {
+        let creator = ctx.accounts.creator_member();
+        let Self {
+            multisig,
+            transaction,
+            rent_payer,
+            ..
+        } = ctx.accounts;
+
+        create_vault_transaction(
+            ctx.program_id,
+            multisig,
+            transaction,
+            ctx.bumps.transaction,
+            creator,
+            rent_payer.key(),
+            args,
+        )
+    }
+}
+
+/// Checks that the `creator` member can create transactions for the vault at `vault_index`.
+fn validate_creator(multisig: &Multisig, creator: Pubkey, vault_index: u8) -> Result<()> {
+    require!(
+        multisig
+            .is_active_member(creator, Clock::get()?.unix_timestamp)
+            .is_some(),
+        MultisigError::NotAMember
+    );
+    require!(
+        multisig.member_has_permission(creator, Permission::Initiate),
+        MultisigError::Unauthorized
+    );
+    require!(
+        multisig.member_has_vault_permission(creator, Permission::Initiate, vault_index),
+        MultisigError::VaultOutOfScope
+    );
+
+    Ok(())
+}
+
+/// Initializes the `transaction` created by `creator` and bumps the transaction index of the `multisig`.
+fn create_vault_transaction(
+    program_id: &Pubkey,
+    multisig: &mut Account<Multisig>,
+    transaction: &mut Account<VaultTransaction>,
+    transaction_bump: u8,
+    creator: Pubkey,
+    rent_payer: Pubkey,
+    args: VaultTransactionCreateArgsV2,
+) -> Result<()> {
+    let transaction_message =
+        TransactionMessage::deserialize(&mut args.transaction_message.as_slice())?;
+
+    let multisig_key = multisig.key();
+    let transaction_key = transaction.key();
+
+    let vault_seeds = &[
+        SEED_PREFIX,
+        multisig_key.as_ref(),
+        SEED_VAULT,
+        &args.vault_index.to_le_bytes(),
+    ];
+    let (_, vault_bump) = Pubkey::find_program_address(vault_seeds, program_id);
+
+    let ephemeral_signer_bumps: Vec<u8> = (0..args.ephemeral_signers)
+        .map(|ephemeral_signer_index| {
+            let ephemeral_signer_seeds = &[
+                SEED_PREFIX,
+                transaction_key.as_ref(),
+                SEED_EPHEMERAL_SIGNER,
+                &ephemeral_signer_index.to_le_bytes(),
+            ];
+
+            let (_, bump) = Pubkey::find_program_address(ephemeral_signer_seeds, program_id);
+            bump
+        })
+        .collect();
+
+    // Increment the transaction index.
+    let transaction_index = multisig.transaction_index.checked_add(1).unwrap();
+
+    // Initialize the transaction fields.
+    transaction.multisig = multisig_key;
+    transaction.creator = creator;
+    transaction.index = transaction_index;
+    transaction.bump = transaction_bump;
+    transaction.vault_index = args.vault_index;
+    transaction.vault_bump = vault_bump;
+    transaction.ephemeral_signer_bumps = ephemeral_signer_bumps;
+    transaction.message = transaction_message.try_into()?;
+
+    // Make sure the transaction only invokes the allowed programs, if the allowlist is set.
+    if let Some(program_allowlist) = multisig.vault_program_allowlist(args.vault_index) {
+        transaction
+            .message
+            .check_program_allowlist(&program_allowlist)?;
+    }
+
+    if let Some(balance_guards) = &args.balance_guards {
+        balance_guards.invariant()?;
+    }
+    transaction.extension = AccountExtension::new(VaultTransactionExtension {
+        balance_guards: args.balance_guards,
+        allow_vault_account_changes: args.allow_vault_account_changes,
+        deny_ephemeral_signer_account_changes: args.deny_ephemeral_signer_account_changes,
+        preconditions: args.preconditions,
+        executed_instruction_index: 0,
+        rent_payer,
+        pending_message_hash: None,
+    });
+
+    // Updated last transaction index in the multisig account.
+    multisig.transaction_index = transaction_index;
+
+    multisig.invariant()?;
+
+    // Logs for indexing.
+    msg!("transaction index: {}", transaction_index);
+
+    Ok(())
 }
 
 /// Unvalidated instruction data, must be treated as untrusted.
@@ -2,6 +2,7 @@ use crate::errors::*;
 use crate::instructions::*;
 use crate::state::*;
 use anchor_lang::{prelude::*, system_program};
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
 
 #[derive(Accounts)]
 pub struct VaultTransactionCreateFromBuffer<'info> {
@@ -10,15 +11,13 @@ pub struct VaultTransactionCreateFromBuffer<'info> {
 
     #[account(
         mut,
-        close = creator,
-        // Only the creator can turn the buffer into a transaction and reclaim
-        // the rent
-        constraint = transaction_buffer.creator == creator.key() @ MultisigError::Unauthorized,
+        // Only the creator and the co-writers can turn the buffer into a transaction
+        constraint = transaction_buffer.is_writer(&creator.key()) @ MultisigError::Unauthorized,
         seeds = [
             SEED_PREFIX,
             vault_transaction_create.multisig.key().as_ref(),
             SEED_TRANSACTION_BUFFER,
-            creator.key().as_ref(),
+            transaction_buffer.creator.as_ref(),
             &transaction_buffer.buffer_index.to_le_bytes(),
         ],
         bump
@@ -32,79 +31,85 @@ pub struct VaultTransactionCreateFromBuffer<'info> {
         address = vault_transaction_create.creator.key(),
     )]
     pub creator: Signer<'info>,
+
+    /// The member who created the `transaction_buffer`, receives the buffer rent once it's closed.
+    /// Only required if the `creator` signer is one of the co-writers.
+    /// CHECK: Validated against `transaction_buffer.creator`.
+    #[account(mut)]
+    pub buffer_creator: Option<AccountInfo<'info>>,
 }
 
-impl<'info> VaultTransactionCreateFromBuffer<'info> {
-    pub fn validate(&self, args: &VaultTransactionCreateArgs) -> Result<()> {
-        let transaction_buffer_account = &self.transaction_buffer;
+/// `VaultTransactionCreateFromBuffer` creating the transaction with `VaultTransactionCreateV2`.
+#[derive(Accounts)]
+pub struct VaultTransactionCreateFromBufferV2<'info> {
+    // The context needed for the VaultTransactionCreateV2 instruction
+    pub vault_transaction_create: VaultTransactionCreateV2<'info>,
 
-        // Check that the transaction message is "empty"
-        require!(
-            args.transaction_message == vec![0, 0, 0, 0, 0, 0],
-            MultisigError::InvalidInstructionArgs
-        );
+    #[account(
+        mut,
+        // Only the creator and the co-writers can turn the buffer into a transaction
+        constraint = transaction_buffer.is_writer(&creator.key()) @ MultisigError::Unauthorized,
+        seeds = [
+            SEED_PREFIX,
+            vault_transaction_create.multisig.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            transaction_buffer.creator.as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction_buffer: Box<Account<'info, TransactionBuffer>>,
 
-        // Validate that the final hash matches the buffer
-        transaction_buffer_account.validate_hash()?;
+    // Anchor doesn't allow us to use the creator inside of
+    // vault_transaction_create, so we just re-pass it here with the same constraint
+    #[account(
+        mut,
+        address = vault_transaction_create.creator.key(),
+    )]
+    pub creator: Signer<'info>,
 
-        // Validate that the final size is correct
-        transaction_buffer_account.validate_size()?;
-        Ok(())
+    /// The member who created the `transaction_buffer`, receives the buffer rent once it's closed.
+    /// Only required if the `creator` signer is one of the co-writers.
+    /// CHECK: Validated against `transaction_buffer.creator`.
+    #[account(mut)]
+    pub buffer_creator: Option<AccountInfo<'info>>,
+}
+
+impl<'info> VaultTransactionCreateFromBuffer<'info> {
+    pub fn validate(&self, args: &VaultTransactionCreateArgs) -> Result<()> {
+        validate_buffer(&self.transaction_buffer, &args.transaction_message)
     }
+
     /// Create a new vault transaction from a completed transaction buffer account.
     #[access_control(ctx.accounts.validate(&args))]
     pub fn vault_transaction_create_from_buffer(
         ctx: Context<'_, '_, 'info, 'info, Self>,
         args: VaultTransactionCreateArgs,
     ) -> Result<()> {
-        // Account infos necessary for reallocation
-        let vault_transaction_account_info = &ctx
-            .accounts
-            .vault_transaction_create
-            .transaction
-            .to_account_info();
-        let rent_payer_account_info = &ctx
-            .accounts
-            .vault_transaction_create
-            .rent_payer
-            .to_account_info();
-
-        let system_program = &ctx.accounts.vault_transaction_create.system_program.to_account_info();
-
-        // Read-only accounts
+        let vault_transaction_create = &ctx.accounts.vault_transaction_create;
         let transaction_buffer = &ctx.accounts.transaction_buffer;
 
-        // Calculate the new required length of the vault transaction account,
-        // since it was initialized with an empty transaction message
-        let new_len =
-            VaultTransaction::size(args.ephemeral_signers, transaction_buffer.buffer.as_slice())?;
-
-        // Calculate the rent exemption for new length
-        let rent_exempt_lamports = Rent::get().unwrap().minimum_balance(new_len).max(1);
-
-        // Check the difference between the rent exemption and the current lamports
-        let top_up_lamports =
-            rent_exempt_lamports.saturating_sub(vault_transaction_account_info.lamports());
-
-        // System Transfer the remaining difference to the vault transaction account
-        let transfer_context = CpiContext::new(
-            system_program.to_account_info(),
-            system_program::Transfer {
-                from: rent_payer_account_info.clone(),
-                to: vault_transaction_account_info.clone(),
-            },
-        );
-        system_program::transfer(transfer_context, top_up_lamports)?;
-
-        // Reallocate the vault transaction account to the new length of the
-        // actual transaction message
-        AccountInfo::realloc(&vault_transaction_account_info, new_len, true)?;
+        let message_fits = realloc_for_buffer(
+            &vault_transaction_create.transaction.to_account_info(),
+            &vault_transaction_create.rent_payer.to_account_info(),
+            &vault_transaction_create.system_program.to_account_info(),
+            VaultTransaction::size(
+                args.ephemeral_signers,
+                transaction_buffer.buffer.as_slice(),
+                &None,
+                &[],
+            )?,
+        )?;
 
         // Create the args for the vault transaction create instruction
         let create_args = VaultTransactionCreateArgs {
             vault_index: args.vault_index,
             ephemeral_signers: args.ephemeral_signers,
-            transaction_message: transaction_buffer.buffer.clone(),
+            transaction_message: if message_fits {
+                transaction_buffer.buffer.clone()
+            } else {
+                args.transaction_message
+            },
             memo: args.memo,
         };
         // Create the context for the vault transaction create instruction
@@ -118,6 +123,144 @@ impl<'info> VaultTransactionCreateFromBuffer<'info> {
         // Call the vault transaction create instruction
         VaultTransactionCreate::vault_transaction_create(context, create_args)?;
 
-        Ok(())
+        release_buffer(
+            &mut ctx.accounts.vault_transaction_create.transaction,
+            &ctx.accounts.transaction_buffer,
+            ctx.accounts.creator.to_account_info(),
+            ctx.accounts.buffer_creator.clone(),
+            message_fits,
+        )
+    }
+}
+
+impl<'info> VaultTransactionCreateFromBufferV2<'info> {
+    pub fn validate(&self, args: &VaultTransactionCreateArgsV2) -> Result<()> {
+        validate_buffer(&self.transaction_buffer, &args.transaction_message)
     }
+
+    /// Create a new vault transaction from a completed transaction buffer account,
+    /// with the arguments of `vault_transaction_create_v2`.
+    #[access_control(ctx.accounts.validate(&args))]
+    pub fn vault_transaction_create_from_buffer_v2(
+        ctx: Context<'_, '_, 'info, 'info, Self>,
+        args: VaultTransactionCreateArgsV2,
+    ) -> Result<()> {
+        let vault_transaction_create = &ctx.accounts.vault_transaction_create;
+        let transaction_buffer = &ctx.accounts.transaction_buffer;
+
+        let message_fits = realloc_for_buffer(
+            &vault_transaction_create.transaction.to_account_info(),
+            &vault_transaction_create.rent_payer.to_account_info(),
+            &vault_transaction_create.system_program.to_account_info(),
+            VaultTransaction::size(
+                args.ephemeral_signers,
+                transaction_buffer.buffer.as_slice(),
+                &args.balance_guards,
+                &args.preconditions,
+            )?,
+        )?;
+
+        // Create the args for the vault transaction create instruction
+        let create_args = VaultTransactionCreateArgsV2 {
+            transaction_message: if message_fits {
+                transaction_buffer.buffer.clone()
+            } else {
+                args.transaction_message.clone()
+            },
+            ..args
+        };
+        // Create the context for the vault transaction create instruction
+        let context = Context::new(
+            ctx.program_id,
+            &mut ctx.accounts.vault_transaction_create,
+            ctx.remaining_accounts,
+            ctx.bumps.vault_transaction_create,
+        );
+
+        // Call the vault transaction create instruction
+        VaultTransactionCreateV2::vault_transaction_create_v2(context, create_args)?;
+
+        release_buffer(
+            &mut ctx.accounts.vault_transaction_create.transaction,
+            &ctx.accounts.transaction_buffer,
+            ctx.accounts.creator.to_account_info(),
+            ctx.accounts.buffer_creator.clone(),
+            message_fits,
+        )
+    }
+}
+
+fn validate_buffer(
+    transaction_buffer: &TransactionBuffer,
+    transaction_message: &[u8],
+) -> Result<()> {
+    // Check that the transaction message is "empty"
+    require!(
+        transaction_message == [0, 0, 0, 0, 0, 0],
+        MultisigError::InvalidInstructionArgs
+    );
+
+    // Validate that the final hash matches the buffer
+    transaction_buffer.validate_hash()?;
+
+    // Validate that the final size is correct
+    transaction_buffer.validate_size()?;
+    Ok(())
+}
+
+/// Grows the `transaction` account, initialized with an empty transaction message,
+/// towards `new_len`, the size needed to fit the message of the buffer.
+/// Returns whether the message fits: the transaction account is created within the instruction,
+/// so it can't be allocated more than MAX_PERMITTED_DATA_INCREASE bytes. Larger transactions
+/// are created with the message pending, and grown by `vault_transaction_extend_from_buffer`.
+fn realloc_for_buffer<'info>(
+    transaction: &AccountInfo<'info>,
+    rent_payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<bool> {
+    let message_fits = new_len <= MAX_PERMITTED_DATA_INCREASE;
+    let new_len = new_len.min(MAX_PERMITTED_DATA_INCREASE);
+
+    // Calculate the rent exemption for new length
+    let rent_exempt_lamports = Rent::get().unwrap().minimum_balance(new_len).max(1);
+
+    // Check the difference between the rent exemption and the current lamports
+    let top_up_lamports = rent_exempt_lamports.saturating_sub(transaction.lamports());
+
+    // System Transfer the remaining difference to the vault transaction account
+    let transfer_context = CpiContext::new(
+        system_program.clone(),
+        system_program::Transfer {
+            from: rent_payer.clone(),
+            to: transaction.clone(),
+        },
+    );
+    system_program::transfer(transfer_context, top_up_lamports)?;
+
+    // Reallocate the vault transaction account to the new length of the
+    // actual transaction message
+    AccountInfo::realloc(transaction, new_len, true)?;
+
+    Ok(message_fits)
+}
+
+/// Closes the `transaction_buffer` if its message has been moved into the `transaction`,
+/// or marks the message of the `transaction` as pending until it is.
+fn release_buffer<'info>(
+    transaction: &mut Account<'info, VaultTransaction>,
+    transaction_buffer: &Account<'info, TransactionBuffer>,
+    creator: AccountInfo<'info>,
+    buffer_creator: Option<AccountInfo<'info>>,
+    message_fits: bool,
+) -> Result<()> {
+    if message_fits {
+        // The buffer is no longer needed, the rent goes back to its creator.
+        TransactionBuffer::close_to_creator(transaction_buffer, creator, buffer_creator)?;
+    } else {
+        // Keep the buffer until the message is moved in.
+        transaction.extension.pending_message_hash = Some(transaction_buffer.final_buffer_hash);
+    }
+
+    Ok(())
 }
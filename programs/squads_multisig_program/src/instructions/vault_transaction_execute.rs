@@ -4,6 +4,12 @@ use crate::errors::*;
 use crate::state::*;
 use crate::utils::*;
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct VaultTransactionExecutePartialArgs {
+    /// Number of the message instructions to execute, starting from the first one not executed yet.
+    pub num_instructions: u8,
+}
+
 #[derive(Accounts)]
 pub struct VaultTransactionExecute<'info> {
     #[account(
@@ -43,113 +49,335 @@ pub struct VaultTransactionExecute<'info> {
     // 1. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
     // 2. Accounts in the order they appear in `message.account_keys`.
     // 3. Accounts in the order they appear in `message.address_table_lookups`.
+    // 4. The `RateLimit` account of the multisig, writable, if it has one.
+    // 5. Accounts referenced by `transaction.extension.preconditions` that are not a part of the message, if any.
 }
 
-impl VaultTransactionExecute<'_> {
-    fn validate(&self) -> Result<()> {
-        let Self {
-            multisig,
-            proposal,
-            member,
-            ..
-        } = self;
-
-        // member
-        require!(
-            multisig.is_member(member.key()).is_some(),
-            MultisigError::NotAMember
-        );
-        require!(
-            multisig.member_has_permission(member.key(), Permission::Execute),
-            MultisigError::Unauthorized
-        );
+/// `VaultTransactionExecute` with the `transaction` writable, to record how many of its instructions were executed.
+#[derive(Accounts)]
+pub struct VaultTransactionExecutePartial<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
 
-        // proposal
-        match proposal.status {
-            ProposalStatus::Approved { timestamp } => {
-                require!(
-                    Clock::get()?.unix_timestamp - timestamp >= i64::from(multisig.time_lock),
-                    MultisigError::TimeLockNotReleased
-                );
-            }
-            _ => return err!(MultisigError::InvalidProposalStatus),
-        }
-        // Stale vault transaction proposals CAN be executed if they were approved
-        // before becoming stale, hence no check for staleness here.
+    /// The proposal account associated with the transaction.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &transaction.index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, Proposal>,
 
-        // `transaction` is validated by its seeds.
+    /// The transaction to execute.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &transaction.index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+    )]
+    pub transaction: Account<'info, VaultTransaction>,
+
+    pub member: Signer<'info>,
+    // `remaining_accounts` must include the following accounts in the exact order:
+    // 1. AddressLookupTable accounts in the order they appear in `message.address_table_lookups`.
+    // 2. Accounts in the order they appear in `message.account_keys`.
+    // 3. Accounts in the order they appear in `message.address_table_lookups`.
+    // 4. The `RateLimit` account of the multisig, writable, if it has one.
+    // 5. Accounts referenced by `transaction.extension.preconditions` that are not a part of the message, if any.
+}
 
-        Ok(())
+impl VaultTransactionExecute<'_> {
+    fn validate(&self) -> Result<()> {
+        validate_execute(
+            &self.multisig,
+            &self.proposal,
+            &self.transaction,
+            &self.member,
+        )
     }
 
     /// Execute the multisig transaction.
     /// The transaction must be `Approved`.
+    /// The `transaction` is read-only here, the proposal status records the execution.
     #[access_control(ctx.accounts.validate())]
     pub fn vault_transaction_execute(ctx: Context<Self>) -> Result<()> {
-        let multisig = &mut ctx.accounts.multisig;
-        let proposal = &mut ctx.accounts.proposal;
+        let accounts = &mut *ctx.accounts;
+        execute_instructions(
+            ctx.program_id,
+            &accounts.multisig,
+            &mut accounts.proposal,
+            &mut accounts.transaction,
+            ctx.remaining_accounts,
+            None,
+        )
+    }
+}
 
-        // NOTE: After `take()` is called, the VaultTransaction is reduced to
-        // its default empty value, which means it should no longer be referenced or
-        // used after this point to avoid faulty behavior.
-        // Instead only make use of the returned `transaction` value.
-        let transaction = ctx.accounts.transaction.take();
+impl VaultTransactionExecutePartial<'_> {
+    fn validate(&self) -> Result<()> {
+        validate_execute(
+            &self.multisig,
+            &self.proposal,
+            &self.transaction,
+            &self.member,
+        )
+    }
 
-        let multisig_key = multisig.key();
-        let transaction_key = ctx.accounts.transaction.key();
+    /// Execute the next `num_instructions` instructions of the multisig transaction,
+    /// so that large transactions can be executed across multiple Solana transactions.
+    /// The transaction must be `Approved`.
+    #[access_control(ctx.accounts.validate())]
+    pub fn vault_transaction_execute_partial(
+        ctx: Context<Self>,
+        args: VaultTransactionExecutePartialArgs,
+    ) -> Result<()> {
+        require_neq!(args.num_instructions, 0, MultisigError::InvalidInstructionArgs);
 
-        let vault_seeds = &[
-            SEED_PREFIX,
-            multisig_key.as_ref(),
-            SEED_VAULT,
-            &transaction.vault_index.to_le_bytes(),
-            &[transaction.vault_bump],
-        ];
-
-        let transaction_message = transaction.message;
-        let num_lookups = transaction_message.address_table_lookups.len();
-
-        let message_account_infos = ctx
-            .remaining_accounts
-            .get(num_lookups..)
-            .ok_or(MultisigError::InvalidNumberOfAccounts)?;
-        let address_lookup_table_account_infos = ctx
-            .remaining_accounts
-            .get(..num_lookups)
-            .ok_or(MultisigError::InvalidNumberOfAccounts)?;
-
-        let vault_pubkey = Pubkey::create_program_address(vault_seeds, ctx.program_id).unwrap();
-
-        let (ephemeral_signer_keys, ephemeral_signer_seeds) =
-            derive_ephemeral_signers(transaction_key, &transaction.ephemeral_signer_bumps);
-
-        let executable_message = ExecutableTransactionMessage::new_validated(
-            transaction_message,
-            message_account_infos,
-            address_lookup_table_account_infos,
-            &vault_pubkey,
-            &ephemeral_signer_keys,
-        )?;
+        let accounts = &mut *ctx.accounts;
+        execute_instructions(
+            ctx.program_id,
+            &accounts.multisig,
+            &mut accounts.proposal,
+            &mut accounts.transaction,
+            ctx.remaining_accounts,
+            Some(args.num_instructions),
+        )
+    }
+}
 
-        let protected_accounts = &[proposal.key()];
-
-        // Execute the transaction message instructions one-by-one.
-        // NOTE: `execute_message()` calls `self.to_instructions_and_accounts()`
-        // which in turn calls `take()` on
-        // `self.message.instructions`, therefore after this point no more
-        // references or usages of `self.message` should be made to avoid
-        // faulty behavior.
-        executable_message.execute_message(
-            vault_seeds,
-            &ephemeral_signer_seeds,
-            protected_accounts,
+fn validate_execute(
+    multisig: &Multisig,
+    proposal: &Proposal,
+    transaction: &VaultTransaction,
+    member: &Signer,
+) -> Result<()> {
+    // member
+    require!(
+        multisig
+            .is_active_member(member.key(), Clock::get()?.unix_timestamp)
+            .is_some(),
+        MultisigError::NotAMember
+    );
+    require!(
+        multisig.member_has_permission(member.key(), Permission::Execute),
+        MultisigError::Unauthorized
+    );
+    require!(
+        multisig.member_has_vault_permission(
+            member.key(),
+            Permission::Execute,
+            transaction.vault_index
+        ),
+        MultisigError::VaultOutOfScope
+    );
+    multisig.check_two_person_rule(member.key(), transaction.creator, proposal)?;
+
+    // proposal
+    match proposal.status {
+        ProposalStatus::Approved { timestamp } => {
+            require!(
+                Clock::get()?.unix_timestamp - timestamp >= i64::from(multisig.time_lock),
+                MultisigError::TimeLockNotReleased
+            );
+        }
+        _ => return err!(MultisigError::InvalidProposalStatus),
+    }
+    // Stale vault transaction proposals CAN be executed if they were approved
+    // before becoming stale, hence no check for staleness here.
+
+    // `transaction` is validated by its seeds.
+    // The message of a transaction created from a large buffer must be in place.
+    require!(
+        transaction.extension.pending_message_hash.is_none(),
+        MultisigError::TransactionMessagePending
+    );
+
+    Ok(())
+}
+
+/// Execute the next `num_instructions` instructions of the transaction, or all the remaining ones if `None`.
+/// The proposal is marked as executed once the last instruction is executed.
+fn execute_instructions(
+    program_id: &Pubkey,
+    multisig: &Account<Multisig>,
+    proposal: &mut Account<Proposal>,
+    transaction_account: &mut Account<VaultTransaction>,
+    remaining_accounts: &[AccountInfo],
+    num_instructions: Option<u8>,
+) -> Result<()> {
+    // NOTE: After `take()` is called, the VaultTransaction is reduced to
+    // its default empty value, which means it should no longer be referenced or
+    // used after this point to avoid faulty behavior.
+    // Instead only make use of the returned `transaction` value,
+    // which is put back once the instructions are executed to record the progress.
+    let mut transaction = transaction_account.take();
+
+    let multisig_key = multisig.key();
+    let transaction_key = transaction_account.key();
+
+    let vault_seeds = &[
+        SEED_PREFIX,
+        multisig_key.as_ref(),
+        SEED_VAULT,
+        &transaction.vault_index.to_le_bytes(),
+        &[transaction.vault_bump],
+    ];
+
+    let total_instructions = transaction.message.instructions.len();
+    let start = usize::from(transaction.extension.executed_instruction_index);
+    let end = match num_instructions {
+        Some(num_instructions) => start + usize::from(num_instructions),
+        None => total_instructions,
+    };
+    require!(end <= total_instructions, MultisigError::InvalidInstructionArgs);
+
+    // Balance guards compare the vault balances before and after the whole transaction,
+    // so transactions with balance guards must be executed at once.
+    // So must the transactions created before the extension of the account,
+    // which has no room to record the progress.
+    let records_progress = transaction.extension.is_stored();
+    if transaction.extension.balance_guards.is_some() || !records_progress {
+        require!(
+            start == 0 && end == total_instructions,
+            MultisigError::PartialExecutionNotAllowed
+        );
+    }
+
+    // Move the instructions out of the transaction and only execute the `start..end` range of them.
+    let instructions = core::mem::take(&mut transaction.message.instructions);
+    let transaction_message = VaultTransactionMessage {
+        instructions: instructions[start..end].to_vec(),
+        ..transaction.message.clone()
+    };
+    let num_lookups = transaction_message.address_table_lookups.len();
+    let num_message_accounts = num_lookups + transaction_message.num_all_account_keys();
+
+    // The rate limit of the multisig follows the message accounts, if it has one.
+    let rate_limit_info = remaining_accounts.get(num_message_accounts);
+    let mut rate_limit = multisig.load_rate_limit(&multisig_key, rate_limit_info)?;
+
+    // Only the rate limit and the accounts referenced by the preconditions can follow the message accounts.
+    require!(
+        remaining_accounts.len()
+            <= num_message_accounts
+                + usize::from(rate_limit.is_some())
+                + transaction.extension.preconditions.len(),
+        MultisigError::InvalidNumberOfAccounts
+    );
+
+    // Make sure the on-chain state the transaction was approved against still holds
+    // before executing its first instruction.
+    if start == 0 {
+        for precondition in &transaction.extension.preconditions {
+            let account = remaining_accounts
+                .iter()
+                .find(|account| account.key == &precondition.account)
+                .ok_or(MultisigError::MissingAccount)?;
+            precondition.check(account)?;
+        }
+    }
+
+    let message_account_infos = remaining_accounts
+        .get(num_lookups..num_message_accounts)
+        .ok_or(MultisigError::InvalidNumberOfAccounts)?;
+    let address_lookup_table_account_infos = remaining_accounts
+        .get(..num_lookups)
+        .ok_or(MultisigError::InvalidNumberOfAccounts)?;
+
+    let vault_pubkey = Pubkey::create_program_address(vault_seeds, program_id).unwrap();
+
+    let (ephemeral_signer_keys, ephemeral_signer_seeds) =
+        derive_ephemeral_signers(transaction_key, &transaction.ephemeral_signer_bumps);
+
+    let executable_message = ExecutableTransactionMessage::new_validated(
+        transaction_message,
+        message_account_infos,
+        address_lookup_table_account_infos,
+        &vault_pubkey,
+        &ephemeral_signer_keys,
+    )?;
+
+    let protected_accounts = &[
+        Some(proposal.key()),
+        rate_limit
+            .as_ref()
+            .and(rate_limit_info)
+            .map(|info| *info.key),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    // Take a snapshot of the vault balances if the transaction has balance guards.
+    let vault_balances =
+        transaction.extension.balance_guards.as_ref().map(|_| {
+            VaultBalances::snapshot(&vault_pubkey, &executable_message.writable_accounts())
+        });
+
+    let program_allowlist = multisig.vault_program_allowlist(transaction.vault_index);
+
+    let vault_lamports_before = vault_lamports(&vault_pubkey, message_account_infos);
+
+    // Execute the transaction message instructions one-by-one.
+    // NOTE: `execute_message()` calls `self.to_instructions_and_accounts()`
+    // which in turn calls `take()` on
+    // `self.message.instructions`, therefore after this point no more
+    // references or usages of `self.message` should be made to avoid
+    // faulty behavior.
+    executable_message.execute_message(
+        vault_seeds,
+        &ephemeral_signer_seeds,
+        protected_accounts,
+        program_allowlist.as_deref(),
+        transaction.extension.allow_vault_account_changes,
+        transaction.extension.deny_ephemeral_signer_account_changes,
+    )?;
+
+    // Make sure the transaction didn't move more out of the vault than its balance guards allow.
+    if let (Some(vault_balances), Some(balance_guards)) =
+        (&vault_balances, &transaction.extension.balance_guards)
+    {
+        vault_balances.check_balance_guards(balance_guards)?;
+    }
+
+    // Record the execution against the rate limit of the multisig, if it has one.
+    // A transaction executed in parts counts as one execution.
+    if let Some(rate_limit) = &mut rate_limit {
+        let vault_lamports_after = vault_lamports(&vault_pubkey, message_account_infos);
+        rate_limit.check_and_record(
+            u32::from(start == 0),
+            vault_lamports_before.saturating_sub(vault_lamports_after),
+            Some(proposal),
+            Clock::get()?.unix_timestamp,
         )?;
+        rate_limit.save(rate_limit_info.unwrap())?;
+    }
 
-        // Mark the proposal as executed.
+    // If the last instruction has been executed, mark the proposal as executed.
+    if end == total_instructions {
         proposal.status = ProposalStatus::Executed {
             timestamp: Clock::get()?.unix_timestamp,
         };
+    }
 
-        Ok(())
+    // Put the transaction back with the executed instructions recorded.
+    transaction.message.instructions = instructions;
+    if records_progress {
+        transaction.extension.executed_instruction_index = u8::try_from(end).unwrap();
     }
+    transaction_account.set_inner(transaction);
+
+    Ok(())
 }
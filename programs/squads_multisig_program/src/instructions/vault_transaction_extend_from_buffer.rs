@@ -0,0 +1,178 @@
+use anchor_lang::{prelude::*, system_program};
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+
+use crate::errors::*;
+use crate::instructions::TransactionMessage;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct VaultTransactionExtendFromBuffer<'info> {
+    #[account(
+        seeds = [SEED_PREFIX, SEED_MULTISIG, multisig.create_key.as_ref()],
+        bump = multisig.bump,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    /// The transaction created from the `transaction_buffer`, with its message still pending.
+    #[account(
+        mut,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION,
+            &transaction.index.to_le_bytes(),
+        ],
+        bump = transaction.bump,
+    )]
+    pub transaction: Account<'info, VaultTransaction>,
+
+    #[account(
+        mut,
+        // Only the creator and the co-writers can move the buffer into the transaction
+        constraint = transaction_buffer.is_writer(&creator.key()) @ MultisigError::Unauthorized,
+        seeds = [
+            SEED_PREFIX,
+            multisig.key().as_ref(),
+            SEED_TRANSACTION_BUFFER,
+            transaction_buffer.creator.as_ref(),
+            &transaction_buffer.buffer_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub transaction_buffer: Box<Account<'info, TransactionBuffer>>,
+
+    /// The member of the multisig moving the buffer into the transaction.
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// The payer for the additional transaction account rent.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// The member who created the `transaction_buffer`, receives the buffer rent once it's closed.
+    /// Only required if the `creator` signer is one of the co-writers.
+    /// CHECK: Validated against `transaction_buffer.creator`.
+    #[account(mut)]
+    pub buffer_creator: Option<AccountInfo<'info>>,
+}
+
+impl VaultTransactionExtendFromBuffer<'_> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            multisig,
+            transaction,
+            transaction_buffer,
+            creator,
+            ..
+        } = self;
+
+        // creator
+        require!(
+            multisig
+                .is_active_member(creator.key(), Clock::get()?.unix_timestamp)
+                .is_some(),
+            MultisigError::NotAMember
+        );
+        require!(
+            multisig.member_has_permission(creator.key(), Permission::Initiate),
+            MultisigError::Unauthorized
+        );
+        require!(
+            multisig.member_has_vault_permission(
+                creator.key(),
+                Permission::Initiate,
+                transaction.vault_index
+            ),
+            MultisigError::VaultOutOfScope
+        );
+
+        // transaction
+        let pending_message_hash = transaction
+            .extension
+            .pending_message_hash
+            .ok_or(MultisigError::TransactionMessageNotPending)?;
+
+        // transaction_buffer
+        require!(
+            transaction_buffer.final_buffer_hash == pending_message_hash,
+            MultisigError::FinalBufferHashMismatch
+        );
+        transaction_buffer.validate_hash()?;
+        transaction_buffer.validate_size()?;
+
+        Ok(())
+    }
+
+    /// Grow the transaction account by as much as a single instruction allows,
+    /// and move the buffer into the transaction message once it fits.
+    #[access_control(ctx.accounts.validate())]
+    pub fn vault_transaction_extend_from_buffer(ctx: Context<Self>) -> Result<()> {
+        let multisig = &ctx.accounts.multisig;
+        let transaction = &mut ctx.accounts.transaction;
+        let transaction_buffer = &ctx.accounts.transaction_buffer;
+
+        let transaction_account_info = transaction.to_account_info();
+
+        // Calculate the length of the transaction account with the message in place.
+        let required_len = VaultTransaction::size(
+            u8::try_from(transaction.ephemeral_signer_bumps.len()).unwrap(),
+            transaction_buffer.buffer.as_slice(),
+            &transaction.extension.balance_guards,
+            &transaction.extension.preconditions,
+        )?;
+
+        // Account data can only grow by MAX_PERMITTED_DATA_INCREASE bytes within an instruction.
+        let current_len = transaction_account_info.data_len();
+        let new_len = required_len.min(current_len + MAX_PERMITTED_DATA_INCREASE);
+
+        if new_len > current_len {
+            // Top up the rent exemption for the new length.
+            let rent_exempt_lamports = Rent::get().unwrap().minimum_balance(new_len).max(1);
+            let top_up_lamports =
+                rent_exempt_lamports.saturating_sub(transaction_account_info.lamports());
+
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.rent_payer.to_account_info(),
+                        to: transaction_account_info.clone(),
+                    },
+                ),
+                top_up_lamports,
+            )?;
+
+            #[allow(deprecated)]
+            AccountInfo::realloc(&transaction_account_info, new_len, true)?;
+        }
+
+        // The transaction needs to grow further before the message fits.
+        if new_len < required_len {
+            return Ok(());
+        }
+
+        let transaction_message =
+            TransactionMessage::deserialize(&mut transaction_buffer.buffer.as_slice())?;
+        transaction.message = transaction_message.try_into()?;
+
+        // Make sure the transaction only invokes the allowed programs, if the allowlist is set.
+        if let Some(program_allowlist) = multisig.vault_program_allowlist(transaction.vault_index) {
+            transaction
+                .message
+                .check_program_allowlist(&program_allowlist)?;
+        }
+
+        transaction.extension.pending_message_hash = None;
+
+        // The buffer is no longer needed, the rent goes back to its creator.
+        TransactionBuffer::close_to_creator(
+            &ctx.accounts.transaction_buffer,
+            ctx.accounts.creator.to_account_info(),
+            ctx.accounts.buffer_creator.clone(),
+        )?;
+
+        Ok(())
+    }
+}
@@ -138,6 +138,79 @@ pub mod squads_multisig_program {
         MultisigConfig::multisig_set_rent_collector(ctx, args)
     }
 
+    /// Set the `program_allowlist` config parameter of the controlled multisig.
+    pub fn multisig_set_program_allowlist(
+        ctx: Context<MultisigConfig>,
+        args: MultisigSetProgramAllowlistArgs,
+    ) -> Result<()> {
+        MultisigConfig::multisig_set_program_allowlist(ctx, args)
+    }
+
+    /// Set the `two_person_rule` config parameter of the controlled multisig.
+    pub fn multisig_set_two_person_rule(
+        ctx: Context<MultisigConfig>,
+        args: MultisigSetTwoPersonRuleArgs,
+    ) -> Result<()> {
+        MultisigConfig::multisig_set_two_person_rule(ctx, args)
+    }
+
+    /// Set whether the controlled multisig refunds the rent of closed transaction accounts
+    /// to the accounts that paid it when it has no `rent_collector`.
+    pub fn multisig_set_rent_refund(
+        ctx: Context<MultisigConfig>,
+        args: MultisigSetRentRefundArgs,
+    ) -> Result<()> {
+        MultisigConfig::multisig_set_rent_refund(ctx, args)
+    }
+
+    /// Close the controlled multisig account and send its rent to `rent_destination`.
+    pub fn multisig_close<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MultisigConfig<'info>>,
+        args: MultisigCloseArgs,
+    ) -> Result<()> {
+        MultisigConfig::multisig_close(ctx, args)
+    }
+
+    /// Record the transactions of the multisig whose accounts have all been closed.
+    /// Anyone can call this instruction.
+    pub fn multisig_record_closed_transactions(
+        ctx: Context<MultisigRecordClosedTransactions>,
+    ) -> Result<()> {
+        MultisigRecordClosedTransactions::multisig_record_closed_transactions(ctx)
+    }
+
+    /// Restrict the permissions of a member of the controlled multisig to a subset of the vaults.
+    pub fn multisig_set_member_vault_scope(
+        ctx: Context<MultisigConfig>,
+        args: MultisigSetMemberVaultScopeArgs,
+    ) -> Result<()> {
+        MultisigConfig::multisig_set_member_vault_scope(ctx, args)
+    }
+
+    /// Set the timestamp after which a member of the controlled multisig is treated as a non-member.
+    pub fn multisig_set_member_valid_until(
+        ctx: Context<MultisigConfig>,
+        args: MultisigSetMemberValidUntilArgs,
+    ) -> Result<()> {
+        MultisigConfig::multisig_set_member_valid_until(ctx, args)
+    }
+
+    /// Set the passkey a member of the controlled multisig votes with.
+    pub fn multisig_set_member_passkey(
+        ctx: Context<MultisigConfig>,
+        args: MultisigSetMemberPasskeyArgs,
+    ) -> Result<()> {
+        MultisigConfig::multisig_set_member_passkey(ctx, args)
+    }
+
+    /// Remove the members whose `valid_until` timestamp has passed.
+    /// Anyone can call this instruction.
+    pub fn multisig_remove_expired_members(
+        ctx: Context<MultisigRemoveExpiredMembers>,
+    ) -> Result<()> {
+        MultisigRemoveExpiredMembers::multisig_remove_expired_members(ctx)
+    }
+
     /// Create a new spending limit for the controlled multisig.
     pub fn multisig_add_spending_limit(
         ctx: Context<MultisigAddSpendingLimit>,
@@ -154,6 +227,39 @@ pub mod squads_multisig_program {
         MultisigRemoveSpendingLimit::multisig_remove_spending_limit(ctx, args)
     }
 
+    /// Create a new allowance for the controlled multisig.
+    pub fn multisig_add_allowance(
+        ctx: Context<MultisigAddAllowance>,
+        args: MultisigAddAllowanceArgs,
+    ) -> Result<()> {
+        MultisigAddAllowance::multisig_add_allowance(ctx, args)
+    }
+
+    /// Remove the allowance from the controlled multisig.
+    pub fn multisig_remove_allowance(
+        ctx: Context<MultisigRemoveAllowance>,
+        args: MultisigRemoveAllowanceArgs,
+    ) -> Result<()> {
+        MultisigRemoveAllowance::multisig_remove_allowance(ctx, args)
+    }
+
+    /// Set the rate limit of the controlled multisig.
+    /// The lamports limit only counts the native lamports moved out of the vaults, not tokens.
+    pub fn multisig_set_rate_limit(
+        ctx: Context<MultisigSetRateLimit>,
+        args: MultisigSetRateLimitArgs,
+    ) -> Result<()> {
+        MultisigSetRateLimit::multisig_set_rate_limit(ctx, args)
+    }
+
+    /// Remove the rate limit of the controlled multisig.
+    pub fn multisig_remove_rate_limit(
+        ctx: Context<MultisigRemoveRateLimit>,
+        args: MultisigRemoveRateLimitArgs,
+    ) -> Result<()> {
+        MultisigRemoveRateLimit::multisig_remove_rate_limit(ctx, args)
+    }
+
     /// Create a new config transaction.
     pub fn config_transaction_create(
         ctx: Context<ConfigTransactionCreate>,
@@ -178,6 +284,15 @@ pub mod squads_multisig_program {
         VaultTransactionCreate::vault_transaction_create(ctx, args)
     }
 
+    /// Create a new vault transaction with balance guards, preconditions and the account change flags.
+    /// The `creator` can be a session key acting on behalf of a member.
+    pub fn vault_transaction_create_v2(
+        ctx: Context<VaultTransactionCreateV2>,
+        args: VaultTransactionCreateArgsV2,
+    ) -> Result<()> {
+        VaultTransactionCreateV2::vault_transaction_create_v2(ctx, args)
+    }
+
     /// Create a transaction buffer account.
     pub fn transaction_buffer_create(
         ctx: Context<TransactionBufferCreate>,
@@ -192,6 +307,7 @@ pub mod squads_multisig_program {
     }
 
     /// Extend a transaction buffer account.
+    /// The buffer can be extended by its creator and its co-writers.
     pub fn transaction_buffer_extend(
         ctx: Context<TransactionBufferExtend>,
         args: TransactionBufferExtendArgs,
@@ -199,8 +315,19 @@ pub mod squads_multisig_program {
         TransactionBufferExtend::transaction_buffer_extend(ctx, args)
     }
 
+    /// Add co-writers to a transaction buffer account.
+    /// Co-writers can extend the buffer and create the transaction from it, like its creator.
+    pub fn transaction_buffer_add_co_writers(
+        ctx: Context<TransactionBufferAddCoWriters>,
+        args: TransactionBufferAddCoWritersArgs,
+    ) -> Result<()> {
+        TransactionBufferAddCoWriters::transaction_buffer_add_co_writers(ctx, args)
+    }
+
     /// Create a new vault transaction from a completed transaction buffer.
     /// Finalized buffer hash must match `final_buffer_hash`
+    /// If the transaction account can't be allocated within this instruction, the transaction is created
+    /// with its message pending, to be moved in by `vault_transaction_extend_from_buffer`.
     pub fn vault_transaction_create_from_buffer<'info>(
         ctx: Context<'_, '_, 'info, 'info, VaultTransactionCreateFromBuffer<'info>>,
         args: VaultTransactionCreateArgs,
@@ -208,12 +335,54 @@ pub mod squads_multisig_program {
         VaultTransactionCreateFromBuffer::vault_transaction_create_from_buffer(ctx, args)
     }
 
+    /// Create a new vault transaction from a completed transaction buffer,
+    /// with the arguments of `vault_transaction_create_v2`.
+    pub fn vault_transaction_create_from_buffer_v2<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VaultTransactionCreateFromBufferV2<'info>>,
+        args: VaultTransactionCreateArgsV2,
+    ) -> Result<()> {
+        VaultTransactionCreateFromBufferV2::vault_transaction_create_from_buffer_v2(ctx, args)
+    }
+
+    /// Grow a vault transaction created from a large transaction buffer, and move the buffer
+    /// into its message once it fits. Must be called until the message is in place,
+    /// the transaction can't be executed before.
+    pub fn vault_transaction_extend_from_buffer(
+        ctx: Context<VaultTransactionExtendFromBuffer>,
+    ) -> Result<()> {
+        VaultTransactionExtendFromBuffer::vault_transaction_extend_from_buffer(ctx)
+    }
+
+    /// Create a new endorsement: a transaction casting the vote of a vault of the multisig
+    /// on a proposal of a parent multisig the vault is a member of.
+    pub fn endorsement_create(
+        ctx: Context<EndorsementCreate>,
+        args: EndorsementCreateArgs,
+    ) -> Result<()> {
+        EndorsementCreate::endorsement_create(ctx, args)
+    }
+
+    /// Execute an endorsement, casting the vote of its vault on the parent multisig proposal.
+    /// The endorsement must be `Approved`.
+    pub fn endorsement_execute(ctx: Context<EndorsementExecute>) -> Result<()> {
+        EndorsementExecute::endorsement_execute(ctx)
+    }
+
     /// Execute a vault transaction.
     /// The transaction must be `Approved`.
     pub fn vault_transaction_execute(ctx: Context<VaultTransactionExecute>) -> Result<()> {
         VaultTransactionExecute::vault_transaction_execute(ctx)
     }
 
+    /// Execute the next `num_instructions` instructions of a vault transaction.
+    /// The transaction must be `Approved`, and becomes `Executed` after its last instruction is executed.
+    pub fn vault_transaction_execute_partial(
+        ctx: Context<VaultTransactionExecutePartial>,
+        args: VaultTransactionExecutePartialArgs,
+    ) -> Result<()> {
+        VaultTransactionExecutePartial::vault_transaction_execute_partial(ctx, args)
+    }
+
     /// Create a new batch.
     pub fn batch_create(ctx: Context<BatchCreate>, args: BatchCreateArgs) -> Result<()> {
         BatchCreate::batch_create(ctx, args)
@@ -227,11 +396,35 @@ pub mod squads_multisig_program {
         BatchAddTransaction::batch_add_transaction(ctx, args)
     }
 
+    /// Add a transaction with balance guards and the account change flags to the batch.
+    pub fn batch_add_transaction_v2(
+        ctx: Context<BatchAddTransactionV2>,
+        args: BatchAddTransactionArgsV2,
+    ) -> Result<()> {
+        BatchAddTransactionV2::batch_add_transaction_v2(ctx, args)
+    }
+
+    /// Add a transaction to the batch from a completed transaction buffer.
+    /// Finalized buffer hash must match `final_buffer_hash`
+    pub fn batch_add_transaction_from_buffer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchAddTransactionFromBuffer<'info>>,
+        args: BatchAddTransactionArgsV2,
+    ) -> Result<()> {
+        BatchAddTransactionFromBuffer::batch_add_transaction_from_buffer(ctx, args)
+    }
+
     /// Execute a transaction from the batch.
     pub fn batch_execute_transaction(ctx: Context<BatchExecuteTransaction>) -> Result<()> {
         BatchExecuteTransaction::batch_execute_transaction(ctx)
     }
 
+    /// Execute consecutive transactions from the batch in one instruction.
+    pub fn batch_execute_transactions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchExecuteTransactions<'info>>,
+    ) -> Result<()> {
+        BatchExecuteTransactions::batch_execute_transactions(ctx)
+    }
+
     /// Create a new multisig proposal.
     pub fn proposal_create(ctx: Context<ProposalCreate>, args: ProposalCreateArgs) -> Result<()> {
         ProposalCreate::proposal_create(ctx, args)
@@ -242,6 +435,12 @@ pub mod squads_multisig_program {
         ProposalActivate::proposal_activate(ctx)
     }
 
+    /// Rebase a stale vault transaction proposal onto the current multisig config.
+    /// The proposal must be `Draft` or `Active`, and becomes `Active` again with all the votes cleared.
+    pub fn proposal_rebase(ctx: Context<ProposalRebase>, args: ProposalRebaseArgs) -> Result<()> {
+        ProposalRebase::proposal_rebase(ctx, args)
+    }
+
     /// Approve a multisig proposal on behalf of the `member`.
     /// The proposal must be `Active`.
     pub fn proposal_approve(ctx: Context<ProposalVote>, args: ProposalVoteArgs) -> Result<()> {
@@ -254,14 +453,74 @@ pub mod squads_multisig_program {
         ProposalVote::proposal_reject(ctx, args)
     }
 
+    /// Approve a multisig proposal on behalf of the `member`, or of the member whose session key signs.
+    /// The proposal must be `Active`.
+    /// Takes the `transaction` of the proposal, needed when the `Vote` permission of any member
+    /// is scoped to particular vaults.
+    pub fn proposal_approve_v2(ctx: Context<ProposalVoteV2>, args: ProposalVoteArgs) -> Result<()> {
+        ProposalVoteV2::proposal_approve_v2(ctx, args)
+    }
+
+    /// Reject a multisig proposal on behalf of the `member`, or of the member whose session key signs.
+    /// The proposal must be `Active`.
+    /// Takes the `transaction` of the proposal, needed when the `Vote` permission of any member
+    /// is scoped to particular vaults.
+    pub fn proposal_reject_v2(ctx: Context<ProposalVoteV2>, args: ProposalVoteArgs) -> Result<()> {
+        ProposalVoteV2::proposal_reject_v2(ctx, args)
+    }
+
+    /// Abstain from voting on a multisig proposal on behalf of the `member`.
+    /// The proposal must be `Active`.
+    pub fn proposal_abstain(ctx: Context<ProposalVoteV2>, args: ProposalVoteArgs) -> Result<()> {
+        ProposalVoteV2::proposal_abstain(ctx, args)
+    }
+
+    /// Withdraw the vote of the `member` from a multisig proposal.
+    /// Votes can be withdrawn from `Active` proposals, and cancellations from `Approved` ones.
+    pub fn proposal_withdraw_vote(
+        ctx: Context<ProposalVoteV2>,
+        args: ProposalVoteArgs,
+    ) -> Result<()> {
+        ProposalVoteV2::proposal_withdraw_vote(ctx, args)
+    }
+
+    /// Approve several multisig proposals on behalf of the `member`.
+    /// The proposals must be `Active`.
+    pub fn proposal_approve_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProposalVoteMany<'info>>,
+        args: ProposalVoteArgs,
+    ) -> Result<()> {
+        ProposalVoteMany::proposal_approve_many(ctx, args)
+    }
+
+    /// Reject several multisig proposals on behalf of the `member`.
+    /// The proposals must be `Active`.
+    pub fn proposal_reject_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProposalVoteMany<'info>>,
+        args: ProposalVoteArgs,
+    ) -> Result<()> {
+        ProposalVoteMany::proposal_reject_many(ctx, args)
+    }
+
+    /// Cancel several multisig proposals on behalf of the `member`.
+    /// The proposals must be `Approved`.
+    pub fn proposal_cancel_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProposalVoteMany<'info>>,
+        args: ProposalVoteArgs,
+    ) -> Result<()> {
+        ProposalVoteMany::proposal_cancel_many(ctx, args)
+    }
+
     /// Cancel a multisig proposal on behalf of the `member`.
     /// The proposal must be `Approved`.
+    /// Cancelling the proposal of a partially executed `Batch` aborts the remaining transactions of the batch.
     pub fn proposal_cancel(ctx: Context<ProposalVote>, args: ProposalVoteArgs) -> Result<()> {
         ProposalVote::proposal_cancel(ctx, args)
     }
 
     /// Cancel a multisig proposal on behalf of the `member`.
     /// The proposal must be `Approved`.
+    /// Cancelling the proposal of a partially executed `Batch` aborts the remaining transactions of the batch.
     /// This was introduced to incorporate proper state update, as old multisig members
     /// may have lingering votes, and the proposal size may need to be reallocated to
     /// accommodate the new amount of cancel votes.
@@ -274,6 +533,41 @@ pub mod squads_multisig_program {
         ProposalCancelV2::proposal_cancel_v2(ctx, args)
     }
 
+    /// Cancel a multisig proposal on behalf of the `member`, or of the member whose session key signs.
+    /// The proposal must be `Approved`.
+    /// Cancelling the proposal of a partially executed `Batch` aborts the remaining transactions of the batch.
+    /// Takes the `transaction` of the proposal, needed when the `Vote` permission of any member
+    /// is scoped to particular vaults, and reallocates the proposal like `proposal_cancel_v2`.
+    pub fn proposal_cancel_v3(ctx: Context<ProposalVoteV2>, args: ProposalVoteArgs) -> Result<()> {
+        ProposalVoteV2::proposal_cancel_v3(ctx, args)
+    }
+
+    /// Cast a vote on a multisig proposal on behalf of a passkey member,
+    /// authorized by a secp256r1 signature verification instruction preceding this one.
+    pub fn proposal_vote_passkey(
+        ctx: Context<ProposalVotePasskey>,
+        args: ProposalVotePasskeyArgs,
+    ) -> Result<()> {
+        ProposalVotePasskey::proposal_vote_passkey(ctx, args)
+    }
+
+    /// Register a session key that can vote and create vault transactions on behalf of the `member`
+    /// for the specified actions, until it expires.
+    pub fn session_key_create(
+        ctx: Context<SessionKeyCreate>,
+        args: SessionKeyCreateArgs,
+    ) -> Result<()> {
+        SessionKeyCreate::session_key_create(ctx, args)
+    }
+
+    /// Revoke a session key of the `member`.
+    pub fn session_key_revoke(
+        ctx: Context<SessionKeyRevoke>,
+        args: SessionKeyRevokeArgs,
+    ) -> Result<()> {
+        SessionKeyRevoke::session_key_revoke(ctx, args)
+    }
+
     /// Use a spending limit to transfer tokens from a multisig vault to a destination account.
     pub fn spending_limit_use(
         ctx: Context<SpendingLimitUse>,
@@ -282,6 +576,20 @@ pub mod squads_multisig_program {
         SpendingLimitUse::spending_limit_use(ctx, args)
     }
 
+    /// Use an allowance to execute an instruction matching its template from a multisig vault
+    /// without a proposal.
+    pub fn allowance_use<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AllowanceUse<'info>>,
+        args: AllowanceUseArgs,
+    ) -> Result<()> {
+        AllowanceUse::allowance_use(ctx, args)
+    }
+
+    /// Claim the vested amount of a stream, transferring it from a multisig vault to the stream recipient.
+    pub fn stream_claim(ctx: Context<StreamClaim>, args: StreamClaimArgs) -> Result<()> {
+        StreamClaim::stream_claim(ctx, args)
+    }
+
     /// Closes a `ConfigTransaction` and the corresponding `Proposal`.
     /// `transaction` can be closed if either:
     /// - the `proposal` is in a terminal state: `Executed`, `Rejected`, or `Cancelled`.
@@ -302,11 +610,28 @@ pub mod squads_multisig_program {
         VaultTransactionAccountsClose::vault_transaction_accounts_close(ctx)
     }
 
+    /// Closes an `Endorsement` and the corresponding `Proposal`.
+    /// `endorsement` can be closed on the same conditions as in `vault_transaction_accounts_close`.
+    pub fn endorsement_accounts_close(ctx: Context<EndorsementAccountsClose>) -> Result<()> {
+        EndorsementAccountsClose::endorsement_accounts_close(ctx)
+    }
+
+    /// Closes multiple `VaultTransaction`s and the corresponding `Proposal`s at once.
+    /// Each `transaction` can be closed on the same conditions as in `vault_transaction_accounts_close`,
+    /// and the rent goes to the `rent_collector`, or to the account that paid the rent of all of them
+    /// if the multisig refunds rent to the payers.
+    pub fn transactions_accounts_close_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TransactionsAccountsCloseMany<'info>>,
+    ) -> Result<()> {
+        TransactionsAccountsCloseMany::transactions_accounts_close_many(ctx)
+    }
+
     /// Closes a `VaultBatchTransaction` belonging to the `batch` and `proposal`.
     /// `transaction` can be closed if either:
     /// - it's marked as executed within the `batch`;
     /// - the `proposal` is in a terminal state: `Executed`, `Rejected`, or `Cancelled`.
     /// - the `proposal` is stale and not `Approved`.
+    /// This includes the transactions left unexecuted in a batch whose proposal was cancelled midway.
     pub fn vault_batch_transaction_account_close(
         ctx: Context<VaultBatchTransactionAccountClose>,
     ) -> Result<()> {
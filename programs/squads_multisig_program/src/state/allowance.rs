@@ -0,0 +1,262 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::Period;
+
+/// Pre-approved instruction template that designated members can execute from a vault without a vote.
+#[account]
+pub struct Allowance {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+
+    /// Key that is used to seed the Allowance PDA.
+    pub create_key: Pubkey,
+
+    /// The index of the vault that the allowance is for.
+    pub vault_index: u8,
+
+    /// The template the executed instructions must match.
+    pub template: InstructionTemplate,
+
+    /// The number of times the allowance can be used in a period.
+    pub max_uses: u32,
+
+    /// The reset period of the allowance.
+    /// When it passes, the remaining uses are reset, unless it's `Period::OneTime`.
+    pub period: Period,
+
+    /// The remaining number of times the allowance can be used in the current period.
+    pub remaining_uses: u32,
+
+    /// Unix timestamp marking the last time the allowance was reset (or created).
+    pub last_reset: i64,
+
+    /// Unix timestamp after which the allowance can no longer be used.
+    /// `None` means the allowance doesn't expire.
+    pub expires_at: Option<i64>,
+
+    /// PDA bump.
+    pub bump: u8,
+
+    /// Members of the allowance that can use it.
+    /// Unlike with spending limits, they must also be members of the multisig
+    /// that haven't expired at the time of use, so removing them from the multisig revokes their access.
+    /// To change who can use the allowance: close and re-initialize it.
+    pub members: Vec<Pubkey>,
+}
+
+impl Allowance {
+    pub fn size(template: &InstructionTemplate, members_length: usize) -> usize {
+        8  + // anchor discriminator
+        32 + // multisig
+        32 + // create_key
+        1  + // vault_index
+        template.size() + // template
+        4  + // max_uses
+        1  + // period
+        4  + // remaining_uses
+        8  + // last_reset
+        1 + 8 + // expires_at
+        1  + // bump
+        4  + // members vector length
+        members_length * 32 // members
+    }
+
+    pub fn invariant(&self) -> Result<()> {
+        // Max uses must be a non-zero value.
+        require_neq!(self.max_uses, 0, MultisigError::AllowanceInvalidMaxUses);
+
+        require!(!self.members.is_empty(), MultisigError::EmptyMembers);
+
+        // There must be no duplicate members, we make sure members are sorted when creating an Allowance.
+        let has_duplicates = self.members.windows(2).any(|win| win[0] == win[1]);
+        require!(!has_duplicates, MultisigError::DuplicateMember);
+
+        self.template.invariant()?;
+
+        Ok(())
+    }
+}
+
+/// Describes the instructions an allowance permits.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InstructionTemplate {
+    /// The program the instruction must invoke.
+    pub program_id: Pubkey,
+    /// Whether each of the instruction accounts is writable.
+    /// The instruction must have exactly as many accounts as there are flags.
+    pub writable_flags: Vec<bool>,
+    /// Accounts that must be at the specified positions of the instruction accounts.
+    /// Positions not listed can be any account.
+    pub fixed_accounts: Vec<FixedAccount>,
+    /// Bytes the instruction data must start with, compared under `data_mask`.
+    pub data_pattern: Vec<u8>,
+    /// Mask applied to both the instruction data and `data_pattern` before comparing them.
+    /// Must be the same length as `data_pattern`; a mask of all `0xff` makes the pattern a plain prefix.
+    pub data_mask: Vec<u8>,
+}
+
+impl InstructionTemplate {
+    pub fn size(&self) -> usize {
+        32 + // program_id
+        4 + self.writable_flags.len() + // writable_flags
+        4 + self.fixed_accounts.len() * FixedAccount::INIT_SPACE + // fixed_accounts
+        4 + self.data_pattern.len() + // data_pattern
+        4 + self.data_mask.len() // data_mask
+    }
+
+    pub fn invariant(&self) -> Result<()> {
+        require_eq!(
+            self.data_pattern.len(),
+            self.data_mask.len(),
+            MultisigError::InvalidInstructionTemplate
+        );
+
+        // There must be at most one fixed account per position, within the instruction accounts.
+        for (index, fixed_account) in self.fixed_accounts.iter().enumerate() {
+            require!(
+                usize::from(fixed_account.index) < self.writable_flags.len(),
+                MultisigError::InvalidInstructionTemplate
+            );
+            require!(
+                self.fixed_accounts[..index]
+                    .iter()
+                    .all(|other| other.index != fixed_account.index),
+                MultisigError::InvalidInstructionTemplate
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the instruction invoking `program_id` with `accounts` and `data` matches the template.
+    pub fn check(&self, program_id: &Pubkey, accounts: &[AccountMeta], data: &[u8]) -> Result<()> {
+        require_keys_eq!(
+            *program_id,
+            self.program_id,
+            MultisigError::InstructionNotMatchingTemplate
+        );
+
+        require_eq!(
+            accounts.len(),
+            self.writable_flags.len(),
+            MultisigError::InstructionNotMatchingTemplate
+        );
+        let writable_flags_match = accounts
+            .iter()
+            .zip(&self.writable_flags)
+            .all(|(account, is_writable)| account.is_writable == *is_writable);
+        require!(
+            writable_flags_match,
+            MultisigError::InstructionNotMatchingTemplate
+        );
+
+        for fixed_account in &self.fixed_accounts {
+            require_keys_eq!(
+                accounts[usize::from(fixed_account.index)].pubkey,
+                fixed_account.pubkey,
+                MultisigError::InstructionNotMatchingTemplate
+            );
+        }
+
+        require!(
+            data.len() >= self.data_pattern.len(),
+            MultisigError::InstructionNotMatchingTemplate
+        );
+        let data_matches = data
+            .iter()
+            .zip(self.data_pattern.iter().zip(&self.data_mask))
+            .all(|(byte, (pattern, mask))| byte & mask == pattern & mask);
+        require!(data_matches, MultisigError::InstructionNotMatchingTemplate);
+
+        Ok(())
+    }
+}
+
+/// An account that must be at the `index` position of the instruction accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedAccount {
+    pub index: u8,
+    pub pubkey: Pubkey,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_template_check() {
+        let program_id = Pubkey::new_unique();
+        let oracle = Pubkey::new_unique();
+        let template = InstructionTemplate {
+            program_id,
+            writable_flags: vec![true, false],
+            fixed_accounts: vec![FixedAccount {
+                index: 1,
+                pubkey: oracle,
+            }],
+            data_pattern: vec![7, 0],
+            data_mask: vec![0xff, 0x0f],
+        };
+        template.invariant().unwrap();
+
+        let writable = Pubkey::new_unique();
+        let accounts = [
+            AccountMeta::new(writable, false),
+            AccountMeta::new_readonly(oracle, false),
+        ];
+        template
+            .check(&program_id, &accounts, &[7, 0xf0, 1])
+            .unwrap();
+
+        // Data not matching the masked pattern.
+        assert!(template.check(&program_id, &accounts, &[7, 1]).is_err());
+        assert!(template.check(&program_id, &accounts, &[7]).is_err());
+        // Another program.
+        assert!(template
+            .check(&Pubkey::new_unique(), &accounts, &[7, 0])
+            .is_err());
+        // Another fixed account.
+        let other_oracle = [
+            accounts[0].clone(),
+            AccountMeta::new_readonly(writable, false),
+        ];
+        assert!(template.check(&program_id, &other_oracle, &[7, 0]).is_err());
+        // The fixed account passed as writable.
+        let writable_oracle = [accounts[0].clone(), AccountMeta::new(oracle, false)];
+        assert!(template
+            .check(&program_id, &writable_oracle, &[7, 0])
+            .is_err());
+        // An extra account.
+        let extra_account = [
+            accounts[0].clone(),
+            accounts[1].clone(),
+            AccountMeta::new(Pubkey::new_unique(), false),
+        ];
+        assert!(template
+            .check(&program_id, &extra_account, &[7, 0])
+            .is_err());
+    }
+
+    #[test]
+    fn test_template_invariant() {
+        let mut template = InstructionTemplate {
+            program_id: Pubkey::new_unique(),
+            writable_flags: vec![false],
+            fixed_accounts: vec![FixedAccount {
+                index: 1,
+                pubkey: Pubkey::new_unique(),
+            }],
+            data_pattern: vec![],
+            data_mask: vec![],
+        };
+        // The fixed account is out of the instruction accounts.
+        assert!(template.invariant().is_err());
+
+        template.writable_flags.push(true);
+        template.invariant().unwrap();
+
+        template.data_pattern.push(1);
+        assert!(template.invariant().is_err());
+    }
+}
@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 #[allow(deprecated)]
 use solana_borsh::v0_10::get_instance_packed_len;
 
-use crate::{TransactionMessage, VaultTransactionMessage};
+use crate::utils::AccountExtension;
+use crate::{BalanceGuards, TransactionMessage, VaultTransactionMessage};
 
 /// Stores data required for serial execution of a batch of multisig vault transactions.
 /// Vault transaction is a transaction that's executed on behalf of the multisig vault PDA
@@ -28,6 +29,17 @@ pub struct Batch {
     /// Index of the last executed transaction within the batch.
     /// 0 means that no transactions have been executed yet.
     pub executed_transaction_index: u32,
+    /// Fields added after the original layout of the account.
+    pub extension: AccountExtension<BatchExtension>,
+}
+
+/// Fields of `Batch` added after the original layout of the account.
+/// Batches created before have the default values.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Default, Debug, PartialEq, Eq)]
+pub struct BatchExtension {
+    /// The account that paid the rent for this account, refunded on close
+    /// if the multisig has no `rent_collector`.
+    pub rent_payer: Pubkey,
 }
 
 impl Batch {
@@ -55,20 +67,50 @@ pub struct VaultBatchTransaction {
     pub ephemeral_signer_bumps: Vec<u8>,
     /// data required for executing the transaction.
     pub message: VaultTransactionMessage,
+    /// Fields added after the original layout of the account.
+    pub extension: AccountExtension<VaultBatchTransactionExtension>,
+}
+
+/// Fields of `VaultBatchTransaction` added after the original layout of the account.
+/// Transactions created before have the default values.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct VaultBatchTransactionExtension {
+    /// Assertions on the vault balances checked after the transaction is executed.
+    pub balance_guards: Option<BalanceGuards>,
+    /// Whether the vault PDA is allowed to be assigned to another program
+    /// or to have data allocated on it during execution.
+    pub allow_vault_account_changes: bool,
+    /// Whether the ephemeral signer PDAs must stay owned by the System Program with no data
+    /// after execution. Off by default, as creating a mint or a token account at an ephemeral
+    /// signer is what they are for.
+    pub deny_ephemeral_signer_account_changes: bool,
+    /// The account that paid the rent for this account, refunded on close
+    /// if the multisig has no `rent_collector`.
+    pub rent_payer: Pubkey,
 }
 
 impl VaultBatchTransaction {
-    pub fn size(ephemeral_signers_length: u8, transaction_message: &[u8]) -> Result<usize> {
+    pub fn size(
+        ephemeral_signers_length: u8,
+        transaction_message: &[u8],
+        balance_guards: &Option<BalanceGuards>,
+    ) -> Result<usize> {
         let transaction_message: VaultTransactionMessage =
             TransactionMessage::deserialize(&mut &transaction_message[..])?.try_into()?;
 
         let message_size = get_instance_packed_len(&transaction_message).unwrap_or_default();
+        let balance_guards_size = 1 + balance_guards.as_ref().map_or(0, BalanceGuards::size);
 
         Ok(
             8 +   // anchor account discriminator
             1 +   // bump
             (4 + usize::from(ephemeral_signers_length)) +   // ephemeral_signers_bumps vec
-            message_size, // message
+            message_size + // message
+            8 + // extension prefix
+            balance_guards_size + // balance_guards
+            1 + // allow_vault_account_changes
+            1 + // deny_ephemeral_signer_account_changes
+            32, // rent_payer
         )
     }
 
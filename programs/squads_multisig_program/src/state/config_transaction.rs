@@ -3,6 +3,7 @@ use anchor_lang::prelude::*;
 use solana_borsh::v0_10::get_instance_packed_len;
 
 use super::*;
+use crate::utils::AccountExtension;
 
 /// Stores data required for execution of a multisig configuration transaction.
 /// Config transaction can perform a predefined set of actions on the Multisig PDA, such as adding/removing members,
@@ -19,6 +20,17 @@ pub struct ConfigTransaction {
     pub bump: u8,
     /// Action to be performed on the multisig.
     pub actions: Vec<ConfigAction>,
+    /// Fields added after the original layout of the account.
+    pub extension: AccountExtension<ConfigTransactionExtension>,
+}
+
+/// Fields of `ConfigTransaction` added after the original layout of the account.
+/// Transactions created before have the default values.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Default, Debug, PartialEq, Eq)]
+pub struct ConfigTransactionExtension {
+    /// The account that paid the rent for this account, refunded on close
+    /// if the multisig has no `rent_collector`.
+    pub rent_payer: Pubkey,
 }
 
 impl ConfigTransaction {
@@ -34,7 +46,9 @@ impl ConfigTransaction {
         8 +   // index
         1 +   // bump 
         4 +  // actions vector length
-        actions_size
+        actions_size +
+        8 + // extension prefix
+        32 // rent_payer
     }
 }
 
@@ -80,4 +94,101 @@ pub enum ConfigAction {
     RemoveSpendingLimit { spending_limit: Pubkey },
     /// Set the `rent_collector` config parameter of the multisig.
     SetRentCollector { new_rent_collector: Option<Pubkey> },
+    /// Restrict the permissions of a member to a subset of the vaults.
+    /// `None` lifts the restriction.
+    SetMemberVaultScope {
+        member: Pubkey,
+        vault_scope: Option<VaultScope>,
+    },
+    /// Set the `program_allowlist` config parameter of the multisig.
+    SetProgramAllowlist {
+        new_program_allowlist: Option<Vec<AllowedProgram>>,
+    },
+    /// Set the `two_person_rule` config parameter of the multisig.
+    SetTwoPersonRule { new_two_person_rule: TwoPersonRule },
+    /// Add an allowance to the multisig.
+    AddAllowance {
+        /// Key that is used to seed the Allowance PDA.
+        create_key: Pubkey,
+        /// The index of the vault that the allowance is for.
+        vault_index: u8,
+        /// The template the executed instructions must match.
+        template: InstructionTemplate,
+        /// The number of times the allowance can be used in a period.
+        max_uses: u32,
+        /// The reset period of the allowance.
+        /// When it passes, the remaining uses are reset, unless it's `Period::OneTime`.
+        period: Period,
+        /// Unix timestamp after which the allowance can no longer be used.
+        expires_at: Option<i64>,
+        /// Members that can use the allowance.
+        /// They must also be active members of the multisig at the time of use.
+        members: Vec<Pubkey>,
+    },
+    /// Remove an allowance from the multisig.
+    RemoveAllowance { allowance: Pubkey },
+    /// Add a vault-funded stream paying `total_amount` to the `recipient`, vesting linearly
+    /// between `start_time` and `end_time`.
+    AddStream {
+        /// Key that is used to seed the Stream PDA.
+        create_key: Pubkey,
+        /// The index of the vault that funds the stream.
+        vault_index: u8,
+        /// The account the vested tokens are paid to.
+        recipient: Pubkey,
+        /// The token mint the stream is for.
+        /// Pubkey::default() means SOL.
+        mint: Pubkey,
+        /// The total amount of tokens vested by `end_time`.
+        total_amount: u64,
+        /// Unix timestamp the vesting starts at.
+        start_time: i64,
+        /// Unix timestamp the whole `total_amount` is vested at.
+        end_time: i64,
+        /// Unix timestamp before which nothing can be claimed.
+        cliff_time: Option<i64>,
+    },
+    /// Cancel a stream: the amount vested so far stays claimable by the recipient,
+    /// and the stream is closed once there's nothing left to claim.
+    CancelStream { stream: Pubkey },
+    /// Set the rate limit of the multisig, creating the rate limit account if it doesn't exist.
+    /// The usage recorded in the current period is kept.
+    SetRateLimit {
+        /// Length of the rate limit period in seconds.
+        period: i64,
+        /// The number of vault transactions that can be executed in a period.
+        max_executions: Option<u32>,
+        /// The amount of lamports that can be moved out of the vaults in a period.
+        /// Only the native lamports of the vaults are counted, not their token balances.
+        max_lamports: Option<u64>,
+        /// The number of approvals a proposal needs to be executed over the limits.
+        over_limit_threshold: Option<u16>,
+        /// How many seconds must pass between the approval of a proposal and its execution over the limits.
+        over_limit_time_lock: Option<u32>,
+    },
+    /// Remove the rate limit of the multisig.
+    RemoveRateLimit,
+    /// Close the multisig account, along with the config transaction and its proposal,
+    /// and send the rent to `rent_destination`. Must be the last action of the transaction.
+    CloseMultisig {
+        /// The account to send the rent to.
+        rent_destination: Pubkey,
+        /// The vaults that must be empty for the multisig to be closed.
+        vault_indices: Vec<u8>,
+    },
+    /// Set the timestamp after which a member is treated as a non-member.
+    /// `None` makes the membership permanent.
+    SetMemberValidUntil {
+        member: Pubkey,
+        valid_until: Option<i64>,
+    },
+    /// Set the passkey a member votes with: the compressed secp256r1 public key
+    /// the `member` key is derived from. `None` removes the passkey.
+    SetMemberPasskey {
+        member: Pubkey,
+        passkey: Option<[u8; 33]>,
+    },
+    /// Set whether the rent of closed transaction accounts is refunded to the accounts that paid it
+    /// when the multisig has no `rent_collector`.
+    SetRentRefund { new_refund_rent_to_payers: bool },
 }
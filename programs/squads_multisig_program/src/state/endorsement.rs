@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::Vote;
+
+/// Lightweight transaction through which a multisig votes on a proposal of a parent multisig
+/// that one of its vaults is a member of, without a vault transaction CPI-ing into the program.
+/// It goes through the usual `Proposal` flow of the multisig, and once approved
+/// `endorsement_execute` records the `vote` of the vault on the parent proposal.
+#[account]
+#[derive(InitSpace)]
+pub struct Endorsement {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+    /// Member of the Multisig who submitted the endorsement.
+    pub creator: Pubkey,
+    /// Index of this endorsement within the multisig transactions.
+    pub index: u64,
+    /// bump for the endorsement seeds.
+    pub bump: u8,
+    /// Index of the vault that is the member of the parent multisig.
+    pub vault_index: u8,
+    /// Derivation bump of the vault PDA this endorsement belongs to.
+    pub vault_bump: u8,
+    /// The parent multisig to vote in.
+    pub parent_multisig: Pubkey,
+    /// Index of the parent multisig transaction whose proposal to vote on.
+    pub parent_transaction_index: u64,
+    /// The vote to cast on the parent proposal.
+    pub vote: Vote,
+    /// The account that paid the rent for this account, refunded on close
+    /// if the multisig has no `rent_collector`.
+    pub rent_payer: Pubkey,
+}
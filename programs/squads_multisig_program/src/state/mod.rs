@@ -1,19 +1,35 @@
 pub use self::multisig::*;
+pub use allowance::*;
 pub use batch::*;
 pub use config_transaction::*;
+pub use endorsement::*;
+pub use precondition::*;
+pub use passkey::*;
+pub use program_allowlist::*;
 pub use program_config::*;
 pub use proposal::*;
+pub use rate_limit::*;
 pub use seeds::*;
+pub use session_key::*;
 pub use spending_limit::*;
+pub use stream::*;
 pub use transaction_buffer::*;
 pub use vault_transaction::*;
 
+mod allowance;
 mod batch;
 mod config_transaction;
+mod endorsement;
 mod multisig;
+mod precondition;
+mod passkey;
+mod program_allowlist;
 mod program_config;
 mod proposal;
+mod rate_limit;
 mod seeds;
+mod session_key;
 mod spending_limit;
+mod stream;
 mod transaction_buffer;
 mod vault_transaction;
@@ -5,6 +5,12 @@ use anchor_lang::system_program;
 
 use crate::errors::*;
 use crate::id;
+use crate::state::{
+    AllowedProgram, Passkey, Proposal, RateLimit, SEED_PREFIX, SEED_PROPOSAL, SEED_RATE_LIMIT,
+    SEED_TRANSACTION, SEED_VAULT,
+};
+use crate::utils;
+use crate::utils::AccountExtension;
 
 pub const MAX_TIME_LOCK: u32 = 3 * 30 * 24 * 60 * 60; // 3 months
 
@@ -32,16 +38,18 @@ pub struct Multisig {
     /// This index is updated when multisig config (members/threshold/time_lock) changes.
     pub stale_transaction_index: u64,
     /// The address where the rent for the accounts related to executed, rejected, or cancelled
-    /// transactions can be reclaimed. If set to `None`, the rent reclamation feature is turned off.
+    /// transactions can be reclaimed. If set to `None`, the rent is refunded to whoever paid it.
     pub rent_collector: Option<Pubkey>,
     /// Bump for the multisig PDA seed.
     pub bump: u8,
     /// Members of the multisig.
     pub members: Vec<Member>,
+    /// Config parameters added after the original layout of the account.
+    pub extension: AccountExtension<MultisigExtension>,
 }
 
 impl Multisig {
-    pub fn size(members_length: usize) -> usize {
+    pub fn size(members_length: usize, extension: &AccountExtension<MultisigExtension>) -> usize {
         8  + // anchor account discriminator
         32 + // create_key
         32 + // config_authority
@@ -53,7 +61,8 @@ impl Multisig {
         32 + // rent_collector (always 32 bytes, even if None, just to keep the realloc logic simpler)
         1  + // bump
         4  + // members vector length
-        members_length * Member::INIT_SPACE // members
+        members_length * Member::INIT_SPACE + // members
+        extension.size(extension.value_size()) // extension
     }
 
     pub fn num_voters(members: &[Member]) -> usize {
@@ -63,6 +72,65 @@ impl Multisig {
             .count()
     }
 
+    /// Number of members that can vote on the transactions of the vault at `vault_index`
+    /// and haven't expired at `now`.
+    /// `vault_index` is `None` for the transactions not bound to a vault.
+    pub fn num_active_voters(&self, vault_index: Option<u8>, now: i64) -> usize {
+        self.members
+            .iter()
+            .filter(|m| self.is_vault_voter(m, vault_index) && !self.is_expired(m.key, now))
+            .count()
+    }
+
+    /// Whether `member` has the `Vote` permission for the vault at `vault_index`,
+    /// or just the `Vote` permission if `vault_index` is `None`.
+    fn is_vault_voter(&self, member: &Member, vault_index: Option<u8>) -> bool {
+        member.permissions.has(Permission::Vote)
+            && vault_index.is_none_or(|vault_index| {
+                self.member_vault_scope(member.key, Permission::Vote)
+                    .is_none_or(|scope| scope.allows(vault_index))
+            })
+    }
+
+    /// Whether the `Vote` permission of any member is restricted to particular vaults.
+    /// The votes on a transaction then depend on the vault it's bound to.
+    pub fn has_vault_scoped_voters(&self) -> bool {
+        self.extension.member_settings.iter().any(|settings| {
+            settings
+                .vault_scope
+                .is_some_and(|scope| scope.permissions.has(Permission::Vote))
+        })
+    }
+
+    /// The smallest number of members with `Vote` permission that never expire,
+    /// among the voters of each vault.
+    pub fn min_permanent_vault_voters(&self) -> usize {
+        let mut num_unscoped_voters = 0;
+        let mut num_scoped_voters = [0usize; 256];
+
+        for member in &self.members {
+            if !member.permissions.has(Permission::Vote) {
+                continue;
+            }
+            let settings = self.member_settings(member.key);
+            if settings.is_some_and(|settings| settings.valid_until.is_some()) {
+                continue;
+            }
+            match self.member_vault_scope(member.key, Permission::Vote) {
+                Some(scope) => {
+                    for vault_index in 0..=u8::MAX {
+                        if scope.allows(vault_index) {
+                            num_scoped_voters[usize::from(vault_index)] += 1;
+                        }
+                    }
+                }
+                None => num_unscoped_voters += 1,
+            }
+        }
+
+        num_unscoped_voters + num_scoped_voters.iter().min().unwrap()
+    }
+
     pub fn num_proposers(members: &[Member]) -> usize {
         members
             .iter()
@@ -77,11 +145,13 @@ impl Multisig {
             .count()
     }
 
-    /// Check if the multisig account space needs to be reallocated to accommodate `members_length`.
+    /// Check if the multisig account space needs to be reallocated to accommodate `members_length`
+    /// and the `extension`.
     /// Returns `true` if the account was reallocated.
     pub fn realloc_if_needed<'a>(
         multisig: AccountInfo<'a>,
         members_length: usize,
+        extension: &AccountExtension<MultisigExtension>,
         rent_payer: Option<AccountInfo<'a>>,
         system_program: Option<AccountInfo<'a>>,
     ) -> Result<bool> {
@@ -89,7 +159,7 @@ impl Multisig {
         require_keys_eq!(*multisig.owner, id(), MultisigError::IllegalAccountOwner);
 
         let current_account_size = multisig.data.borrow().len();
-        let account_size_to_fit_members = Multisig::size(members_length);
+        let account_size_to_fit_members = Multisig::size(members_length, extension);
 
         // Check if we need to reallocate space.
         if current_account_size >= account_size_to_fit_members {
@@ -133,6 +203,205 @@ impl Multisig {
         Ok(true)
     }
 
+    /// Makes sure the `rate_limit` account is passed if the multisig has one.
+    /// The account itself is validated by its seeds.
+    pub fn check_rate_limit_account(&self, rate_limit: Option<&Account<RateLimit>>) -> Result<()> {
+        require!(
+            !self.extension.has_rate_limit || rate_limit.is_some(),
+            MultisigError::MissingAccount
+        );
+
+        Ok(())
+    }
+
+    /// Loads the `RateLimit` account of the multisig from `rate_limit_info`, required if it has one.
+    /// The instructions that predate rate limits take it from `remaining_accounts`, at a fixed position
+    /// after the accounts they already had there, so that their account lists stay unchanged.
+    /// Returns `None` if the multisig has no rate limit, whatever `rate_limit_info` is.
+    pub fn load_rate_limit(
+        &self,
+        multisig_key: &Pubkey,
+        rate_limit_info: Option<&AccountInfo>,
+    ) -> Result<Option<RateLimit>> {
+        if !self.extension.has_rate_limit {
+            return Ok(None);
+        }
+
+        let rate_limit_info = rate_limit_info.ok_or(MultisigError::MissingAccount)?;
+        let rate_limit = RateLimit::load(rate_limit_info)?.ok_or(MultisigError::MissingAccount)?;
+
+        // The account must be the canonical rate limit PDA of the multisig.
+        let rate_limit_key = Pubkey::create_program_address(
+            &[
+                SEED_PREFIX,
+                multisig_key.as_ref(),
+                SEED_RATE_LIMIT,
+                &[rate_limit.bump],
+            ],
+            &id(),
+        )
+        .map_err(|_| MultisigError::InvalidAccount)?;
+        require_keys_eq!(
+            *rate_limit_info.key,
+            rate_limit_key,
+            MultisigError::InvalidAccount
+        );
+        require!(rate_limit_info.is_writable, MultisigError::InvalidAccount);
+
+        Ok(Some(rate_limit))
+    }
+
+    /// Whether the multisig has been closed and only its tombstone is left.
+    /// Open multisigs always have a non-zero `threshold`.
+    pub fn is_closed(&self) -> bool {
+        self.threshold == 0
+    }
+
+    /// Advance `extension.closed_transaction_index` over the following transactions
+    /// whose transaction and proposal accounts are both in `accounts` and closed.
+    /// Stops at the first transaction that is still open or whose accounts aren't passed.
+    pub fn record_closed_transactions(&mut self, multisig_key: &Pubkey, accounts: &[AccountInfo]) {
+        let is_closed = |address: &Pubkey| {
+            accounts
+                .iter()
+                .any(|account| account.key == address && account.data_is_empty())
+        };
+
+        while self.extension.closed_transaction_index < self.transaction_index {
+            let index = self.extension.closed_transaction_index + 1;
+            let (transaction_key, _) = Pubkey::find_program_address(
+                &[
+                    SEED_PREFIX,
+                    multisig_key.as_ref(),
+                    SEED_TRANSACTION,
+                    &index.to_le_bytes(),
+                ],
+                &id(),
+            );
+            let (proposal_key, _) = Pubkey::find_program_address(
+                &[
+                    SEED_PREFIX,
+                    multisig_key.as_ref(),
+                    SEED_TRANSACTION,
+                    &index.to_le_bytes(),
+                    SEED_PROPOSAL,
+                ],
+                &id(),
+            );
+
+            if !is_closed(&transaction_key) || !is_closed(&proposal_key) {
+                break;
+            }
+
+            self.extension.closed_transaction_index = index;
+        }
+    }
+
+    /// Close the `multisig` and send its rent to `rent_destination`.
+    ///
+    /// All the transactions of the multisig must be closed, either already recorded
+    /// with `record_closed_transactions` or with their accounts passed in `accounts`.
+    /// The vaults at `vault_indices` must be empty, as well as their token accounts in `accounts`.
+    /// The rate limit and all the spending limits, allowances and streams counted in the extension
+    /// must be removed first, because they couldn't be once the multisig is gone.
+    /// None of `accounts` can be an open account of the multisig either, which also catches
+    /// the spending limits that aren't counted.
+    /// Transaction buffers don't need to be closed, their creators can still close them afterwards.
+    /// `accounts` must include the vaults and the `rent_destination`.
+    ///
+    /// The account is left as a tombstone without members, which keeps a multisig from being
+    /// created again at the same address with the transaction index starting over.
+    pub fn close<'a>(
+        multisig: &mut Account<'a, Multisig>,
+        vault_indices: &[u8],
+        rent_destination: &Pubkey,
+        accounts: &[AccountInfo<'a>],
+    ) -> Result<()> {
+        let multisig_key = multisig.key();
+
+        multisig.record_closed_transactions(&multisig_key, accounts);
+        require_eq!(
+            multisig.extension.closed_transaction_index,
+            multisig.transaction_index,
+            MultisigError::MultisigNotEmpty
+        );
+
+        let extension = &multisig.extension;
+        require!(!extension.has_rate_limit, MultisigError::MultisigNotEmpty);
+        require_eq!(
+            extension.spending_limit_count,
+            0,
+            MultisigError::MultisigNotEmpty
+        );
+        require_eq!(
+            extension.allowance_count,
+            0,
+            MultisigError::MultisigNotEmpty
+        );
+        require_eq!(extension.stream_count, 0, MultisigError::MultisigNotEmpty);
+
+        for vault_index in vault_indices {
+            let (vault_key, _) = Pubkey::find_program_address(
+                &[
+                    SEED_PREFIX,
+                    multisig_key.as_ref(),
+                    SEED_VAULT,
+                    &vault_index.to_le_bytes(),
+                ],
+                &id(),
+            );
+            let vault = accounts
+                .iter()
+                .find(|account| account.key == &vault_key)
+                .ok_or(MultisigError::MissingAccount)?;
+            require_eq!(vault.lamports(), 0, MultisigError::VaultNotEmpty);
+
+            for account in accounts {
+                if let Some((_, amount)) = utils::vault_token_balance(&vault_key, account) {
+                    require_eq!(amount, 0, MultisigError::VaultNotEmpty);
+                }
+            }
+        }
+
+        for account in accounts {
+            // All the accounts belonging to a multisig store its key right after the discriminator.
+            let belongs_to_multisig = account.owner == &id()
+                && account.try_borrow_data()?.get(8..40) == Some(multisig_key.as_ref());
+            require!(!belongs_to_multisig, MultisigError::MultisigNotEmpty);
+        }
+
+        let rent_destination = accounts
+            .iter()
+            .find(|account| account.key == rent_destination)
+            .ok_or(MultisigError::MissingAccount)?;
+
+        // Reset the multisig to its tombstone, which nobody can act on.
+        let transaction_index = multisig.transaction_index;
+        multisig.config_authority = Pubkey::default();
+        multisig.threshold = 0;
+        multisig.time_lock = 0;
+        multisig.stale_transaction_index = transaction_index;
+        multisig.rent_collector = None;
+        multisig.members = vec![];
+        multisig.extension = AccountExtension::new(MultisigExtension {
+            closed_transaction_index: transaction_index,
+            ..Default::default()
+        });
+
+        // Shrink the account to the tombstone and send the rent it no longer needs to `rent_destination`.
+        let multisig_info = multisig.to_account_info();
+        let tombstone_size = Multisig::size(0, &multisig.extension);
+        #[allow(deprecated)]
+        AccountInfo::realloc(&multisig_info, tombstone_size, false)?;
+
+        let tombstone_lamports = Rent::get()?.minimum_balance(tombstone_size);
+        let released_lamports = multisig_info.lamports().saturating_sub(tombstone_lamports);
+        **multisig_info.try_borrow_mut_lamports()? -= released_lamports;
+        **rent_destination.try_borrow_mut_lamports()? += released_lamports;
+
+        Ok(())
+    }
+
     // Makes sure the multisig state is valid.
     // This must be called at the end of every instruction that modifies a Multisig account.
     pub fn invariant(&self) -> Result<()> {
@@ -159,6 +428,48 @@ impl Multisig {
             MultisigError::UnknownPermission
         );
 
+        // Member settings must be sorted without duplicates, and belong to members.
+        let member_settings = &self.extension.member_settings;
+        require!(
+            member_settings
+                .windows(2)
+                .all(|win| win[0].key < win[1].key),
+            MultisigError::DuplicateMember
+        );
+        require!(
+            member_settings
+                .iter()
+                .all(|settings| self.is_member(settings.key).is_some()),
+            MultisigError::NotAMember
+        );
+
+        // Vault scopes must not restrict unknown permissions.
+        require!(
+            member_settings
+                .iter()
+                .filter_map(|settings| settings.vault_scope.as_ref())
+                .all(|scope| scope.permissions.mask < 8),
+            MultisigError::UnknownPermission
+        );
+
+        // Passkey members must be keyed by the address derived from their passkey.
+        require!(
+            member_settings.iter().all(|settings| settings
+                .passkey
+                .is_none_or(|passkey| passkey.address() == settings.key)),
+            MultisigError::PasskeyAddressMismatch
+        );
+
+        // Passkey members can't sign transactions, so they can only have the Vote permission.
+        require!(
+            member_settings
+                .iter()
+                .filter(|settings| settings.passkey.is_some())
+                .filter_map(|settings| self.is_member(settings.key))
+                .all(|index| members[index].permissions.mask == Permission::Vote as u8),
+            MultisigError::PasskeyMemberNotVoteOnly
+        );
+
         // There must be at least one member with Initiate permission.
         let num_proposers = Self::num_proposers(members);
         require!(num_proposers > 0, MultisigError::NoProposers);
@@ -171,6 +482,14 @@ impl Multisig {
         let num_voters = Self::num_voters(members);
         require!(num_voters > 0, MultisigError::NoVoters);
 
+        // There must be no duplicate entries in the program allowlist.
+        if let Some(program_allowlist) = &self.extension.program_allowlist {
+            let has_duplicates = program_allowlist.windows(2).any(|win| {
+                win[0].program_id == win[1].program_id && win[0].vault_index == win[1].vault_index
+            });
+            require!(!has_duplicates, MultisigError::DuplicateAllowedProgram);
+        }
+
         // Threshold must be greater than 0.
         require!(*threshold > 0, MultisigError::InvalidThreshold);
 
@@ -180,6 +499,14 @@ impl Multisig {
             MultisigError::InvalidThreshold
         );
 
+        // Threshold must stay reachable once the expiring voters are gone, so it can't rely on them.
+        // And it must be reachable for the transactions of every vault, by the voters whose
+        // `Vote` permission is not restricted to other vaults.
+        require!(
+            usize::from(*threshold) <= self.min_permanent_vault_voters(),
+            MultisigError::ThresholdUnreachable
+        );
+
         // `state.stale_transaction_index` must be less than or equal to `state.transaction_index`.
         require!(
             stale_transaction_index <= transaction_index,
@@ -196,9 +523,18 @@ impl Multisig {
     }
 
     /// Makes the transactions created up until this moment stale.
-    /// Should be called whenever any multisig parameter related to the voting consensus is changed.
+    /// Should be called whenever any multisig parameter related to the voting consensus is changed:
+    /// the members (including their permissions and vault scopes), the threshold, the time lock
+    /// or the config authority. Other config changes must not make the pending transactions stale.
+    /// Stale vault transaction proposals can be re-activated with `proposal_rebase`.
+    /// `extension.invalidation_count` is only bumped once the extension is stored, so that
+    /// invalidating never grows the account. `proposal_rebase` stores it before recording
+    /// the count, and until then no proposal depends on it.
     pub fn invalidate_prior_transactions(&mut self) {
         self.stale_transaction_index = self.transaction_index;
+        if self.extension.is_stored() {
+            self.extension.invalidation_count = self.extension.invalidation_count.wrapping_add(1);
+        }
     }
 
     /// Returns `Some(index)` if `member_pubkey` is a member, with `index` into the `members` vec.
@@ -209,6 +545,62 @@ impl Multisig {
             .ok()
     }
 
+    /// Returns `Some(index)` if `member_pubkey` is a member that hasn't expired at `now`,
+    /// with `index` into the `members` vec. `None` otherwise.
+    /// Expired members must be treated as non-members by all the instructions.
+    pub fn is_active_member(&self, member_pubkey: Pubkey, now: i64) -> Option<usize> {
+        self.is_member(member_pubkey)
+            .filter(|_| !self.is_expired(member_pubkey, now))
+    }
+
+    /// Whether the membership of `member_pubkey` has expired at `now`.
+    fn is_expired(&self, member_pubkey: Pubkey, now: i64) -> bool {
+        self.member_settings(member_pubkey)
+            .is_some_and(|settings| settings.is_expired(now))
+    }
+
+    /// Returns the `MemberSettings` of `member_pubkey`, `None` if it has none or is not a member.
+    pub fn member_settings(&self, member_pubkey: Pubkey) -> Option<&MemberSettings> {
+        let member_settings = &self.extension.member_settings;
+        member_settings
+            .binary_search_by_key(&member_pubkey, |settings| settings.key)
+            .ok()
+            .map(|index| &member_settings[index])
+    }
+
+    /// Apply `update` to the `MemberSettings` of `member_pubkey`, creating them if needed.
+    /// The entry is dropped if none of the settings is set afterwards.
+    ///
+    /// # Errors
+    /// - `MultisigError::NotAMember` if `member_pubkey` is not a member.
+    fn update_member_settings(
+        &mut self,
+        member_pubkey: Pubkey,
+        update: impl FnOnce(&mut MemberSettings),
+    ) -> Result<()> {
+        require!(
+            self.is_member(member_pubkey).is_some(),
+            MultisigError::NotAMember
+        );
+
+        let member_settings = &mut self.extension.member_settings;
+        let index = match member_settings.binary_search_by_key(&member_pubkey, |s| s.key) {
+            Ok(index) => index,
+            Err(index) => {
+                member_settings.insert(index, MemberSettings::new(member_pubkey));
+                index
+            }
+        };
+
+        update(&mut member_settings[index]);
+
+        if member_settings[index].is_empty() {
+            member_settings.remove(index);
+        }
+
+        Ok(())
+    }
+
     pub fn member_has_permission(&self, member_pubkey: Pubkey, permission: Permission) -> bool {
         match self.is_member(member_pubkey) {
             Some(index) => self.members[index].permissions.has(permission),
@@ -216,11 +608,104 @@ impl Multisig {
         }
     }
 
+    /// Returns the `VaultScope` of `member_pubkey` if it restricts the `permission`.
+    /// `None` if the member can use the `permission` for any vault, or is not a member.
+    pub fn member_vault_scope(
+        &self,
+        member_pubkey: Pubkey,
+        permission: Permission,
+    ) -> Option<&VaultScope> {
+        self.member_settings(member_pubkey)
+            .and_then(|settings| settings.vault_scope.as_ref())
+            .filter(|scope| scope.permissions.has(permission))
+    }
+
+    /// Whether `member_pubkey` has the `permission` for the vault at `vault_index`.
+    pub fn member_has_vault_permission(
+        &self,
+        member_pubkey: Pubkey,
+        permission: Permission,
+        vault_index: u8,
+    ) -> bool {
+        self.member_has_permission(member_pubkey, permission)
+            && self
+                .member_vault_scope(member_pubkey, permission)
+                .is_none_or(|scope| scope.allows(vault_index))
+    }
+
+    /// Returns the `program_allowlist` entries applicable to the vault at `vault_index`.
+    /// `None` if the program allowlist is not set.
+    pub fn vault_program_allowlist(&self, vault_index: u8) -> Option<Vec<AllowedProgram>> {
+        self.extension
+            .program_allowlist
+            .as_ref()
+            .map(|program_allowlist| {
+                program_allowlist
+                    .iter()
+                    .filter(|entry| entry.vault_index.is_none_or(|index| index == vault_index))
+                    .copied()
+                    .collect()
+            })
+    }
+
+    /// Returns the address the rent of a closed account paid by `rent_payer` goes to:
+    /// the `rent_collector` if set, otherwise `rent_payer` if the multisig refunds rent to the payers.
+    /// Accounts created before the rent payers were recorded have a default `rent_payer`
+    /// and can only be closed to the `rent_collector`.
+    pub fn rent_destination(&self, rent_payer: Pubkey) -> Result<Pubkey> {
+        if let Some(rent_collector) = self.rent_collector {
+            return Ok(rent_collector);
+        }
+
+        require!(
+            self.extension.refund_rent_to_payers && rent_payer != Pubkey::default(),
+            MultisigError::RentReclamationDisabled
+        );
+
+        Ok(rent_payer)
+    }
+
+    /// Check that the `executor` of a transaction created by `creator` and approved
+    /// by the members in `proposal` satisfies the `two_person_rule`.
+    pub fn check_two_person_rule(
+        &self,
+        executor: Pubkey,
+        creator: Pubkey,
+        proposal: &Proposal,
+    ) -> Result<()> {
+        match self.extension.two_person_rule {
+            TwoPersonRule::Disabled => {}
+            TwoPersonRule::ExecutorNotCreator => {
+                require_keys_neq!(executor, creator, MultisigError::ExecutorIsCreator);
+            }
+            TwoPersonRule::ExecutorNotCreatorOrApprover => {
+                require_keys_neq!(executor, creator, MultisigError::ExecutorIsCreator);
+                require!(
+                    proposal.approved.binary_search(&executor).is_err(),
+                    MultisigError::ExecutorIsApprover
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set the `program_allowlist` and sort it, so that duplicates can be detected.
+    pub fn set_program_allowlist(&mut self, program_allowlist: Option<Vec<AllowedProgram>>) {
+        self.extension.program_allowlist = program_allowlist.map(|mut program_allowlist| {
+            program_allowlist.sort_by_key(|entry| (entry.program_id, entry.vault_index));
+            program_allowlist
+        });
+    }
+
     /// How many "reject" votes are enough to make the transaction "Rejected".
     /// The cutoff must be such that it is impossible for the remaining voters to reach the approval threshold.
+    /// "Abstain" votes count towards the cutoff too, as abstaining members won't approve.
+    /// Only the voters of the vault at `vault_index` that haven't expired at `now` can still approve,
+    /// `vault_index` is `None` for the transactions not bound to a vault.
     /// For example: total voters = 7, threshold = 3, cutoff = 5.
-    pub fn cutoff(&self) -> usize {
-        Self::num_voters(&self.members)
+    pub fn cutoff(&self, vault_index: Option<u8>, now: i64) -> usize {
+        self.num_active_voters(vault_index, now)
             .checked_sub(usize::from(self.threshold))
             .unwrap()
             .checked_add(1)
@@ -245,8 +730,127 @@ impl Multisig {
 
         self.members.remove(old_member_index);
 
+        // Drop the settings of the removed member.
+        self.extension
+            .member_settings
+            .retain(|settings| settings.key != member_pubkey);
+
         Ok(())
     }
+
+    /// Set or remove the `valid_until` timestamp of `member_pubkey`.
+    ///
+    /// # Errors
+    /// - `MultisigError::NotAMember` if `member_pubkey` is not a member.
+    pub fn set_member_valid_until(
+        &mut self,
+        member_pubkey: Pubkey,
+        valid_until: Option<i64>,
+    ) -> Result<()> {
+        self.update_member_settings(member_pubkey, |settings| {
+            settings.valid_until = valid_until;
+        })
+    }
+
+    /// Set or remove the `vault_scope` of `member_pubkey`.
+    ///
+    /// # Errors
+    /// - `MultisigError::NotAMember` if `member_pubkey` is not a member.
+    pub fn set_member_vault_scope(
+        &mut self,
+        member_pubkey: Pubkey,
+        vault_scope: Option<VaultScope>,
+    ) -> Result<()> {
+        self.update_member_settings(member_pubkey, |settings| {
+            settings.vault_scope = vault_scope;
+        })
+    }
+
+    /// Set or remove the `passkey` of `member_pubkey`.
+    ///
+    /// # Errors
+    /// - `MultisigError::NotAMember` if `member_pubkey` is not a member.
+    pub fn set_member_passkey(
+        &mut self,
+        member_pubkey: Pubkey,
+        passkey: Option<Passkey>,
+    ) -> Result<()> {
+        self.update_member_settings(member_pubkey, |settings| {
+            settings.passkey = passkey;
+        })
+    }
+
+    /// Remove all the members whose `valid_until` has passed at `now`.
+    /// Returns the number of removed members.
+    pub fn remove_expired_members(&mut self, now: i64) -> usize {
+        let members_before = self.members.len();
+
+        let member_settings = &mut self.extension.member_settings;
+        let expired = member_settings
+            .iter()
+            .filter(|settings| settings.is_expired(now))
+            .map(|settings| settings.key)
+            .collect::<Vec<_>>();
+        member_settings.retain(|settings| !settings.is_expired(now));
+        self.members
+            .retain(|m| expired.binary_search(&m.key).is_err());
+
+        members_before - self.members.len()
+    }
+}
+
+/// Config parameters of the multisig added after the original layout of the `Multisig` account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct MultisigExtension {
+    /// Settings of the members on top of their `Member` entry, sorted by key.
+    /// Members without any of the settings have no entry.
+    pub member_settings: Vec<MemberSettings>,
+    /// Restricts who can execute transactions on top of the `Execute` permission.
+    pub two_person_rule: TwoPersonRule,
+    /// Programs that vault transactions are allowed to invoke.
+    /// If set to `None`, vault transactions can invoke any program.
+    pub program_allowlist: Option<Vec<AllowedProgram>>,
+    /// Whether the rent of closed transaction accounts is refunded to the accounts that paid it
+    /// when no `rent_collector` is set. If `false`, such accounts can't be closed.
+    pub refund_rent_to_payers: bool,
+    /// All the transactions up until this index have their transaction and proposal accounts closed.
+    /// Proposals can't be created for these transactions anymore.
+    pub closed_transaction_index: u64,
+    /// Number of times the prior transactions have been invalidated since the extension was stored.
+    /// Rebased proposals become stale as soon as it changes.
+    pub invalidation_count: u64,
+    /// Whether the multisig has a `RateLimit` account, which the instructions moving funds
+    /// out of the vaults must then be passed.
+    pub has_rate_limit: bool,
+    /// Number of open `SpendingLimit` accounts created by config transactions.
+    /// Those added by the `config_authority` with `multisig_add_spending_limit` are not counted,
+    /// because the instruction takes the multisig read-only.
+    pub spending_limit_count: u32,
+    /// Number of open `Allowance` accounts of the multisig.
+    pub allowance_count: u32,
+    /// Number of open `Stream` accounts of the multisig.
+    pub stream_count: u32,
+}
+
+impl MultisigExtension {
+    /// Size of the serialized extension fields.
+    pub fn value_size(&self) -> usize {
+        let program_allowlist_length = self.program_allowlist.as_ref().map_or(0, Vec::len);
+
+        4  + // member_settings vector length
+        self.member_settings.len() * MemberSettings::INIT_SPACE + // member_settings
+        1  + // two_person_rule
+        1  + // program_allowlist Option discriminator
+        4  + // program_allowlist vector length (always 4 bytes, even if None)
+        program_allowlist_length * AllowedProgram::INIT_SPACE + // program_allowlist
+        1  + // refund_rent_to_payers
+        8  + // closed_transaction_index
+        8  + // invalidation_count
+        1  + // has_rate_limit
+        4  + // spending_limit_count
+        4  + // allowance_count
+        4 // stream_count
+    }
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone)]
@@ -255,6 +859,58 @@ pub struct Member {
     pub permissions: Permissions,
 }
 
+/// Settings of a multisig member on top of its `Member` entry.
+#[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct MemberSettings {
+    /// The `key` of the member.
+    pub key: Pubkey,
+    /// Unix timestamp after which the member is treated as a non-member.
+    /// `None` means the membership never expires.
+    pub valid_until: Option<i64>,
+    /// Restricts some of the member's permissions to a subset of the multisig vaults.
+    /// `None` means the permissions can be used for any vault.
+    pub vault_scope: Option<VaultScope>,
+    /// The passkey of the member, if it votes with a passkey instead of signing with `key`.
+    /// `key` must then be `passkey.address()`.
+    pub passkey: Option<Passkey>,
+}
+
+impl MemberSettings {
+    pub fn new(key: Pubkey) -> Self {
+        Self {
+            key,
+            valid_until: None,
+            vault_scope: None,
+            passkey: None,
+        }
+    }
+
+    /// Whether none of the settings is set.
+    pub fn is_empty(&self) -> bool {
+        self.valid_until.is_none() && self.vault_scope.is_none() && self.passkey.is_none()
+    }
+
+    /// Whether the membership has expired at `now`.
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.valid_until, Some(valid_until) if now > valid_until)
+    }
+}
+
+/// Compliance policy separating the initiation of a transaction from its execution.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Eq, PartialEq, Clone, Copy, Default, Debug,
+)]
+pub enum TwoPersonRule {
+    /// Any member with the `Execute` permission can execute transactions.
+    #[default]
+    Disabled,
+    /// The executing member must not be the creator of the transaction.
+    ExecutorNotCreator,
+    /// The executing member must be neither the creator of the transaction,
+    /// nor one of the members who approved it.
+    ExecutorNotCreatorOrApprover,
+}
+
 #[derive(Clone, Copy)]
 pub enum Permission {
     Initiate = 1 << 0,
@@ -284,3 +940,396 @@ impl Permissions {
         self.mask & (permission as u8) != 0
     }
 }
+
+/// Restricts the `permissions` of a member to the vaults listed in `vault_indexes`.
+/// Only applies to the actions bound to a vault: vault transactions and batches.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct VaultScope {
+    /// Permissions that are restricted by the scope. Other permissions of the member are not affected.
+    pub permissions: Permissions,
+    /// Bitmap of the vault indexes the restricted permissions can be used for.
+    /// Bit `i % 8` of byte `i / 8` is set if the vault at index `i` is allowed.
+    pub vault_indexes: [u8; 32],
+}
+
+impl VaultScope {
+    pub fn new(permissions: Permissions, vault_indexes: &[u8]) -> Self {
+        let mut bitmap = [0u8; 32];
+        for vault_index in vault_indexes {
+            bitmap[usize::from(vault_index / 8)] |= 1 << (vault_index % 8);
+        }
+        Self {
+            permissions,
+            vault_indexes: bitmap,
+        }
+    }
+
+    /// Whether the vault at `vault_index` is within the scope.
+    pub fn allows(&self, vault_index: u8) -> bool {
+        self.vault_indexes[usize::from(vault_index / 8)] & (1 << (vault_index % 8)) != 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::ACCOUNT_EXTENSION_PREFIX;
+
+    fn member(key: Pubkey, mask: u8) -> Member {
+        Member {
+            key,
+            permissions: Permissions { mask },
+        }
+    }
+
+    fn multisig(members: Vec<Member>, threshold: u16) -> Multisig {
+        let mut members = members;
+        members.sort_by_key(|m| m.key);
+        Multisig {
+            create_key: Pubkey::new_unique(),
+            config_authority: Pubkey::default(),
+            threshold,
+            time_lock: 0,
+            transaction_index: 0,
+            stale_transaction_index: 0,
+            rent_collector: None,
+            bump: 255,
+            members,
+            extension: AccountExtension::default(),
+        }
+    }
+
+    #[test]
+    fn test_member_settings() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut multisig = multisig(vec![member(a, 7), member(b, 7)], 1);
+
+        multisig.set_member_valid_until(b, Some(100)).unwrap();
+        multisig.set_member_valid_until(a, Some(200)).unwrap();
+        assert_eq!(multisig.extension.member_settings.len(), 2);
+        assert!(multisig
+            .extension
+            .member_settings
+            .windows(2)
+            .all(|win| win[0].key < win[1].key));
+        assert!(multisig.extension.is_stored());
+
+        // Unsetting the only setting drops the entry.
+        multisig.set_member_valid_until(a, None).unwrap();
+        assert!(multisig.member_settings(a).is_none());
+        assert_eq!(multisig.member_settings(b).unwrap().valid_until, Some(100));
+
+        // Non-members can't have settings.
+        assert!(multisig
+            .set_member_valid_until(Pubkey::new_unique(), Some(1))
+            .is_err());
+
+        // Removing a member drops its settings.
+        multisig.remove_member(b).unwrap();
+        assert!(multisig.extension.member_settings.is_empty());
+    }
+
+    #[test]
+    fn test_remove_expired_members() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut multisig = multisig(vec![member(a, 7), member(b, 7), member(c, 7)], 1);
+        multisig.set_member_valid_until(a, Some(100)).unwrap();
+        multisig.set_member_valid_until(b, Some(200)).unwrap();
+
+        assert_eq!(multisig.is_active_member(a, 100), multisig.is_member(a));
+        assert_eq!(multisig.is_active_member(a, 101), None);
+        assert_eq!(multisig.num_active_voters(None, 150), 2);
+        assert_eq!(multisig.min_permanent_vault_voters(), 1);
+
+        assert_eq!(multisig.remove_expired_members(150), 1);
+        assert!(multisig.is_member(a).is_none());
+        assert!(multisig.member_settings(a).is_none());
+        assert_eq!(multisig.member_settings(b).unwrap().valid_until, Some(200));
+        assert_eq!(multisig.members.len(), 2);
+    }
+
+    #[test]
+    fn test_invariant_rejects_threshold_relying_on_expiring_voters() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut multisig = multisig(vec![member(a, 7), member(b, 7), member(c, 7)], 2);
+        multisig.set_member_valid_until(a, Some(100)).unwrap();
+        multisig.invariant().unwrap();
+
+        multisig.set_member_valid_until(b, Some(100)).unwrap();
+        assert_eq!(
+            multisig.invariant().unwrap_err(),
+            MultisigError::ThresholdUnreachable.into()
+        );
+
+        // Members without the Vote permission don't count.
+        multisig.set_member_valid_until(b, None).unwrap();
+        let b_index = multisig.is_member(b).unwrap();
+        multisig.members[b_index].permissions = Permissions { mask: 5 };
+        assert_eq!(
+            multisig.invariant().unwrap_err(),
+            MultisigError::ThresholdUnreachable.into()
+        );
+    }
+
+    #[test]
+    fn test_cutoff_excludes_expired_voters() {
+        let (a, b, c, d) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut multisig = multisig(
+            vec![member(a, 7), member(b, 7), member(c, 7), member(d, 1)],
+            2,
+        );
+        assert_eq!(multisig.cutoff(None, 0), 2);
+
+        multisig.set_member_valid_until(a, Some(100)).unwrap();
+        assert_eq!(multisig.cutoff(None, 100), 2);
+        assert_eq!(multisig.cutoff(None, 101), 1);
+    }
+
+    #[test]
+    fn test_invariant_counts_voters_per_vault() {
+        let (a, b, c) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut multisig = multisig(vec![member(a, 7), member(b, 7), member(c, 7)], 2);
+        let vote = Permissions::from_vec(&[Permission::Vote]);
+
+        // `c` can only vote for vault 1, the other vaults still have 2 voters.
+        multisig
+            .set_member_vault_scope(c, Some(VaultScope::new(vote, &[1])))
+            .unwrap();
+        multisig.invariant().unwrap();
+        assert!(multisig.has_vault_scoped_voters());
+        assert_eq!(multisig.min_permanent_vault_voters(), 2);
+
+        // `b` can only vote for vault 1 too, leaving a single voter for vault 0.
+        multisig
+            .set_member_vault_scope(b, Some(VaultScope::new(vote, &[1])))
+            .unwrap();
+        assert_eq!(
+            multisig.invariant().unwrap_err(),
+            MultisigError::ThresholdUnreachable.into()
+        );
+
+        // Scopes of the other permissions don't affect the voters.
+        let initiate = Permissions::from_vec(&[Permission::Initiate]);
+        multisig
+            .set_member_vault_scope(b, Some(VaultScope::new(initiate, &[1])))
+            .unwrap();
+        multisig.invariant().unwrap();
+    }
+
+    #[test]
+    fn test_cutoff_per_vault() {
+        let (a, b, c, d) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut multisig = multisig(
+            vec![member(a, 7), member(b, 7), member(c, 7), member(d, 7)],
+            2,
+        );
+        let vote = Permissions::from_vec(&[Permission::Vote]);
+        multisig
+            .set_member_vault_scope(d, Some(VaultScope::new(vote, &[1])))
+            .unwrap();
+
+        // 3 voters for vault 0, 4 for vault 1, 4 for config transactions.
+        assert_eq!(multisig.cutoff(Some(0), 0), 2);
+        assert_eq!(multisig.cutoff(Some(1), 0), 3);
+        assert_eq!(multisig.cutoff(None, 0), 3);
+    }
+
+    #[test]
+    fn test_invariant_passkey_members_vote_only() {
+        let passkey = Passkey { pubkey: [2; 33] };
+        let (a, b) = (Pubkey::new_unique(), passkey.address());
+        let mut multisig = multisig(vec![member(a, 7), member(b, 7)], 1);
+        multisig.set_member_passkey(b, Some(passkey)).unwrap();
+        assert_eq!(
+            multisig.invariant().unwrap_err(),
+            MultisigError::PasskeyMemberNotVoteOnly.into()
+        );
+
+        let b_index = multisig.is_member(b).unwrap();
+        multisig.members[b_index].permissions = Permissions::from_vec(&[Permission::Vote]);
+        multisig.invariant().unwrap();
+
+        // The passkey must match the member key.
+        multisig
+            .set_member_passkey(b, Some(Passkey { pubkey: [3; 33] }))
+            .unwrap();
+        assert_eq!(
+            multisig.invariant().unwrap_err(),
+            MultisigError::PasskeyAddressMismatch.into()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_legacy_account() {
+        use base64::Engine;
+
+        // A multisig account created before the extension was introduced.
+        let fixture =
+            include_str!("../../../../tests/fixtures/pre-rent-collector/multisig-account.json");
+        let encoded = fixture
+            .split_once("\"data\": [")
+            .and_then(|(_, rest)| rest.split('"').nth(1))
+            .unwrap();
+        let data = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .unwrap();
+
+        let mut multisig = Multisig::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(multisig.members.len(), 4);
+        assert_eq!(multisig.bump, 255);
+        assert!(!multisig.extension.is_stored());
+        assert_eq!(multisig.extension.two_person_rule, TwoPersonRule::Disabled);
+        assert!(multisig.extension.program_allowlist.is_none());
+
+        // Written back within the allocated size.
+        let mut buffer = vec![0u8; data.len()];
+        multisig.try_serialize(&mut &mut buffer[..]).unwrap();
+        assert_eq!(buffer, data);
+
+        // Setting the extension fields requires a reallocation.
+        multisig.extension.two_person_rule = TwoPersonRule::ExecutorNotCreator;
+        assert!(Multisig::size(multisig.members.len(), &multisig.extension) > data.len());
+        assert!(multisig.try_serialize(&mut &mut buffer[..]).is_err());
+    }
+
+    #[test]
+    fn test_size_includes_extension() {
+        let a = Pubkey::new_unique();
+        let mut multisig = multisig(vec![member(a, 7)], 1);
+        let size = Multisig::size(1, &multisig.extension);
+        let serialized_size = multisig.try_to_vec().unwrap().len();
+        // `rent_collector` always gets space reserved.
+        assert_eq!(serialized_size + 8 + 32, size);
+
+        multisig.set_member_valid_until(a, Some(100)).unwrap();
+        let size_with_extension = Multisig::size(1, &multisig.extension);
+        // The fields following `member_settings`.
+        let other_fields_size = 1 + 1 + 4 + 1 + 8 + 8 + 1 + 4 + 4 + 4;
+        assert_eq!(
+            size_with_extension - size,
+            ACCOUNT_EXTENSION_PREFIX.len() + 4 + MemberSettings::INIT_SPACE + other_fields_size
+        );
+        assert!(multisig.try_to_vec().unwrap().len() + 8 <= size_with_extension);
+    }
+
+    #[test]
+    fn test_invalidate_prior_transactions_keeps_size() {
+        let mut multisig = multisig(vec![member(Pubkey::new_unique(), 7)], 1);
+        multisig.transaction_index = 3;
+        let size = Multisig::size(1, &multisig.extension);
+
+        // Legacy accounts without the extension don't count the invalidations.
+        multisig.invalidate_prior_transactions();
+        assert_eq!(multisig.stale_transaction_index, 3);
+        assert_eq!(multisig.extension.invalidation_count, 0);
+        assert_eq!(Multisig::size(1, &multisig.extension), size);
+
+        multisig.extension = AccountExtension::new(MultisigExtension::default());
+        let size = Multisig::size(1, &multisig.extension);
+        multisig.invalidate_prior_transactions();
+        assert_eq!(multisig.extension.invalidation_count, 1);
+        assert_eq!(Multisig::size(1, &multisig.extension), size);
+    }
+
+    #[test]
+    fn test_rent_destination() {
+        let (rent_collector, rent_payer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut multisig = multisig(vec![member(Pubkey::new_unique(), 7)], 1);
+
+        // No rent collector and no opt-in: rent can't be reclaimed.
+        assert!(multisig.rent_destination(rent_payer).is_err());
+
+        multisig.extension.refund_rent_to_payers = true;
+        assert_eq!(multisig.rent_destination(rent_payer).unwrap(), rent_payer);
+        // Legacy accounts have no recorded rent payer.
+        assert!(multisig.rent_destination(Pubkey::default()).is_err());
+
+        // The rent collector takes precedence.
+        multisig.rent_collector = Some(rent_collector);
+        assert_eq!(multisig.rent_destination(rent_payer).unwrap(), rent_collector);
+        assert_eq!(
+            multisig.rent_destination(Pubkey::default()).unwrap(),
+            rent_collector
+        );
+    }
+
+    #[test]
+    fn test_record_closed_transactions() {
+        let multisig_key = Pubkey::new_unique();
+        let mut multisig = multisig(vec![member(Pubkey::new_unique(), 7)], 1);
+        multisig.transaction_index = 3;
+
+        let address = |index: u64, proposal: bool| {
+            let index = index.to_le_bytes();
+            let mut seeds = vec![SEED_PREFIX, multisig_key.as_ref(), SEED_TRANSACTION, &index];
+            if proposal {
+                seeds.push(SEED_PROPOSAL);
+            }
+            Pubkey::find_program_address(&seeds, &id()).0
+        };
+        // Transactions 1 and 2 are closed, transaction 3 is still open.
+        let keys = [
+            address(1, false),
+            address(1, true),
+            address(2, false),
+            address(2, true),
+            address(3, false),
+            address(3, true),
+        ];
+        let mut lamports = [0u64; 6];
+        let mut data = [vec![], vec![], vec![], vec![], vec![1u8; 8], vec![1u8; 8]];
+        let owner = id();
+        let accounts = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(key, false, false, lamports, data, &owner, false, 0)
+            })
+            .collect::<Vec<_>>();
+
+        // Transaction 2 can't be recorded before transaction 1.
+        multisig.record_closed_transactions(&multisig_key, &accounts[2..]);
+        assert_eq!(multisig.extension.closed_transaction_index, 0);
+
+        multisig.record_closed_transactions(&multisig_key, &accounts);
+        assert_eq!(multisig.extension.closed_transaction_index, 2);
+
+        // Missing accounts don't move the index back.
+        multisig.record_closed_transactions(&multisig_key, &[]);
+        assert_eq!(multisig.extension.closed_transaction_index, 2);
+    }
+
+    #[test]
+    fn test_is_closed() {
+        let mut multisig = multisig(vec![member(Pubkey::new_unique(), 7)], 1);
+        assert!(!multisig.is_closed());
+        assert!(multisig.invariant().is_ok());
+
+        multisig.threshold = 0;
+        multisig.members = vec![];
+        assert!(multisig.is_closed());
+    }
+}
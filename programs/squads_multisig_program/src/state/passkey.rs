@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use solana_program::hash::hashv;
+
+use crate::instructions::Vote;
+use crate::state::SEED_PASSKEY;
+
+/// secp256r1 (WebAuthn) public key of a passkey member.
+/// Passkey members can't sign Solana transactions, they vote with `proposal_vote_passkey`
+/// by signing a challenge with their passkey, which is verified by the secp256r1 precompile.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Passkey {
+    /// Compressed secp256r1 public key.
+    pub pubkey: [u8; 33],
+}
+
+impl Passkey {
+    /// The member key of the passkey member: the address derived from the secp256r1 public key.
+    /// It's what `Multisig::is_member` looks up and what the `Proposal` votes record.
+    pub fn address(&self) -> Pubkey {
+        Pubkey::new_from_array(hashv(&[SEED_PASSKEY, &self.pubkey]).to_bytes())
+    }
+
+    /// The challenge the passkey must sign to cast `vote` on the proposal for
+    /// the `transaction_index` transaction of the `multisig`.
+    /// `nonce` is the `passkey_nonce` of the proposal, so that a signed challenge can't be replayed.
+    pub fn challenge(
+        &self,
+        multisig: &Pubkey,
+        transaction_index: u64,
+        vote: Vote,
+        nonce: u64,
+    ) -> [u8; 32] {
+        hashv(&[
+            multisig.as_ref(),
+            &transaction_index.to_le_bytes(),
+            &[vote as u8],
+            &nonce.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_challenge_depends_on_nonce() {
+        let passkey = Passkey { pubkey: [2; 33] };
+        let multisig = Pubkey::new_unique();
+
+        let challenge = passkey.challenge(&multisig, 1, Vote::Approve, 0);
+        assert_ne!(challenge, passkey.challenge(&multisig, 1, Vote::Approve, 1));
+        assert_ne!(challenge, passkey.challenge(&multisig, 1, Vote::Reject, 0));
+        assert_ne!(challenge, passkey.challenge(&multisig, 2, Vote::Approve, 0));
+    }
+}
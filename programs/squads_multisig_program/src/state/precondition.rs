@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+
+/// A check on the state of an account that must hold right before a vault transaction is executed.
+/// Protects proposals that are only safe as long as the on-chain state hasn't changed since approval.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Precondition {
+    /// The account to check.
+    pub account: Pubkey,
+    /// What to check.
+    pub check: PreconditionCheck,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum PreconditionCheck {
+    /// The account lamports must be within `min..=max`.
+    Lamports { min: u64, max: u64 },
+    /// The account must be owned by `owner`.
+    Owner { owner: Pubkey },
+    /// The account data at `offset` must be equal to `expected`.
+    Data { offset: u32, expected: Vec<u8> },
+    /// The little-endian u64 in the account data at `offset` must be within `min..=max`.
+    DataU64Range { offset: u32, min: u64, max: u64 },
+}
+
+impl Precondition {
+    pub fn size(&self) -> usize {
+        32 + // account
+        1 + // check enum discriminator
+        match &self.check {
+            PreconditionCheck::Lamports { .. } => 8 + 8,
+            PreconditionCheck::Owner { .. } => 32,
+            PreconditionCheck::Data { expected, .. } => 4 + 4 + expected.len(),
+            PreconditionCheck::DataU64Range { .. } => 4 + 8 + 8,
+        }
+    }
+
+    /// Checks the precondition against the current state of `account`.
+    pub fn check(&self, account: &AccountInfo) -> Result<()> {
+        require_keys_eq!(*account.key, self.account, MultisigError::InvalidAccount);
+
+        let holds = match &self.check {
+            PreconditionCheck::Lamports { min, max } => (*min..=*max).contains(&account.lamports()),
+            PreconditionCheck::Owner { owner } => account.owner == owner,
+            PreconditionCheck::Data { offset, expected } => {
+                let data = account.try_borrow_data()?;
+                let start = *offset as usize;
+                data.get(start..start.saturating_add(expected.len())) == Some(expected.as_slice())
+            }
+            PreconditionCheck::DataU64Range { offset, min, max } => {
+                let data = account.try_borrow_data()?;
+                let start = *offset as usize;
+                data.get(start..start.saturating_add(8))
+                    .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+                    .is_some_and(|value| (*min..=*max).contains(&value))
+            }
+        };
+
+        require!(holds, MultisigError::PreconditionFailed);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn precondition(account: Pubkey, check: PreconditionCheck) -> Precondition {
+        Precondition { account, check }
+    }
+
+    #[test]
+    fn test_check() {
+        let (key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut lamports = 1_000;
+        let mut data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        let account = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        let failed = MultisigError::PreconditionFailed.into();
+
+        let lamports = |min, max| precondition(key, PreconditionCheck::Lamports { min, max });
+        lamports(1_000, 1_000).check(&account).unwrap();
+        assert_eq!(lamports(0, 999).check(&account).unwrap_err(), failed);
+        assert_eq!(
+            lamports(1_001, u64::MAX).check(&account).unwrap_err(),
+            failed
+        );
+
+        precondition(key, PreconditionCheck::Owner { owner })
+            .check(&account)
+            .unwrap();
+        assert_eq!(
+            precondition(
+                key,
+                PreconditionCheck::Owner {
+                    owner: Pubkey::new_unique()
+                }
+            )
+            .check(&account)
+            .unwrap_err(),
+            failed
+        );
+
+        let data =
+            |offset, expected| precondition(key, PreconditionCheck::Data { offset, expected });
+        data(2, vec![3, 4]).check(&account).unwrap();
+        assert_eq!(data(2, vec![3, 5]).check(&account).unwrap_err(), failed);
+        // Out of bounds.
+        assert_eq!(data(9, vec![10, 0]).check(&account).unwrap_err(), failed);
+
+        let value = u64::from_le_bytes([2, 3, 4, 5, 6, 7, 8, 9]);
+        let range = |offset, min, max| {
+            precondition(key, PreconditionCheck::DataU64Range { offset, min, max })
+        };
+        range(1, value, value).check(&account).unwrap();
+        assert_eq!(
+            range(1, value + 1, u64::MAX).check(&account).unwrap_err(),
+            failed
+        );
+        assert_eq!(range(3, 0, u64::MAX).check(&account).unwrap_err(), failed);
+
+        // The precondition must be checked against its account.
+        assert_eq!(
+            precondition(Pubkey::new_unique(), PreconditionCheck::Owner { owner })
+                .check(&account)
+                .unwrap_err(),
+            MultisigError::InvalidAccount.into()
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::{token, token_2022};
+
+use crate::errors::*;
+
+const BPF_LOADER_UPGRADEABLE_ID: Pubkey = pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// An entry of the multisig `program_allowlist`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Eq, PartialEq, Clone, Copy, Debug)]
+pub struct AllowedProgram {
+    /// The program that vault transactions are allowed to invoke.
+    pub program_id: Pubkey,
+    /// The vault the entry applies to. `None` means the entry applies to all vaults.
+    pub vault_index: Option<u8>,
+    /// Whether instructions changing the authority of an account are allowed,
+    /// e.g. `SetAuthority` of the Token programs, `Upgrade` or `SetAuthority` of the BPF Upgradeable Loader,
+    /// or `Assign` of the System program.
+    pub allow_authority_changes: bool,
+}
+
+/// Checks that an instruction invoking `program_id` with `data` is permitted by the `program_allowlist`.
+/// `program_allowlist` must only contain the entries applicable to the vault executing the instruction.
+pub fn check_program_allowlist(
+    program_allowlist: &[AllowedProgram],
+    program_id: &Pubkey,
+    data: &[u8],
+) -> Result<()> {
+    let mut entries = program_allowlist
+        .iter()
+        .filter(|entry| entry.program_id == *program_id)
+        .peekable();
+
+    require!(entries.peek().is_some(), MultisigError::ProgramNotAllowed);
+
+    if is_authority_change(program_id, data) {
+        require!(
+            entries.any(|entry| entry.allow_authority_changes),
+            MultisigError::AuthorityChangeNotAllowed
+        );
+    }
+
+    Ok(())
+}
+
+/// Whether the instruction invoking `program_id` with `data` changes the authority of an account.
+fn is_authority_change(program_id: &Pubkey, data: &[u8]) -> bool {
+    if *program_id == token::ID || *program_id == token_2022::ID {
+        // `SetAuthority`.
+        return data.first() == Some(&6);
+    }
+
+    // The instructions of the System program and the BPF Upgradeable Loader
+    // are bincode-serialized enums with a u32 variant index.
+    let variant = data
+        .get(..4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()));
+
+    if *program_id == BPF_LOADER_UPGRADEABLE_ID {
+        // `Upgrade`, `SetAuthority`, `Close`, `SetAuthorityChecked`.
+        return matches!(variant, Some(3 | 4 | 5 | 7));
+    }
+
+    if *program_id == system_program::ID {
+        // `Assign`, `AssignWithSeed`.
+        return matches!(variant, Some(1 | 10));
+    }
+
+    false
+}
@@ -3,6 +3,8 @@ use anchor_lang::prelude::*;
 
 use crate::errors::*;
 use crate::id;
+use crate::state::Multisig;
+use crate::utils::AccountExtension;
 
 use anchor_lang::system_program;
 
@@ -25,6 +27,8 @@ pub struct Proposal {
     pub rejected: Vec<Pubkey>,
     /// Keys that have cancelled (Approved only).
     pub cancelled: Vec<Pubkey>,
+    /// Fields added after the original layout of the account.
+    pub extension: AccountExtension<ProposalExtension>,
 }
 
 impl Proposal {
@@ -37,7 +41,21 @@ impl Proposal {
         1 +   // bump
         (4 + (members_len * 32)) + // approved vec
         (4 + (members_len * 32)) + // rejected vec
-        (4 + (members_len * 32)) // cancelled vec
+        (4 + (members_len * 32)) + // cancelled vec
+        8 + // extension prefix
+        32 + // rent_payer
+        1 + 8 + // rebased_invalidation_count
+        (4 + (members_len * 32)) + // abstained vec
+        8 // passkey_nonce
+    }
+
+    /// Whether the multisig config affecting consensus has changed since the proposal
+    /// was created or last rebased.
+    pub fn is_stale(&self, multisig: &Multisig) -> bool {
+        match self.extension.rebased_invalidation_count {
+            Some(invalidation_count) => invalidation_count != multisig.extension.invalidation_count,
+            None => self.transaction_index <= multisig.stale_transaction_index,
+        }
     }
 
     /// Register an approval vote.
@@ -46,6 +64,10 @@ impl Proposal {
         if let Some(vote_index) = self.has_voted_reject(member.key()) {
             self.remove_rejection_vote(vote_index);
         }
+        // If `member` has previously abstained, remove that vote.
+        if let Some(vote_index) = self.has_voted_abstain(member.key()) {
+            self.remove_abstention_vote(vote_index);
+        }
 
         // Insert the vote of approval.
         match self.approved.binary_search(&member) {
@@ -69,6 +91,10 @@ impl Proposal {
         if let Some(vote_index) = self.has_voted_approve(member.key()) {
             self.remove_approval_vote(vote_index);
         }
+        // If `member` has previously abstained, remove that vote.
+        if let Some(vote_index) = self.has_voted_abstain(member.key()) {
+            self.remove_abstention_vote(vote_index);
+        }
 
         // Insert the vote of rejection.
         match self.rejected.binary_search(&member) {
@@ -76,8 +102,61 @@ impl Proposal {
             Err(pos) => self.rejected.insert(pos, member),
         };
 
-        // If current number of rejections reaches cutoff, mark the transaction as `Rejected`.
-        if self.rejected.len() >= cutoff {
+        self.reject_if_cutoff_reached(cutoff)
+    }
+
+    /// Register an abstention vote.
+    /// Abstaining members won't approve the transaction, so abstentions count towards the rejection `cutoff`.
+    pub fn abstain(&mut self, member: Pubkey, cutoff: usize) -> Result<()> {
+        // If `member` has previously voted to approve, remove that vote.
+        if let Some(vote_index) = self.has_voted_approve(member.key()) {
+            self.remove_approval_vote(vote_index);
+        }
+        // If `member` has previously voted to reject, remove that vote.
+        if let Some(vote_index) = self.has_voted_reject(member.key()) {
+            self.remove_rejection_vote(vote_index);
+        }
+
+        // Insert the vote of abstention.
+        match self.extension.abstained.binary_search(&member) {
+            Ok(_) => return err!(MultisigError::AlreadyAbstained),
+            Err(pos) => self.extension.abstained.insert(pos, member),
+        };
+
+        self.reject_if_cutoff_reached(cutoff)
+    }
+
+    /// Withdraw the vote of `member`: the approval, rejection or abstention of an `Active` proposal,
+    /// or the cancellation of an `Approved` one.
+    pub fn withdraw_vote(&mut self, member: Pubkey) -> Result<()> {
+        let votes = match self.status {
+            ProposalStatus::Active { .. } => {
+                if let Some(vote_index) = self.has_voted_approve(member) {
+                    self.remove_approval_vote(vote_index);
+                    return Ok(());
+                }
+                if let Some(vote_index) = self.has_voted_reject(member) {
+                    self.remove_rejection_vote(vote_index);
+                    return Ok(());
+                }
+                &mut self.extension.abstained
+            }
+            ProposalStatus::Approved { .. } => &mut self.cancelled,
+            _ => return err!(MultisigError::InvalidProposalStatus),
+        };
+
+        match votes.binary_search(&member) {
+            Ok(pos) => votes.remove(pos),
+            Err(_) => return err!(MultisigError::NoVoteToWithdraw),
+        };
+
+        Ok(())
+    }
+
+    /// If the number of rejections and abstentions reaches `cutoff`, the approval threshold
+    /// can no longer be reached, so mark the transaction as `Rejected`.
+    fn reject_if_cutoff_reached(&mut self, cutoff: usize) -> Result<()> {
+        if self.rejected.len() + self.extension.abstained.len() >= cutoff {
             self.status = ProposalStatus::Rejected {
                 timestamp: Clock::get()?.unix_timestamp,
             };
@@ -116,6 +195,12 @@ impl Proposal {
         self.rejected.binary_search(&member).ok()
     }
 
+    /// Check if the member abstained from voting on the transaction.
+    /// Returns `Some(index)` if `member` has abstained, with `index` into the `abstained` vec.
+    fn has_voted_abstain(&self, member: Pubkey) -> Option<usize> {
+        self.extension.abstained.binary_search(&member).ok()
+    }
+
     /// Delete the vote of rejection at the `index`.
     fn remove_rejection_vote(&mut self, index: usize) {
         self.rejected.remove(index);
@@ -126,8 +211,14 @@ impl Proposal {
         self.approved.remove(index);
     }
 
-    /// Check if the proposal account space needs to be reallocated to accommodate `cancelled` vec.
-    /// Proposal size is crated at creation, and thus may not accomodate enough space for all members to cancel if more are added or changed
+    /// Delete the vote of abstention at the `index`.
+    fn remove_abstention_vote(&mut self, index: usize) {
+        self.extension.abstained.remove(index);
+    }
+
+    /// Check if the proposal account space needs to be reallocated to accommodate the vote vecs,
+    /// e.g. `cancelled` and `abstained`.
+    /// Proposal size is crated at creation, and thus may not accomodate enough space for all members to vote if more are added or changed
     /// Returns `true` if the account was reallocated.
     pub fn realloc_if_needed<'a>(
         proposal: AccountInfo<'a>,
@@ -183,6 +274,23 @@ impl Proposal {
     }
 }
 
+/// Fields of `Proposal` added after the original layout of the account.
+/// Proposals created before have the default values.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct ProposalExtension {
+    /// The account that paid the rent for creating this account, refunded on close
+    /// if the multisig has no `rent_collector`.
+    pub rent_payer: Pubkey,
+    /// The multisig `invalidation_count` recorded when the proposal was last rebased
+    /// onto the current config with `proposal_rebase`, `None` if it never was.
+    pub rebased_invalidation_count: Option<u64>,
+    /// Keys that have abstained (Active only).
+    pub abstained: Vec<Pubkey>,
+    /// Number of votes cast with passkeys, included in the passkey challenges
+    /// so that a signed challenge can't be replayed.
+    pub passkey_nonce: u64,
+}
+
 /// The status of a proposal.
 /// Each variant wraps a timestamp of when the status was set.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
@@ -206,3 +314,108 @@ pub enum ProposalStatus {
     /// Proposal has been cancelled.
     Cancelled { timestamp: i64 },
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::state::MultisigExtension;
+
+    /// The layout of `Proposal` before the extension.
+    #[derive(AnchorSerialize)]
+    struct LegacyProposal {
+        multisig: Pubkey,
+        transaction_index: u64,
+        status: ProposalStatus,
+        bump: u8,
+        approved: Vec<Pubkey>,
+        rejected: Vec<Pubkey>,
+        cancelled: Vec<Pubkey>,
+    }
+
+    fn multisig(stale_transaction_index: u64, invalidation_count: u64) -> Multisig {
+        Multisig {
+            create_key: Pubkey::new_unique(),
+            config_authority: Pubkey::default(),
+            threshold: 1,
+            time_lock: 0,
+            transaction_index: stale_transaction_index.max(3),
+            stale_transaction_index,
+            rent_collector: None,
+            bump: 255,
+            members: vec![],
+            extension: AccountExtension::new(MultisigExtension {
+                invalidation_count,
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_legacy_proposal() {
+        let members_len = 3;
+        let legacy = LegacyProposal {
+            multisig: Pubkey::new_unique(),
+            transaction_index: 1,
+            status: ProposalStatus::Active { timestamp: 0 },
+            bump: 255,
+            approved: vec![Pubkey::new_unique()],
+            rejected: vec![],
+            cancelled: vec![],
+        };
+        // The accounts were allocated for the votes of all the members.
+        let legacy_size = 8 + 32 + 8 + 1 + 8 + 1 + 3 * (4 + members_len * 32);
+        let mut data = Proposal::DISCRIMINATOR.to_vec();
+        data.extend(legacy.try_to_vec().unwrap());
+        data.resize(legacy_size, 0);
+
+        let mut proposal = Proposal::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(proposal.approved, legacy.approved);
+        assert!(!proposal.extension.is_stored());
+        assert_eq!(proposal.extension.rent_payer, Pubkey::default());
+        assert!(!proposal.is_stale(&multisig(0, 0)));
+
+        // Approvals and rejections are written back within the allocated size.
+        proposal.reject(Pubkey::new_unique(), 3).unwrap();
+        let mut buffer = vec![0u8; legacy_size];
+        proposal.try_serialize(&mut &mut buffer[..]).unwrap();
+
+        // Abstentions may need the account to be reallocated.
+        proposal.abstain(Pubkey::new_unique(), 3).unwrap();
+        assert!(proposal.extension.is_stored());
+        assert!(Proposal::size(members_len) > legacy_size);
+        let mut buffer = vec![0u8; Proposal::size(members_len)];
+        proposal.try_serialize(&mut &mut buffer[..]).unwrap();
+    }
+
+    #[test]
+    fn test_is_stale() {
+        let mut proposal = Proposal::try_deserialize(
+            &mut &[
+                Proposal::DISCRIMINATOR.to_vec(),
+                LegacyProposal {
+                    multisig: Pubkey::new_unique(),
+                    transaction_index: 2,
+                    status: ProposalStatus::Active { timestamp: 0 },
+                    bump: 255,
+                    approved: vec![],
+                    rejected: vec![],
+                    cancelled: vec![],
+                }
+                .try_to_vec()
+                .unwrap(),
+            ]
+            .concat()[..],
+        )
+        .unwrap();
+
+        // Not rebased: stale once the config changes after the transaction is created.
+        assert!(!proposal.is_stale(&multisig(1, 1)));
+        assert!(proposal.is_stale(&multisig(2, 2)));
+
+        // Rebased: stale once the config changes after the rebase, whatever the transaction index.
+        proposal.extension.rebased_invalidation_count = Some(2);
+        assert!(!proposal.is_stale(&multisig(2, 2)));
+        assert!(!proposal.is_stale(&multisig(3, 2)));
+        assert!(proposal.is_stale(&multisig(2, 3)));
+    }
+}
@@ -0,0 +1,366 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::id;
+use crate::state::{Proposal, ProposalStatus};
+
+/// Caps how much can be executed from the vaults of a multisig in a period,
+/// so that a compromised quorum can't drain the vaults at once.
+/// Checked by all the vault and batch transaction execution instructions,
+/// as well as by the spending limits, allowances and streams.
+#[account]
+#[derive(InitSpace)]
+pub struct RateLimit {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+
+    /// Length of the rate limit period in seconds.
+    /// The limits apply to a window of that length sliding with time: the usage of the current period
+    /// is added to that of the previous one, weighted by how much of it still overlaps the window.
+    pub period: i64,
+
+    /// The number of vault transactions that can be executed in a period.
+    /// Each transaction of a batch counts as one. `None` means no limit.
+    pub max_executions: Option<u32>,
+
+    /// The amount of lamports that can be moved out of the vaults by the executed transactions in a period.
+    /// Only the native lamports of the vaults are counted, not their token balances.
+    /// `None` means no limit.
+    pub max_lamports: Option<u64>,
+
+    /// The number of approvals a proposal needs to be executed over the limits.
+    /// `None` means the approvals can't lift the limits.
+    pub over_limit_threshold: Option<u16>,
+
+    /// How many seconds must pass between the approval of a proposal and its execution over the limits.
+    /// `None` means waiting can't lift the limits.
+    pub over_limit_time_lock: Option<u32>,
+
+    /// Unix timestamp the current period started at.
+    pub period_start: i64,
+
+    /// The number of vault transactions executed in the current period.
+    pub executions: u32,
+
+    /// The amount of lamports moved out of the vaults in the current period.
+    pub lamports: u64,
+
+    /// The number of vault transactions executed in the previous period.
+    pub previous_executions: u32,
+
+    /// The amount of lamports moved out of the vaults in the previous period.
+    pub previous_lamports: u64,
+
+    /// PDA bump.
+    pub bump: u8,
+}
+
+impl RateLimit {
+    pub fn invariant(&self) -> Result<()> {
+        require!(self.period > 0, MultisigError::RateLimitInvalidPeriod);
+
+        if let Some(over_limit_threshold) = self.over_limit_threshold {
+            require_neq!(over_limit_threshold, 0, MultisigError::InvalidThreshold);
+        }
+
+        Ok(())
+    }
+
+    /// Deserialize the rate limit from `rate_limit_info`, or `None` if the multisig has no rate limit,
+    /// in which case the account is not initialized.
+    pub fn load(rate_limit_info: &AccountInfo) -> Result<Option<Self>> {
+        if rate_limit_info.owner != &id() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &**rate_limit_info.try_borrow_data()?,
+        )?))
+    }
+
+    /// Serialize the rate limit back into `rate_limit_info`.
+    pub fn save(&self, rate_limit_info: &AccountInfo) -> Result<()> {
+        self.try_serialize(&mut &mut rate_limit_info.try_borrow_mut_data()?[..])
+    }
+
+    /// Check and record `executions` vault transactions moving `lamports` out of the vaults at `now`.
+    /// Fails if the limits are exceeded, unless `proposal` is allowed to be executed over them.
+    /// Transfers made without a proposal, e.g. with a spending limit, can never exceed the limits.
+    pub fn check_and_record(
+        &mut self,
+        executions: u32,
+        lamports: u64,
+        proposal: Option<&Proposal>,
+        now: i64,
+    ) -> Result<()> {
+        self.advance_period(now);
+
+        self.executions = self.executions.saturating_add(executions);
+        self.lamports = self.lamports.saturating_add(lamports);
+
+        // The previous period counts for the part of it the window still covers.
+        let elapsed = now - self.period_start;
+        let window_executions = self.executions.saturating_add(
+            u32::try_from(self.weight_previous(u64::from(self.previous_executions), elapsed))
+                .unwrap_or(u32::MAX),
+        );
+        let window_lamports = self
+            .lamports
+            .saturating_add(self.weight_previous(self.previous_lamports, elapsed));
+
+        let is_over_limit = self
+            .max_executions
+            .is_some_and(|max_executions| window_executions > max_executions)
+            || self
+                .max_lamports
+                .is_some_and(|max_lamports| window_lamports > max_lamports);
+
+        if is_over_limit {
+            require!(
+                proposal.is_some_and(|proposal| self.allows_over_limit(proposal, now)),
+                MultisigError::RateLimitExceeded
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Move to the period `now` falls in. Periods follow each other from the first `period_start`,
+    /// so the usage of the current period becomes that of the previous one only if they are adjacent.
+    fn advance_period(&mut self, now: i64) {
+        let elapsed_periods = (now - self.period_start) / self.period;
+        if elapsed_periods <= 0 {
+            return;
+        }
+
+        if elapsed_periods == 1 {
+            self.previous_executions = self.executions;
+            self.previous_lamports = self.lamports;
+        } else {
+            self.previous_executions = 0;
+            self.previous_lamports = 0;
+        }
+        self.executions = 0;
+        self.lamports = 0;
+        self.period_start += elapsed_periods * self.period;
+    }
+
+    /// The part of the `previous` period usage still covered by the window, `elapsed` seconds
+    /// into the current period. Rounded up so that the window never undercounts.
+    fn weight_previous(&self, previous: u64, elapsed: i64) -> u64 {
+        let period = self.period as u128;
+        let remaining = (self.period - elapsed).clamp(0, self.period) as u128;
+        let weighted = (u128::from(previous) * remaining).div_ceil(period);
+        u64::try_from(weighted).unwrap_or(u64::MAX)
+    }
+
+    /// Changing or removing the rate limit is subject to the same conditions as executing over the limits,
+    /// otherwise the quorum the rate limit protects against could simply lift it.
+    /// If the limits can't be exceeded at all, the `proposal` must have been approved
+    /// at least a whole period ago.
+    pub fn check_change_allowed(&self, proposal: &Proposal, now: i64) -> Result<()> {
+        let is_allowed =
+            if self.over_limit_threshold.is_none() && self.over_limit_time_lock.is_none() {
+                matches!(
+                    proposal.status,
+                    ProposalStatus::Approved { timestamp } if now - timestamp >= self.period
+                )
+            } else {
+                self.allows_over_limit(proposal, now)
+            };
+
+        require!(is_allowed, MultisigError::RateLimitExceeded);
+
+        Ok(())
+    }
+
+    /// Whether `proposal` has enough approvals, or was approved long enough ago,
+    /// to be executed over the limits.
+    fn allows_over_limit(&self, proposal: &Proposal, now: i64) -> bool {
+        let ProposalStatus::Approved { timestamp } = proposal.status else {
+            return false;
+        };
+
+        self.over_limit_threshold
+            .is_some_and(|threshold| proposal.approved.len() >= usize::from(threshold))
+            || self
+                .over_limit_time_lock
+                .is_some_and(|time_lock| now - timestamp >= i64::from(time_lock))
+    }
+}
+
+/// The lamports of the `vault` if it's one of the `account_infos`, 0 otherwise.
+/// Used to measure how much a transaction moves out of the vault.
+pub fn vault_lamports(vault: &Pubkey, account_infos: &[AccountInfo]) -> u64 {
+    account_infos
+        .iter()
+        .find(|account_info| account_info.key == vault)
+        .map_or(0, |account_info| account_info.lamports())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::utils::AccountExtension;
+
+    fn new_rate_limit(max_executions: Option<u32>, max_lamports: Option<u64>) -> RateLimit {
+        RateLimit {
+            multisig: Pubkey::new_unique(),
+            period: 100,
+            max_executions,
+            max_lamports,
+            over_limit_threshold: None,
+            over_limit_time_lock: None,
+            period_start: 0,
+            executions: 0,
+            lamports: 0,
+            previous_executions: 0,
+            previous_lamports: 0,
+            bump: 255,
+        }
+    }
+
+    fn approved_proposal(approvals: usize, timestamp: i64) -> Proposal {
+        Proposal {
+            multisig: Pubkey::new_unique(),
+            transaction_index: 1,
+            status: ProposalStatus::Approved { timestamp },
+            bump: 255,
+            approved: (0..approvals).map(|_| Pubkey::new_unique()).collect(),
+            rejected: vec![],
+            cancelled: vec![],
+            extension: AccountExtension::default(),
+        }
+    }
+
+    #[test]
+    fn test_check_and_record_within_period() {
+        let mut rate_limit = new_rate_limit(Some(2), Some(1_000));
+
+        rate_limit.check_and_record(1, 400, None, 10).unwrap();
+        rate_limit.check_and_record(1, 600, None, 20).unwrap();
+        assert_eq!((rate_limit.executions, rate_limit.lamports), (2, 1_000));
+
+        assert_eq!(
+            rate_limit.check_and_record(1, 0, None, 99).unwrap_err(),
+            MultisigError::RateLimitExceeded.into()
+        );
+
+        let mut lamports_limit = new_rate_limit(None, Some(1_000));
+        lamports_limit.check_and_record(1, 1_000, None, 10).unwrap();
+        assert_eq!(
+            lamports_limit.check_and_record(1, 1, None, 10).unwrap_err(),
+            MultisigError::RateLimitExceeded.into()
+        );
+    }
+
+    #[test]
+    fn test_check_and_record_sliding_window() {
+        let mut rate_limit = new_rate_limit(Some(2), None);
+        rate_limit.check_and_record(2, 0, None, 90).unwrap();
+
+        // The previous period still fully counts at the start of the next one.
+        assert_eq!(
+            rate_limit
+                .clone()
+                .check_and_record(1, 0, None, 100)
+                .unwrap_err(),
+            MultisigError::RateLimitExceeded.into()
+        );
+
+        // Halfway through, it counts for half.
+        rate_limit.check_and_record(1, 0, None, 150).unwrap();
+        assert_eq!(rate_limit.period_start, 100);
+        assert_eq!(
+            (rate_limit.executions, rate_limit.previous_executions),
+            (1, 2)
+        );
+        assert!(rate_limit
+            .clone()
+            .check_and_record(1, 0, None, 150)
+            .is_err());
+
+        rate_limit.check_and_record(1, 0, None, 250).unwrap();
+        assert_eq!(rate_limit.period_start, 200);
+        assert_eq!(
+            (rate_limit.executions, rate_limit.previous_executions),
+            (1, 1)
+        );
+
+        // The usage is forgotten once a whole period has passed without executions.
+        rate_limit.check_and_record(2, 0, None, 420).unwrap();
+        assert_eq!(rate_limit.period_start, 400);
+        assert_eq!(
+            (rate_limit.executions, rate_limit.previous_executions),
+            (2, 0)
+        );
+    }
+
+    #[test]
+    fn test_check_and_record_no_burst_across_periods() {
+        let mut rate_limit = new_rate_limit(None, Some(1_000));
+        rate_limit.check_and_record(1, 1_000, None, 99).unwrap();
+
+        // A fixed window would allow moving another 1_000 lamports right away.
+        assert_eq!(
+            rate_limit
+                .clone()
+                .check_and_record(1, 1, None, 100)
+                .unwrap_err(),
+            MultisigError::RateLimitExceeded.into()
+        );
+        assert!(rate_limit
+            .clone()
+            .check_and_record(1, 501, None, 150)
+            .is_err());
+        rate_limit.check_and_record(1, 500, None, 150).unwrap();
+        assert_eq!(rate_limit.lamports, 500);
+    }
+
+    #[test]
+    fn test_check_and_record_over_limit() {
+        let mut rate_limit = new_rate_limit(Some(0), None);
+        rate_limit.over_limit_threshold = Some(3);
+        rate_limit.over_limit_time_lock = Some(50);
+
+        // Not enough approvals, not approved long enough ago.
+        assert!(rate_limit
+            .check_and_record(1, 0, Some(&approved_proposal(2, 10)), 20)
+            .is_err());
+        // Enough approvals.
+        rate_limit
+            .check_and_record(1, 0, Some(&approved_proposal(3, 10)), 20)
+            .unwrap();
+        // Approved long enough ago.
+        rate_limit
+            .check_and_record(1, 0, Some(&approved_proposal(2, 10)), 60)
+            .unwrap();
+        // Transfers without a proposal can never exceed the limits.
+        assert!(rate_limit.check_and_record(1, 0, None, 60).is_err());
+    }
+
+    #[test]
+    fn test_check_change_allowed() {
+        // Without an over-limit path, the change must have been approved a whole period ago.
+        let rate_limit = new_rate_limit(Some(1), None);
+        assert_eq!(
+            rate_limit
+                .check_change_allowed(&approved_proposal(5, 10), 109)
+                .unwrap_err(),
+            MultisigError::RateLimitExceeded.into()
+        );
+        rate_limit
+            .check_change_allowed(&approved_proposal(5, 10), 110)
+            .unwrap();
+
+        // Otherwise, the change is subject to the over-limit conditions.
+        let mut rate_limit = rate_limit;
+        rate_limit.over_limit_threshold = Some(3);
+        assert!(rate_limit
+            .check_change_allowed(&approved_proposal(2, 10), 1_000)
+            .is_err());
+        rate_limit
+            .check_change_allowed(&approved_proposal(3, 10), 10)
+            .unwrap();
+    }
+}
@@ -8,3 +8,8 @@ pub const SEED_VAULT: &[u8] = b"vault";
 pub const SEED_EPHEMERAL_SIGNER: &[u8] = b"ephemeral_signer";
 pub const SEED_SPENDING_LIMIT: &[u8] = b"spending_limit";
 pub const SEED_TRANSACTION_BUFFER: &[u8] = b"transaction_buffer";
+pub const SEED_ALLOWANCE: &[u8] = b"allowance";
+pub const SEED_RATE_LIMIT: &[u8] = b"rate_limit";
+pub const SEED_STREAM: &[u8] = b"stream";
+pub const SEED_SESSION_KEY: &[u8] = b"session_key";
+pub const SEED_PASSKEY: &[u8] = b"passkey";
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::{BalanceGuards, VaultTransaction};
+
+/// Key registered by a multisig member to act on their behalf for specific actions until it expires,
+/// so that members using hardware wallets don't have to sign every vote with them.
+/// The key acts with the permissions of the `member`, restricted to the `actions` of the session.
+#[account]
+#[derive(InitSpace)]
+pub struct SessionKey {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+
+    /// The member the session key acts on behalf of.
+    pub member: Pubkey,
+
+    /// The session key signing instead of the `member`.
+    pub key: Pubkey,
+
+    /// The actions the session key can take on behalf of the `member`.
+    pub actions: SessionActions,
+
+    /// If set, the session key can only act on vault transactions with balance guards
+    /// limiting the lamports moved out of the vault to at most this amount.
+    /// Token outflows are not limited by the session key.
+    pub max_lamports_outflow: Option<u64>,
+
+    /// Unix timestamp after which the session key can no longer be used.
+    pub expires_at: i64,
+
+    /// PDA bump.
+    pub bump: u8,
+}
+
+impl SessionKey {
+    /// Checks that the session key can take the `action` at `now`.
+    pub fn check_action(&self, action: SessionAction, now: i64) -> Result<()> {
+        require!(now < self.expires_at, MultisigError::SessionKeyExpired);
+        require!(
+            self.actions.has(action),
+            MultisigError::SessionKeyOutOfScope
+        );
+
+        Ok(())
+    }
+
+    /// Checks that the session key can act on a vault transaction with these `balance_guards`.
+    pub fn check_balance_guards(&self, balance_guards: Option<&BalanceGuards>) -> Result<()> {
+        if let Some(max_lamports_outflow) = self.max_lamports_outflow {
+            let lamports_outflow = balance_guards
+                .and_then(|balance_guards| balance_guards.max_lamports_outflow)
+                .ok_or(MultisigError::SessionKeyOutOfScope)?;
+
+            require!(
+                lamports_outflow <= max_lamports_outflow,
+                MultisigError::SessionKeyOutOfScope
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the session key can act on the proposal for the `transaction`.
+    /// If the session key has a lamports limit, `transaction` must be the `VaultTransaction` the proposal is for.
+    pub fn check_transaction(
+        &self,
+        transaction: Option<&AccountInfo>,
+        transaction_index: u64,
+    ) -> Result<()> {
+        if self.max_lamports_outflow.is_none() {
+            return Ok(());
+        }
+
+        let transaction = transaction.ok_or(MultisigError::MissingAccount)?;
+
+        require_keys_eq!(
+            *transaction.owner,
+            crate::id(),
+            MultisigError::IllegalAccountOwner
+        );
+
+        // Config transactions and batches are out of scope.
+        let data = transaction.try_borrow_data()?;
+        require!(
+            data.starts_with(VaultTransaction::DISCRIMINATOR),
+            MultisigError::SessionKeyOutOfScope
+        );
+        let transaction = VaultTransaction::try_deserialize(&mut &data[..])?;
+
+        require_keys_eq!(
+            transaction.multisig,
+            self.multisig,
+            MultisigError::TransactionForAnotherMultisig
+        );
+        require_eq!(
+            transaction.index,
+            transaction_index,
+            MultisigError::TransactionNotMatchingProposal
+        );
+
+        self.check_balance_guards(transaction.extension.balance_guards.as_ref())
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum SessionAction {
+    /// Create vault transactions.
+    Initiate = 1 << 0,
+    Approve = 1 << 1,
+    Reject = 1 << 2,
+    Abstain = 1 << 3,
+    Cancel = 1 << 4,
+    WithdrawVote = 1 << 5,
+}
+
+/// Bitmask for session key actions.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Eq, PartialEq, Clone, Copy, Default, Debug,
+)]
+pub struct SessionActions {
+    pub mask: u8,
+}
+
+impl SessionActions {
+    pub fn from_vec(actions: &[SessionAction]) -> Self {
+        let mut mask = 0;
+        for action in actions {
+            mask |= *action as u8;
+        }
+        Self { mask }
+    }
+
+    pub fn has(&self, action: SessionAction) -> bool {
+        self.mask & (action as u8) != 0
+    }
+}
@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+
+/// Vault-funded payout that vests linearly to the `recipient` between `start_time` and `end_time`.
+/// The recipient can claim the vested amount at any time without a vote.
+#[account]
+#[derive(InitSpace)]
+pub struct Stream {
+    /// The multisig this belongs to.
+    pub multisig: Pubkey,
+
+    /// Key that is used to seed the Stream PDA.
+    pub create_key: Pubkey,
+
+    /// The index of the vault that funds the stream.
+    pub vault_index: u8,
+
+    /// The account the vested tokens are paid to.
+    pub recipient: Pubkey,
+
+    /// The token mint the stream is for.
+    /// Pubkey::default() means SOL.
+    pub mint: Pubkey,
+
+    /// The total amount of tokens vested by `end_time`.
+    pub total_amount: u64,
+
+    /// The amount of tokens claimed by the `recipient` so far.
+    pub claimed_amount: u64,
+
+    /// Unix timestamp the vesting starts at.
+    pub start_time: i64,
+
+    /// Unix timestamp the whole `total_amount` is vested at.
+    pub end_time: i64,
+
+    /// Unix timestamp before which nothing can be claimed.
+    /// The amount vested until then becomes claimable at once.
+    pub cliff_time: Option<i64>,
+
+    /// PDA bump.
+    pub bump: u8,
+}
+
+impl Stream {
+    pub fn invariant(&self) -> Result<()> {
+        require_neq!(self.total_amount, 0, MultisigError::StreamInvalidAmount);
+
+        require!(
+            self.claimed_amount <= self.total_amount,
+            MultisigError::StreamInvalidAmount
+        );
+
+        require!(
+            self.start_time < self.end_time,
+            MultisigError::StreamInvalidSchedule
+        );
+
+        if let Some(cliff_time) = self.cliff_time {
+            require!(
+                self.start_time <= cliff_time && cliff_time <= self.end_time,
+                MultisigError::StreamInvalidSchedule
+            );
+        }
+
+        Ok(())
+    }
+
+    /// The amount of tokens vested at `now`.
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now < self.cliff_time.unwrap_or(self.start_time) || now <= self.start_time {
+            return 0;
+        }
+        if now >= self.end_time {
+            return self.total_amount;
+        }
+
+        let elapsed = u128::try_from(now - self.start_time).unwrap();
+        let duration = u128::try_from(self.end_time - self.start_time).unwrap();
+
+        // Can't overflow `u64`, as `elapsed < duration`.
+        u64::try_from(u128::from(self.total_amount) * elapsed / duration).unwrap()
+    }
+
+    /// The amount of tokens the `recipient` can claim at `now`.
+    pub fn claimable_amount(&self, now: i64) -> u64 {
+        self.vested_amount(now).saturating_sub(self.claimed_amount)
+    }
+
+    /// Stop the vesting at `now`: the amount vested so far stays claimable by the `recipient`,
+    /// the rest is no longer owed and remains in the vault.
+    /// Returns `true` if there's nothing left to claim, so the stream can be closed.
+    pub fn cancel(&mut self, now: i64) -> bool {
+        let vested_amount = self.vested_amount(now);
+
+        if vested_amount <= self.claimed_amount {
+            return true;
+        }
+
+        self.total_amount = vested_amount;
+        self.end_time = now;
+        self.cliff_time = None;
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn new_stream(cliff_time: Option<i64>) -> Stream {
+        Stream {
+            multisig: Pubkey::new_unique(),
+            create_key: Pubkey::new_unique(),
+            vault_index: 0,
+            recipient: Pubkey::new_unique(),
+            mint: Pubkey::default(),
+            total_amount: 1_000,
+            claimed_amount: 0,
+            start_time: 100,
+            end_time: 200,
+            cliff_time,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_vested_amount() {
+        let stream = new_stream(None);
+
+        assert_eq!(stream.vested_amount(0), 0);
+        assert_eq!(stream.vested_amount(100), 0);
+        assert_eq!(stream.vested_amount(125), 250);
+        assert_eq!(stream.vested_amount(199), 990);
+        assert_eq!(stream.vested_amount(200), 1_000);
+        assert_eq!(stream.vested_amount(i64::MAX), 1_000);
+    }
+
+    #[test]
+    fn test_vested_amount_cliff() {
+        let stream = new_stream(Some(150));
+
+        assert_eq!(stream.vested_amount(149), 0);
+        // The amount vested until the cliff becomes claimable at once.
+        assert_eq!(stream.vested_amount(150), 500);
+        assert_eq!(stream.vested_amount(175), 750);
+    }
+
+    #[test]
+    fn test_claimable_amount() {
+        let mut stream = new_stream(None);
+        stream.claimed_amount = 250;
+
+        assert_eq!(stream.claimable_amount(110), 0);
+        assert_eq!(stream.claimable_amount(150), 250);
+        assert_eq!(stream.claimable_amount(200), 750);
+    }
+
+    #[test]
+    fn test_cancel() {
+        // Nothing vested yet.
+        assert!(new_stream(None).cancel(50));
+
+        // Everything vested has been claimed.
+        let mut stream = new_stream(None);
+        stream.claimed_amount = 500;
+        assert!(stream.cancel(150));
+
+        // The vested amount stays claimable, the rest is no longer owed.
+        let mut stream = new_stream(Some(120));
+        stream.claimed_amount = 200;
+        assert!(!stream.cancel(150));
+        assert_eq!(stream.total_amount, 500);
+        assert_eq!(stream.end_time, 150);
+        assert_eq!(stream.cliff_time, None);
+        assert_eq!(stream.claimable_amount(150), 300);
+        assert_eq!(stream.claimable_amount(1_000), 300);
+        stream.invariant().unwrap();
+    }
+
+    #[test]
+    fn test_invariant() {
+        new_stream(Some(100)).invariant().unwrap();
+        new_stream(Some(200)).invariant().unwrap();
+
+        let mut stream = new_stream(None);
+        stream.total_amount = 0;
+        assert_eq!(
+            stream.invariant().unwrap_err(),
+            MultisigError::StreamInvalidAmount.into()
+        );
+
+        let mut stream = new_stream(None);
+        stream.claimed_amount = 1_001;
+        assert_eq!(
+            stream.invariant().unwrap_err(),
+            MultisigError::StreamInvalidAmount.into()
+        );
+
+        let mut stream = new_stream(None);
+        stream.end_time = stream.start_time;
+        assert_eq!(
+            stream.invariant().unwrap_err(),
+            MultisigError::StreamInvalidSchedule.into()
+        );
+
+        assert_eq!(
+            new_stream(Some(201)).invariant().unwrap_err(),
+            MultisigError::StreamInvalidSchedule.into()
+        );
+        assert_eq!(
+            new_stream(Some(99)).invariant().unwrap_err(),
+            MultisigError::StreamInvalidSchedule.into()
+        );
+    }
+}
@@ -1,12 +1,22 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
 use solana_program::hash::hash;
 
 use crate::errors::MultisigError;
+use crate::id;
+use crate::utils::AccountExtension;
 
 // Maximum PDA allocation size in an inner ix is 10240 bytes.
 // 10240 - account contents = 10128 bytes
+//
+// Buffers with a larger `final_buffer_size` are created with as much space as a single
+// allocation allows, and reallocated as they're extended.
 pub const MAX_BUFFER_SIZE: usize = 10128 ;
 
+/// Maximum size of the final assembled transaction message.
+pub const MAX_FINAL_BUFFER_SIZE: usize = 60 * 1024;
+
 #[account]
 #[derive(Default, Debug)]
 pub struct TransactionBuffer {
@@ -24,12 +34,17 @@ pub struct TransactionBuffer {
     pub final_buffer_size: u16,
     /// The buffer of the transaction message.
     pub buffer: Vec<u8>,
+    /// Fields added after the original layout of the account.
+    pub extension: AccountExtension<TransactionBufferExtension>,
 }
 
 impl TransactionBuffer {
-    pub fn size(final_message_buffer_size: u16) -> Result<usize> {
-        // Make sure final size is not greater than MAX_BUFFER_SIZE bytes.
-        if (final_message_buffer_size as usize) > MAX_BUFFER_SIZE {
+    pub fn size(
+        final_message_buffer_size: u16,
+        extension: &AccountExtension<TransactionBufferExtension>,
+    ) -> Result<usize> {
+        // Make sure final size is not greater than MAX_FINAL_BUFFER_SIZE bytes.
+        if (final_message_buffer_size as usize) > MAX_FINAL_BUFFER_SIZE {
             return err!(MultisigError::FinalBufferSizeExceeded);
         }
         Ok(
@@ -41,10 +56,110 @@ impl TransactionBuffer {
             32 +  // transaction_message_hash
             2 +  // final_buffer_size
             4 + // vec length bytes
-            final_message_buffer_size as usize, // buffer
+            final_message_buffer_size as usize + // buffer
+            extension.size(extension.value_size()), // extension
         )
     }
 
+    /// Size of the account when it's created: the buffer is allocated in full if the account
+    /// fits into a single allocation, otherwise it grows in `transaction_buffer_extend`.
+    pub fn initial_size(
+        final_message_buffer_size: u16,
+        extension: &AccountExtension<TransactionBufferExtension>,
+    ) -> Result<usize> {
+        Ok(Self::size(final_message_buffer_size, extension)?.min(MAX_PERMITTED_DATA_INCREASE))
+    }
+
+    /// Check if the transaction buffer account space needs to be reallocated to fit `buffer_size` bytes.
+    /// Returns `true` if the account was reallocated.
+    pub fn realloc_if_needed<'a>(
+        transaction_buffer: AccountInfo<'a>,
+        buffer_size: usize,
+        extension: &AccountExtension<TransactionBufferExtension>,
+        rent_payer: Option<AccountInfo<'a>>,
+        system_program: Option<AccountInfo<'a>>,
+    ) -> Result<bool> {
+        // Sanity checks
+        require_keys_eq!(
+            *transaction_buffer.owner,
+            id(),
+            MultisigError::IllegalAccountOwner
+        );
+
+        let current_account_size = transaction_buffer.data.borrow().len();
+        let account_size_to_fit_buffer = Self::size(
+            u16::try_from(buffer_size).map_err(|_| MultisigError::FinalBufferSizeExceeded)?,
+            extension,
+        )?;
+
+        // Check if we need to reallocate space.
+        if current_account_size >= account_size_to_fit_buffer {
+            return Ok(false);
+        }
+
+        // Reallocate more space.
+        #[allow(deprecated)]
+        AccountInfo::realloc(&transaction_buffer, account_size_to_fit_buffer, false)?;
+
+        // If more lamports are needed, transfer them to the account.
+        let rent_exempt_lamports = Rent::get()
+            .unwrap()
+            .minimum_balance(account_size_to_fit_buffer)
+            .max(1);
+        let top_up_lamports = rent_exempt_lamports.saturating_sub(transaction_buffer.lamports());
+
+        if top_up_lamports > 0 {
+            let system_program = system_program.ok_or(MultisigError::MissingAccount)?;
+            require_keys_eq!(
+                *system_program.key,
+                system_program::ID,
+                MultisigError::InvalidAccount
+            );
+
+            let rent_payer = rent_payer.ok_or(MultisigError::MissingAccount)?;
+
+            system_program::transfer(
+                CpiContext::new(
+                    system_program,
+                    system_program::Transfer {
+                        from: rent_payer,
+                        to: transaction_buffer,
+                    },
+                ),
+                top_up_lamports,
+            )?;
+        }
+
+        Ok(true)
+    }
+
+    /// Whether `member` can write to the buffer: the creator or one of the co-writers.
+    /// The caller must make sure `member` has `Initiate` permission in the multisig.
+    pub fn is_writer(&self, member: &Pubkey) -> bool {
+        self.creator == *member || self.extension.co_writers.contains(member)
+    }
+
+    /// Close the buffer, returning the rent to its creator.
+    /// `buffer_creator` is only required if `closer` is not the creator, e.g. a co-writer.
+    pub fn close_to_creator<'info>(
+        transaction_buffer: &Account<'info, TransactionBuffer>,
+        closer: AccountInfo<'info>,
+        buffer_creator: Option<AccountInfo<'info>>,
+    ) -> Result<()> {
+        let buffer_creator = match buffer_creator {
+            Some(buffer_creator) => buffer_creator,
+            None if closer.key() == transaction_buffer.creator => closer,
+            None => return err!(MultisigError::MissingAccount),
+        };
+        require_keys_eq!(
+            buffer_creator.key(),
+            transaction_buffer.creator,
+            MultisigError::InvalidAccount
+        );
+
+        transaction_buffer.close(buffer_creator)
+    }
+
     pub fn validate_hash(&self) -> Result<()> {
         let message_buffer_hash = hash(&self.buffer);
         require!(
@@ -64,18 +179,109 @@ impl TransactionBuffer {
 
     pub fn invariant(&self) -> Result<()> {
         require!(
-            self.final_buffer_size as usize <= MAX_BUFFER_SIZE,
-            MultisigError::FinalBufferSizeExceeded
-        );
-        require!(
-            self.buffer.len() <= MAX_BUFFER_SIZE,
+            self.final_buffer_size as usize <= MAX_FINAL_BUFFER_SIZE,
             MultisigError::FinalBufferSizeExceeded
         );
         require!(
             self.buffer.len() <= self.final_buffer_size as usize,
             MultisigError::FinalBufferSizeMismatch
         );
+        // Co-writers must be unique and not include the creator.
+        for (index, co_writer) in self.extension.co_writers.iter().enumerate() {
+            require!(
+                *co_writer != self.creator
+                    && !self.extension.co_writers[..index].contains(co_writer),
+                MultisigError::DuplicateMember
+            );
+        }
 
         Ok(())
     }
 }
+
+/// Fields of `TransactionBuffer` added after the original layout of the account.
+/// Buffers created before have the default values.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct TransactionBufferExtension {
+    /// Members besides the creator allowed to extend the buffer and create the transaction from it.
+    pub co_writers: Vec<Pubkey>,
+}
+
+impl TransactionBufferExtension {
+    pub fn value_size(&self) -> usize {
+        4  + // co_writers vector length
+        self.co_writers.len() * 32 // co_writers
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_initial_size() {
+        let extension = AccountExtension::new(TransactionBufferExtension {
+            co_writers: vec![Pubkey::new_unique()],
+        });
+
+        // Buffers that fit are allocated in full.
+        assert_eq!(
+            TransactionBuffer::initial_size(1000, &extension).unwrap(),
+            TransactionBuffer::size(1000, &extension).unwrap()
+        );
+        // Larger buffers start with a single allocation.
+        assert_eq!(
+            TransactionBuffer::initial_size(20_000, &extension).unwrap(),
+            10240
+        );
+        assert_eq!(
+            TransactionBuffer::initial_size(MAX_BUFFER_SIZE as u16, &extension).unwrap(),
+            10240
+        );
+        assert!(
+            TransactionBuffer::initial_size(MAX_FINAL_BUFFER_SIZE as u16 + 1, &extension).is_err()
+        );
+    }
+
+    #[test]
+    fn test_is_writer() {
+        let creator = Pubkey::new_unique();
+        let co_writer = Pubkey::new_unique();
+        let mut transaction_buffer = TransactionBuffer {
+            creator,
+            ..Default::default()
+        };
+        assert!(transaction_buffer.is_writer(&creator));
+        assert!(!transaction_buffer.is_writer(&co_writer));
+
+        transaction_buffer.extension = AccountExtension::new(TransactionBufferExtension {
+            co_writers: vec![co_writer],
+        });
+        assert!(transaction_buffer.is_writer(&co_writer));
+        assert!(!transaction_buffer.is_writer(&Pubkey::new_unique()));
+        assert!(transaction_buffer.invariant().is_ok());
+
+        // The creator can't be a co-writer, nor can a co-writer be listed twice.
+        transaction_buffer.extension.co_writers = vec![co_writer, co_writer];
+        assert!(transaction_buffer.invariant().is_err());
+        transaction_buffer.extension.co_writers = vec![creator];
+        assert!(transaction_buffer.invariant().is_err());
+    }
+
+    #[test]
+    fn test_invariant() {
+        let mut transaction_buffer = TransactionBuffer {
+            final_buffer_size: 20_000,
+            buffer: vec![0; 15_000],
+            ..Default::default()
+        };
+        assert!(transaction_buffer.invariant().is_ok());
+
+        transaction_buffer.buffer = vec![0; 20_001];
+        assert!(transaction_buffer.invariant().is_err());
+
+        transaction_buffer.final_buffer_size = MAX_FINAL_BUFFER_SIZE as u16 + 1;
+        transaction_buffer.buffer = vec![];
+        assert!(transaction_buffer.invariant().is_err());
+    }
+}
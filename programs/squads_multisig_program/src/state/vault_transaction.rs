@@ -4,6 +4,8 @@ use solana_borsh::v0_10::get_instance_packed_len;
 
 use crate::errors::*;
 use crate::instructions::{CompiledInstruction, MessageAddressTableLookup, TransactionMessage};
+use crate::state::{check_program_allowlist, AllowedProgram, Precondition};
+use crate::utils::AccountExtension;
 
 /// Stores data required for tracking the voting and execution status of a vault transaction.
 /// Vault transaction is a transaction that's executed on behalf of the multisig vault PDA
@@ -33,13 +35,22 @@ pub struct VaultTransaction {
     pub ephemeral_signer_bumps: Vec<u8>,
     /// data required for executing the transaction.
     pub message: VaultTransactionMessage,
+    /// Fields added after the original layout of the account.
+    pub extension: AccountExtension<VaultTransactionExtension>,
 }
 
 impl VaultTransaction {
-    pub fn size(ephemeral_signers_length: u8, transaction_message: &[u8]) -> Result<usize> {
+    pub fn size(
+        ephemeral_signers_length: u8,
+        transaction_message: &[u8],
+        balance_guards: &Option<BalanceGuards>,
+        preconditions: &[Precondition],
+    ) -> Result<usize> {
         let transaction_message: VaultTransactionMessage =
             TransactionMessage::deserialize(&mut &transaction_message[..])?.try_into()?;
         let message_size = get_instance_packed_len(&transaction_message).unwrap_or_default();
+        let balance_guards_size = 1 + balance_guards.as_ref().map_or(0, BalanceGuards::size);
+        let preconditions_size = 4 + preconditions.iter().map(Precondition::size).sum::<usize>();
 
         Ok(
             8 +   // anchor account discriminator
@@ -50,7 +61,15 @@ impl VaultTransaction {
             1 +   // vault_index
             1 +   // vault_bump
             (4 + usize::from(ephemeral_signers_length)) +   // ephemeral_signers_bumps vec
-            message_size, // message
+            message_size + // message
+            8 + // extension prefix
+            balance_guards_size + // balance_guards
+            1 + // allow_vault_account_changes
+            1 + // deny_ephemeral_signer_account_changes
+            preconditions_size + // preconditions
+            1 + // executed_instruction_index
+            32 + // rent_payer
+            1 + 32, // pending_message_hash
         )
     }
     /// Reduces the VaultTransaction to its default empty value and moves
@@ -60,6 +79,74 @@ impl VaultTransaction {
     }
 }
 
+/// Fields of `VaultTransaction` added after the original layout of the account.
+/// Transactions created before have the default values.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct VaultTransactionExtension {
+    /// Assertions on the vault balances checked after the transaction is executed.
+    pub balance_guards: Option<BalanceGuards>,
+    /// Whether the vault PDA is allowed to be assigned to another program
+    /// or to have data allocated on it during execution.
+    pub allow_vault_account_changes: bool,
+    /// Whether the ephemeral signer PDAs must stay owned by the System Program with no data
+    /// after execution. Off by default, as creating a mint or a token account at an ephemeral
+    /// signer is what they are for.
+    pub deny_ephemeral_signer_account_changes: bool,
+    /// Checks on the state of accounts that must hold right before the transaction is executed.
+    pub preconditions: Vec<Precondition>,
+    /// Number of the message instructions that have been executed.
+    /// 0 means that no instructions have been executed yet.
+    pub executed_instruction_index: u8,
+    /// The account that paid the rent for this account, refunded on close
+    /// if the multisig has no `rent_collector`.
+    pub rent_payer: Pubkey,
+    /// Hash of the message of a transaction created from a transaction buffer too large
+    /// to be moved in within a single instruction, `None` once the message is in place.
+    /// See `vault_transaction_extend_from_buffer`.
+    pub pending_message_hash: Option<[u8; 32]>,
+}
+
+/// Assertions on how much a transaction is allowed to move out of the vault.
+/// Checked against the balances of the vault and its token accounts before and after execution,
+/// so a transaction can't drain more than its approvers agreed to.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq, Eq)]
+pub struct BalanceGuards {
+    /// Maximum amount of lamports the vault is allowed to lose. `None` means no limit.
+    pub max_lamports_outflow: Option<u64>,
+    /// Maximum amounts of tokens allowed to leave the vault token accounts, per mint.
+    /// Mints not in the list are not limited.
+    pub max_token_outflows: Vec<TokenOutflowLimit>,
+}
+
+impl BalanceGuards {
+    pub fn size(&self) -> usize {
+        (1 + 8) + // max_lamports_outflow
+        4 + self.max_token_outflows.len() * TokenOutflowLimit::INIT_SPACE // max_token_outflows vec
+    }
+
+    pub fn invariant(&self) -> Result<()> {
+        // There must be at most one limit per mint.
+        for (index, limit) in self.max_token_outflows.iter().enumerate() {
+            require!(
+                self.max_token_outflows[..index]
+                    .iter()
+                    .all(|other| other.mint != limit.mint),
+                MultisigError::DuplicateTokenOutflowLimit
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TokenOutflowLimit {
+    /// Mint of the tokens.
+    pub mint: Pubkey,
+    /// Maximum amount of the tokens allowed to leave the vault token accounts.
+    pub max_amount: u64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
 pub struct VaultTransactionMessage {
     /// The number of signer pubkeys in the account_keys vec.
@@ -129,6 +216,21 @@ impl VaultTransactionMessage {
     pub fn is_signer_index(&self, key_index: usize) -> bool {
         key_index < usize::from(self.num_signers)
     }
+
+    /// Checks that all the instructions of the message are permitted by the `program_allowlist`.
+    /// Program IDs must be a part of static `account_keys`, so that they can be checked before execution.
+    pub fn check_program_allowlist(&self, program_allowlist: &[AllowedProgram]) -> Result<()> {
+        for instruction in &self.instructions {
+            let program_id = self
+                .account_keys
+                .get(usize::from(instruction.program_id_index))
+                .ok_or(MultisigError::InvalidTransactionMessage)?;
+
+            check_program_allowlist(program_allowlist, program_id, &instruction.data)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl TryFrom<TransactionMessage> for VaultTransactionMessage {
@@ -237,3 +339,56 @@ impl From<MessageAddressTableLookup> for MultisigMessageAddressTableLookup {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// The layout of `VaultTransaction` before the extension.
+    #[derive(AnchorSerialize)]
+    struct LegacyVaultTransaction {
+        multisig: Pubkey,
+        creator: Pubkey,
+        index: u64,
+        bump: u8,
+        vault_index: u8,
+        vault_bump: u8,
+        ephemeral_signer_bumps: Vec<u8>,
+        message: VaultTransactionMessage,
+    }
+
+    #[test]
+    fn test_legacy_vault_transaction() {
+        let legacy = LegacyVaultTransaction {
+            multisig: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            index: 1,
+            bump: 255,
+            vault_index: 0,
+            vault_bump: 255,
+            ephemeral_signer_bumps: vec![254],
+            message: VaultTransactionMessage {
+                num_signers: 1,
+                num_writable_signers: 1,
+                num_writable_non_signers: 0,
+                account_keys: vec![Pubkey::new_unique()],
+                instructions: vec![],
+                address_table_lookups: vec![],
+            },
+        };
+        let mut data = VaultTransaction::DISCRIMINATOR.to_vec();
+        data.extend(legacy.try_to_vec().unwrap());
+
+        let transaction = VaultTransaction::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(transaction.ephemeral_signer_bumps, vec![254]);
+        assert!(!transaction.extension.is_stored());
+        assert!(transaction.extension.balance_guards.is_none());
+        assert!(transaction.extension.preconditions.is_empty());
+        assert_eq!(transaction.extension.rent_payer, Pubkey::default());
+
+        // Written back within the allocated size.
+        let mut buffer = vec![0u8; data.len()];
+        transaction.try_serialize(&mut &mut buffer[..]).unwrap();
+        assert_eq!(buffer, data);
+    }
+}
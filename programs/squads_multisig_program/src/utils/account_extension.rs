@@ -0,0 +1,181 @@
+use std::io::{ErrorKind, Read, Write};
+use std::ops::{Deref, DerefMut};
+
+use anchor_lang::prelude::*;
+
+/// Marks the start of an `AccountExtension` in the account data.
+pub const ACCOUNT_EXTENSION_PREFIX: [u8; 8] = *b"SQDSEXT1";
+
+/// Fields appended to an account type after the fields of its original layout.
+///
+/// Accounts created before the extension was introduced end right after the original fields,
+/// or hold leftovers of a previous serialization there, because their vecs can shrink.
+/// So the extension is only read if it starts with `ACCOUNT_EXTENSION_PREFIX`,
+/// otherwise it takes the default value and is not written back as long as it stays default,
+/// which keeps such accounts within their allocated size.
+/// The account must be reallocated before any of the extension fields is set.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountExtension<T> {
+    /// Whether the extension was read from the account or has been created with `new`.
+    stored: bool,
+    value: T,
+}
+
+impl<T: Default + PartialEq> AccountExtension<T> {
+    /// An extension that is written to the account even if `value` is default.
+    pub fn new(value: T) -> Self {
+        Self {
+            stored: true,
+            value,
+        }
+    }
+
+    /// Whether the extension is written to the account.
+    pub fn is_stored(&self) -> bool {
+        self.stored || self.value != T::default()
+    }
+
+    /// Size of the serialized extension, given the size of its `value`.
+    pub fn size(&self, value_size: usize) -> usize {
+        if self.is_stored() {
+            ACCOUNT_EXTENSION_PREFIX.len() + value_size
+        } else {
+            0
+        }
+    }
+}
+
+/// Space of a stored extension, for the account types deriving `InitSpace`.
+impl<T: Space> Space for AccountExtension<T> {
+    const INIT_SPACE: usize = ACCOUNT_EXTENSION_PREFIX.len() + T::INIT_SPACE;
+}
+
+impl<T> Deref for AccountExtension<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for AccountExtension<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: AnchorSerialize + Default + PartialEq> AnchorSerialize for AccountExtension<T> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        if !self.is_stored() {
+            return Ok(());
+        }
+
+        writer.write_all(&ACCOUNT_EXTENSION_PREFIX)?;
+        self.value.serialize(writer)
+    }
+}
+
+impl<T: AnchorDeserialize + Default> AnchorDeserialize for AccountExtension<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut prefix = [0u8; ACCOUNT_EXTENSION_PREFIX.len()];
+
+        // The account ends before the extension, or holds something else there.
+        match reader.read_exact(&mut prefix) {
+            Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Ok(Self::default()),
+            result => result?,
+        }
+        if prefix != ACCOUNT_EXTENSION_PREFIX {
+            return Ok(Self::default());
+        }
+
+        Ok(Self {
+            stored: true,
+            value: T::deserialize_reader(reader)?,
+        })
+    }
+}
+
+// The extension is optional in the account data, so only the types of its value go to the IDL,
+// and the generated SDK reads the original fields of the account.
+#[cfg(feature = "idl-build")]
+impl<T: anchor_lang::IdlBuild> anchor_lang::IdlBuild for AccountExtension<T> {
+    fn insert_types(
+        types: &mut std::collections::BTreeMap<String, anchor_lang::idl::types::IdlTypeDef>,
+    ) {
+        if let Some(ty) = T::create_type() {
+            types.insert(T::get_full_path(), ty);
+        }
+        T::insert_types(types);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+    struct Extension {
+        a: u64,
+        b: Vec<u8>,
+    }
+
+    #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+    struct TestAccount {
+        members: Vec<u8>,
+        extension: AccountExtension<Extension>,
+    }
+
+    #[test]
+    fn test_deserialize_without_extension() {
+        let data = TestAccount::default().try_to_vec().unwrap();
+        assert_eq!(data, vec![0, 0, 0, 0]);
+
+        let account = TestAccount::deserialize(&mut &data[..]).unwrap();
+        assert!(!account.extension.is_stored());
+        assert_eq!(*account.extension, Extension::default());
+    }
+
+    #[test]
+    fn test_deserialize_with_leftover_bytes() {
+        // A previous serialization with more members left some bytes after the vec.
+        let data = [&[1, 0, 0, 0, 7][..], &[7; 3], &[0; 16]].concat();
+
+        let account = TestAccount::deserialize(&mut &data[..]).unwrap();
+        assert_eq!(account.members, vec![7]);
+        assert!(!account.extension.is_stored());
+
+        // Nothing is written back for the default extension.
+        assert_eq!(account.try_to_vec().unwrap(), vec![1, 0, 0, 0, 7]);
+    }
+
+    #[test]
+    fn test_roundtrip_with_extension() {
+        let mut account = TestAccount::default();
+        account.extension.a = 5;
+        account.extension.b = vec![1, 2];
+        assert!(account.extension.is_stored());
+
+        let data = account.try_to_vec().unwrap();
+        assert_eq!(data.len(), 4 + account.extension.size(8 + 4 + 2));
+        assert_eq!(data[4..12], ACCOUNT_EXTENSION_PREFIX);
+
+        // Followed by leftover bytes.
+        let data = [&data[..], &[9; 10]].concat();
+        let deserialized = TestAccount::deserialize(&mut &data[..]).unwrap();
+        assert_eq!(*deserialized.extension, *account.extension);
+    }
+
+    #[test]
+    fn test_stored_extension_stays_stored() {
+        let account = TestAccount {
+            members: vec![],
+            extension: AccountExtension::new(Extension::default()),
+        };
+
+        let data = account.try_to_vec().unwrap();
+        assert_eq!(data.len(), 4 + 8 + 8 + 4);
+
+        let account = TestAccount::deserialize(&mut &data[..]).unwrap();
+        assert!(account.extension.is_stored());
+    }
+}
@@ -0,0 +1,237 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{token, token_2022};
+
+use crate::errors::*;
+use crate::state::*;
+
+/// Size of the base `Account` layout shared by the Token and Token-2022 programs.
+const TOKEN_ACCOUNT_LEN: usize = 165;
+/// `AccountType::Account` discriminator of the Token-2022 accounts with extensions.
+const TOKEN_2022_ACCOUNT_TYPE: u8 = 2;
+
+/// Balances of the vault and its token accounts taken before a transaction is executed,
+/// so they can be compared with the balances after the execution.
+pub struct VaultBalances<'a, 'info> {
+    vault_pubkey: Pubkey,
+    /// The vault account, `None` if the transaction doesn't reference it.
+    vault: Option<&'a AccountInfo<'info>>,
+    lamports: u64,
+    /// Token accounts owned by the vault: the account, its mint and amount.
+    token_accounts: Vec<(&'a AccountInfo<'info>, Pubkey, u64)>,
+}
+
+impl<'a, 'info> VaultBalances<'a, 'info> {
+    /// Takes a snapshot of the balances of the vault and its token accounts among `accounts`.
+    pub fn snapshot(vault_pubkey: &Pubkey, accounts: &[&'a AccountInfo<'info>]) -> Self {
+        let vault = accounts
+            .iter()
+            .find(|account| account.key == vault_pubkey)
+            .copied();
+
+        let token_accounts = accounts
+            .iter()
+            .filter_map(|account| {
+                let (mint, amount) = vault_token_balance(vault_pubkey, account)?;
+                Some((*account, mint, amount))
+            })
+            .collect();
+
+        Self {
+            vault_pubkey: *vault_pubkey,
+            vault,
+            lamports: vault.map(|vault| vault.lamports()).unwrap_or_default(),
+            token_accounts,
+        }
+    }
+
+    /// Compares the current balances with the snapshot and checks that the outflows
+    /// don't exceed the `balance_guards`.
+    pub fn check_balance_guards(&self, balance_guards: &BalanceGuards) -> Result<()> {
+        if let (Some(max_lamports_outflow), Some(vault)) =
+            (balance_guards.max_lamports_outflow, self.vault)
+        {
+            let lamports_outflow = self.lamports.saturating_sub(vault.lamports());
+            require!(
+                lamports_outflow <= max_lamports_outflow,
+                MultisigError::LamportsOutflowExceeded
+            );
+        }
+
+        for limit in &balance_guards.max_token_outflows {
+            let (pre_amount, post_amount) = self
+                .token_accounts
+                .iter()
+                .filter(|(_, mint, _)| *mint == limit.mint)
+                .fold((0u128, 0u128), |(pre, post), (account, mint, amount)| {
+                    // Accounts that were closed or handed over to another owner
+                    // count as fully drained.
+                    let post_amount = vault_token_balance(&self.vault_pubkey, account)
+                        .filter(|(post_mint, _)| post_mint == mint)
+                        .map(|(_, amount)| amount)
+                        .unwrap_or_default();
+                    (pre + u128::from(*amount), post + u128::from(post_amount))
+                });
+
+            require!(
+                pre_amount.saturating_sub(post_amount) <= u128::from(limit.max_amount),
+                MultisigError::TokenOutflowExceeded
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the mint and amount of `account` if it's a token account owned by the vault.
+pub fn vault_token_balance(vault_pubkey: &Pubkey, account: &AccountInfo) -> Option<(Pubkey, u64)> {
+    if *account.owner != token::ID && *account.owner != token_2022::ID {
+        return None;
+    }
+
+    let data = account.try_borrow_data().ok()?;
+
+    // Token-2022 accounts with extensions are longer than the base layout and are marked
+    // with the account type right after it, which tells them apart from mints.
+    match data.len() {
+        TOKEN_ACCOUNT_LEN => {}
+        len if len > TOKEN_ACCOUNT_LEN && data[TOKEN_ACCOUNT_LEN] == TOKEN_2022_ACCOUNT_TYPE => {}
+        _ => return None,
+    }
+
+    // Base account layout: mint (32), owner (32), amount (8), ...
+    let owner = Pubkey::try_from(&data[32..64]).ok()?;
+    if owner != *vault_pubkey {
+        return None;
+    }
+
+    let mint = Pubkey::try_from(&data[..32]).ok()?;
+    let amount = u64::from_le_bytes(data[64..72].try_into().ok()?);
+
+    Some((mint, amount))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn token_account_data(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Vec<u8> {
+        let mut data = vec![0u8; TOKEN_ACCOUNT_LEN];
+        data[..32].copy_from_slice(mint.as_ref());
+        data[32..64].copy_from_slice(owner.as_ref());
+        data[64..72].copy_from_slice(&amount.to_le_bytes());
+        data
+    }
+
+    fn set_token_amount(account: &AccountInfo, amount: u64) {
+        account.try_borrow_mut_data().unwrap()[64..72].copy_from_slice(&amount.to_le_bytes());
+    }
+
+    fn guards(max_lamports_outflow: Option<u64>, mint: Pubkey, max_amount: u64) -> BalanceGuards {
+        BalanceGuards {
+            max_lamports_outflow,
+            max_token_outflows: vec![TokenOutflowLimit { mint, max_amount }],
+        }
+    }
+
+    #[test]
+    fn test_check_balance_guards() {
+        let (vault_key, token_account_key, mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let system_program_id = anchor_lang::system_program::ID;
+        let (mut vault_lamports, mut token_account_lamports) = (1_000, 1);
+        let mut vault_data = [];
+        let mut token_account_data = token_account_data(&mint, &vault_key, 500);
+        let vault = AccountInfo::new(
+            &vault_key,
+            false,
+            true,
+            &mut vault_lamports,
+            &mut vault_data,
+            &system_program_id,
+            false,
+            0,
+        );
+        let token_account = AccountInfo::new(
+            &token_account_key,
+            false,
+            true,
+            &mut token_account_lamports,
+            &mut token_account_data,
+            &token::ID,
+            false,
+            0,
+        );
+
+        let balances = VaultBalances::snapshot(&vault_key, &[&vault, &token_account]);
+
+        **vault.try_borrow_mut_lamports().unwrap() = 700;
+        set_token_amount(&token_account, 300);
+
+        balances
+            .check_balance_guards(&guards(Some(300), mint, 200))
+            .unwrap();
+        assert_eq!(
+            balances
+                .check_balance_guards(&guards(Some(299), mint, 200))
+                .unwrap_err(),
+            MultisigError::LamportsOutflowExceeded.into()
+        );
+        assert_eq!(
+            balances
+                .check_balance_guards(&guards(Some(300), mint, 199))
+                .unwrap_err(),
+            MultisigError::TokenOutflowExceeded.into()
+        );
+
+        // Other mints are not limited.
+        balances
+            .check_balance_guards(&guards(None, Pubkey::new_unique(), 0))
+            .unwrap();
+
+        // A token account handed over to another owner counts as fully drained.
+        token_account.try_borrow_mut_data().unwrap()[32..64]
+            .copy_from_slice(Pubkey::new_unique().as_ref());
+        balances
+            .check_balance_guards(&guards(None, mint, 500))
+            .unwrap();
+        assert_eq!(
+            balances
+                .check_balance_guards(&guards(None, mint, 499))
+                .unwrap_err(),
+            MultisigError::TokenOutflowExceeded.into()
+        );
+    }
+
+    #[test]
+    fn test_vault_token_balance() {
+        let (vault_key, account_key, mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut lamports = 1;
+
+        // Token-2022 accounts with extensions are recognized by their account type.
+        let mut data = token_account_data(&mint, &vault_key, 42);
+        data.extend([TOKEN_2022_ACCOUNT_TYPE, 0, 0]);
+        let account = AccountInfo::new(
+            &account_key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &token_2022::ID,
+            false,
+            0,
+        );
+        assert_eq!(vault_token_balance(&vault_key, &account), Some((mint, 42)));
+        assert_eq!(vault_token_balance(&Pubkey::new_unique(), &account), None);
+
+        // Mints with extensions are not token accounts.
+        account.try_borrow_mut_data().unwrap()[TOKEN_ACCOUNT_LEN] = 1;
+        assert_eq!(vault_token_balance(&vault_key, &account), None);
+    }
+}
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::convert::From;
 
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use solana_address_lookup_table_interface as address_lookup_table;
 use solana_address_lookup_table_interface::state::AddressLookupTable;
 use solana_program::instruction::Instruction;
@@ -20,6 +21,10 @@ pub struct ExecutableTransactionMessage<'a, 'info> {
     loaded_writable_accounts: Vec<&'a AccountInfo<'info>>,
     /// Concatenated vector of resolved `readonly_indexes` from all address lookups.
     loaded_readonly_accounts: Vec<&'a AccountInfo<'info>>,
+    /// The vault PDA among the `static_accounts`, if the message references it.
+    vault_account: Option<&'a AccountInfo<'info>>,
+    /// The ephemeral signer PDAs among the `static_accounts`.
+    ephemeral_signer_accounts: Vec<&'a AccountInfo<'info>>,
 }
 
 impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
@@ -72,6 +77,8 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
         );
 
         let mut static_accounts = Vec::new();
+        let mut vault_account = None;
+        let mut ephemeral_signer_accounts = Vec::new();
 
         // CHECK: `message.account_keys` should come first in `account_infos` and have modifiers expected by the message.
         for (i, account_key) in message.account_keys.iter().enumerate() {
@@ -84,11 +91,14 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
             // If the account is marked as signer in the message, it must be a signer in the account infos too.
             // Unless it's a vault or an ephemeral signer PDA, as they cannot be passed as signers to `remaining_accounts`,
             // because they are PDA's and can't sign the transaction.
-            if message.is_signer_index(i)
-                && account_info.key != vault_pubkey
-                && !ephemeral_signer_pdas.contains(account_info.key)
-            {
-                require!(account_info.is_signer, MultisigError::InvalidAccount);
+            if message.is_signer_index(i) {
+                if account_info.key == vault_pubkey {
+                    vault_account = Some(account_info);
+                } else if ephemeral_signer_pdas.contains(account_info.key) {
+                    ephemeral_signer_accounts.push(account_info);
+                } else {
+                    require!(account_info.is_signer, MultisigError::InvalidAccount);
+                }
             }
             // If the account is marked as writable in the message, it must be writable in the account infos too.
             if message.is_static_writable_index(i) {
@@ -169,6 +179,8 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
             static_accounts,
             loaded_writable_accounts: writable_accounts,
             loaded_readonly_accounts: readonly_accounts,
+            vault_account,
+            ephemeral_signer_accounts,
         })
     }
 
@@ -177,11 +189,17 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
     /// * `vault_seeds` - Seeds for the vault PDA.
     /// * `ephemeral_signer_seeds` - Seeds for the ephemeral signer PDAs.
     /// * `protected_accounts` - Accounts that must not be passed as writable to the CPI calls to prevent potential reentrancy attacks.
+    /// * `program_allowlist` - Programs the instructions are allowed to invoke, `None` if any program can be invoked.
+    /// * `allow_vault_account_changes` - Whether the vault PDA may end up assigned to another program or with data.
+    /// * `deny_ephemeral_signer_account_changes` - Whether the ephemeral signer PDAs must not end up assigned to another program or with data.
     pub fn execute_message(
         self,
         vault_seeds: &[&[u8]],
         ephemeral_signer_seeds: &[Vec<Vec<u8>>],
         protected_accounts: &[Pubkey],
+        program_allowlist: Option<&[AllowedProgram]>,
+        allow_vault_account_changes: bool,
+        deny_ephemeral_signer_account_changes: bool,
     ) -> Result<()> {
         // First round of type conversion; from Vec<Vec<Vec<u8>>> to Vec<Vec<&[u8]>>.
         let ephemeral_signer_seeds = &ephemeral_signer_seeds
@@ -196,6 +214,15 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
         // Add the vault seeds.
         signer_seeds.push(&vault_seeds);
 
+        // Collect the signer PDAs that must keep their state.
+        let mut checked_signer_accounts = Vec::new();
+        if !allow_vault_account_changes {
+            checked_signer_accounts.extend(self.vault_account);
+        }
+        if deny_ephemeral_signer_account_changes {
+            checked_signer_accounts.extend(self.ephemeral_signer_accounts.iter().copied());
+        }
+
         // NOTE: `self.to_instructions_and_accounts()` calls `take()` on
         // `self.message.instructions`, therefore after this point no more
         // references or usages of `self.message` should be made to avoid
@@ -208,11 +235,36 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
                     MultisigError::ProtectedAccount
                 );
             }
+            // Make sure the instruction is permitted by the program allowlist, if it's set.
+            if let Some(program_allowlist) = program_allowlist {
+                check_program_allowlist(program_allowlist, &ix.program_id, &ix.data)?;
+            }
             invoke_signed(&ix, &account_infos, &signer_seeds)?;
         }
+
+        // Make sure the vault and ephemeral signers weren't assigned to another program
+        // or allocated data on, which would permanently break the multisig.
+        for account in checked_signer_accounts {
+            require!(
+                account.owner == &system_program::ID && account.data_is_empty(),
+                MultisigError::SignerAccountStateChanged
+            );
+        }
+
         Ok(())
     }
 
+    /// Accounts the message requests as writable, the only ones whose balances it can change.
+    pub fn writable_accounts(&self) -> Vec<&'a AccountInfo<'info>> {
+        self.static_accounts
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.message.is_static_writable_index(*index))
+            .map(|(_, account)| *account)
+            .chain(self.loaded_writable_accounts.iter().copied())
+            .collect()
+    }
+
     /// Account indices are resolved in the following order:
     /// 1. Static accounts.
     /// 2. All loaded writable accounts.
@@ -1,9 +1,17 @@
+mod account_extension;
+mod balance_guards;
 mod ephemeral_signers;
 mod executable_transaction_message;
+mod passkey;
 mod small_vec;
 mod system;
+mod vault_index;
 
+pub use account_extension::*;
+pub use balance_guards::*;
 pub use ephemeral_signers::*;
 pub use executable_transaction_message::*;
+pub use passkey::*;
 pub use small_vec::*;
 pub use system::*;
+pub use vault_index::*;
@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+use solana_program::hash::hash;
+use solana_sdk_ids::secp256r1_program;
+
+use crate::errors::*;
+
+/// Size of `Secp256r1SignatureOffsets` in the secp256r1 precompile instruction data.
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+/// Size of the compressed secp256r1 public key.
+const PUBKEY_SIZE: usize = 33;
+/// Size of the WebAuthn authenticator data up to and including the signature counter.
+const AUTHENTICATOR_DATA_MIN_SIZE: usize = 37;
+/// "User present" flag of the WebAuthn authenticator data.
+const USER_PRESENT_FLAG: u8 = 0x01;
+
+/// Checks that the instruction right before the current one is a secp256r1 precompile instruction
+/// verifying a WebAuthn assertion of `pubkey`, over the `client_data_json` containing `challenge`.
+/// The precompile fails the whole transaction if the signature is invalid, so here we only need to check
+/// that it verified the expected public key and message.
+pub fn verify_passkey_signature(
+    instructions_sysvar: &AccountInfo,
+    pubkey: &[u8; PUBKEY_SIZE],
+    client_data_json: &[u8],
+    challenge: &[u8; 32],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let verify_index = current_index
+        .checked_sub(1)
+        .ok_or(MultisigError::PasskeyMissingSignature)?;
+    let verify_instruction =
+        load_instruction_at_checked(usize::from(verify_index), instructions_sysvar)?;
+
+    require_keys_eq!(
+        verify_instruction.program_id,
+        secp256r1_program::ID,
+        MultisigError::PasskeyMissingSignature
+    );
+
+    check_passkey_assertion(
+        &verify_instruction.data,
+        pubkey,
+        client_data_json,
+        challenge,
+    )
+}
+
+/// Checks that the secp256r1 precompile instruction `data` verifies a WebAuthn assertion of `pubkey`,
+/// over the `client_data_json` containing `challenge`.
+fn check_passkey_assertion(
+    data: &[u8],
+    pubkey: &[u8; PUBKEY_SIZE],
+    client_data_json: &[u8],
+    challenge: &[u8; 32],
+) -> Result<()> {
+    // Exactly one signature, so we know which one is checked.
+    require!(
+        data.len() >= 2 + SIGNATURE_OFFSETS_SIZE && data[0] == 1,
+        MultisigError::PasskeyInvalidSignature
+    );
+
+    // `Secp256r1SignatureOffsets` of the signature, right after the signature count and padding.
+    let offset = |index: usize| u16::from_le_bytes([data[2 + index * 2], data[3 + index * 2]]);
+    let signature_instruction_index = offset(1);
+    let public_key_offset = offset(2);
+    let public_key_instruction_index = offset(3);
+    let message_data_offset = offset(4);
+    let message_data_size = offset(5);
+    let message_instruction_index = offset(6);
+
+    // The signature, public key and message must be in the precompile instruction itself,
+    // otherwise they could come from anywhere in the transaction.
+    require!(
+        [
+            signature_instruction_index,
+            public_key_instruction_index,
+            message_instruction_index,
+        ]
+        .iter()
+        .all(|&index| index == u16::MAX),
+        MultisigError::PasskeyInvalidSignature
+    );
+
+    let public_key_offset = usize::from(public_key_offset);
+    let verified_pubkey = data
+        .get(public_key_offset..public_key_offset + PUBKEY_SIZE)
+        .ok_or(MultisigError::PasskeyInvalidSignature)?;
+    require!(
+        verified_pubkey == pubkey,
+        MultisigError::PasskeyInvalidSignature
+    );
+
+    // WebAuthn signs `authenticator_data || sha256(client_data_json)`.
+    let message_data_offset = usize::from(message_data_offset);
+    let message = data
+        .get(message_data_offset..message_data_offset + usize::from(message_data_size))
+        .ok_or(MultisigError::PasskeyInvalidSignature)?;
+    require!(
+        message.len() >= AUTHENTICATOR_DATA_MIN_SIZE + 32,
+        MultisigError::PasskeyInvalidSignature
+    );
+    let (authenticator_data, client_data_hash) = message.split_at(message.len() - 32);
+
+    require!(
+        client_data_hash == hash(client_data_json).as_ref(),
+        MultisigError::PasskeyInvalidSignature
+    );
+    require!(
+        authenticator_data[32] & USER_PRESENT_FLAG != 0,
+        MultisigError::PasskeyInvalidSignature
+    );
+
+    // The client data must be for an assertion of the `challenge`.
+    let expected_type = br#""type":"webauthn.get""#;
+    let expected_challenge = format!(r#""challenge":"{}""#, URL_SAFE_NO_PAD.encode(challenge));
+    require!(
+        contains(client_data_json, expected_type)
+            && contains(client_data_json, expected_challenge.as_bytes()),
+        MultisigError::PasskeyInvalidSignature
+    );
+
+    Ok(())
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CHALLENGE: [u8; 32] = [7; 32];
+    const PUBKEY: [u8; PUBKEY_SIZE] = [2; PUBKEY_SIZE];
+
+    fn client_data(challenge: &[u8; 32]) -> Vec<u8> {
+        format!(
+            r#"{{"type":"webauthn.get","challenge":"{}","origin":"https://example.com"}}"#,
+            URL_SAFE_NO_PAD.encode(challenge)
+        )
+        .into_bytes()
+    }
+
+    /// Precompile instruction data with a single signature over
+    /// `authenticator_data || sha256(client_data_json)`, all in the instruction itself.
+    fn precompile_data(pubkey: &[u8; PUBKEY_SIZE], client_data_json: &[u8], flags: u8) -> Vec<u8> {
+        let mut authenticator_data = vec![0u8; AUTHENTICATOR_DATA_MIN_SIZE];
+        authenticator_data[32] = flags;
+        let message = [
+            authenticator_data.as_slice(),
+            hash(client_data_json).as_ref(),
+        ]
+        .concat();
+
+        let signature_offset = 2 + SIGNATURE_OFFSETS_SIZE;
+        let public_key_offset = signature_offset + 64;
+        let message_data_offset = public_key_offset + PUBKEY_SIZE;
+
+        let mut data = vec![1, 0];
+        for offset in [
+            signature_offset,
+            usize::from(u16::MAX),
+            public_key_offset,
+            usize::from(u16::MAX),
+            message_data_offset,
+            message.len(),
+            usize::from(u16::MAX),
+        ] {
+            data.extend((offset as u16).to_le_bytes());
+        }
+        data.extend([0u8; 64]);
+        data.extend(pubkey);
+        data.extend(message);
+        data
+    }
+
+    #[test]
+    fn test_check_passkey_assertion() {
+        let client_data_json = client_data(&CHALLENGE);
+        let data = precompile_data(&PUBKEY, &client_data_json, USER_PRESENT_FLAG);
+
+        check_passkey_assertion(&data, &PUBKEY, &client_data_json, &CHALLENGE).unwrap();
+    }
+
+    #[test]
+    fn test_check_passkey_assertion_rejects_mismatches() {
+        let client_data_json = client_data(&CHALLENGE);
+        let data = precompile_data(&PUBKEY, &client_data_json, USER_PRESENT_FLAG);
+        let invalid = MultisigError::PasskeyInvalidSignature.into();
+
+        // Another passkey.
+        assert_eq!(
+            check_passkey_assertion(&data, &[3; PUBKEY_SIZE], &client_data_json, &CHALLENGE)
+                .unwrap_err(),
+            invalid
+        );
+
+        // Another challenge.
+        assert_eq!(
+            check_passkey_assertion(&data, &PUBKEY, &client_data_json, &[8; 32]).unwrap_err(),
+            invalid
+        );
+
+        // Client data not matching the signed hash.
+        let other_client_data_json = client_data(&[8; 32]);
+        assert_eq!(
+            check_passkey_assertion(&data, &PUBKEY, &other_client_data_json, &[8; 32]).unwrap_err(),
+            invalid
+        );
+
+        // Not an assertion.
+        let create_client_data_json = String::from_utf8(client_data_json.clone())
+            .unwrap()
+            .replace("webauthn.get", "webauthn.create")
+            .into_bytes();
+        let data = precompile_data(&PUBKEY, &create_client_data_json, USER_PRESENT_FLAG);
+        assert_eq!(
+            check_passkey_assertion(&data, &PUBKEY, &create_client_data_json, &CHALLENGE)
+                .unwrap_err(),
+            invalid
+        );
+
+        // User not present.
+        let data = precompile_data(&PUBKEY, &client_data_json, 0);
+        assert_eq!(
+            check_passkey_assertion(&data, &PUBKEY, &client_data_json, &CHALLENGE).unwrap_err(),
+            invalid
+        );
+
+        // Public key taken from another instruction.
+        let mut data = precompile_data(&PUBKEY, &client_data_json, USER_PRESENT_FLAG);
+        data[8..10].copy_from_slice(&0u16.to_le_bytes());
+        assert_eq!(
+            check_passkey_assertion(&data, &PUBKEY, &client_data_json, &CHALLENGE).unwrap_err(),
+            invalid
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::errors::*;
+use crate::state::*;
+
+/// Returns the index of the vault the `transaction` account is bound to,
+/// or `None` if the transaction is not bound to a vault, i.e. a `ConfigTransaction`.
+///
+/// `transaction` must be a `VaultTransaction`, `Batch` or `ConfigTransaction` account
+/// of the `multisig` with `transaction_index`.
+pub fn transaction_vault_index(
+    transaction: &AccountInfo,
+    multisig: &Pubkey,
+    transaction_index: u64,
+) -> Result<Option<u8>> {
+    require_keys_eq!(
+        *transaction.owner,
+        crate::id(),
+        MultisigError::IllegalAccountOwner
+    );
+
+    let data = transaction.try_borrow_data()?;
+
+    let (transaction_multisig, index, vault_index) =
+        if data.starts_with(VaultTransaction::DISCRIMINATOR) {
+            let vault_transaction = VaultTransaction::try_deserialize(&mut &data[..])?;
+            (
+                vault_transaction.multisig,
+                vault_transaction.index,
+                Some(vault_transaction.vault_index),
+            )
+        } else if data.starts_with(Batch::DISCRIMINATOR) {
+            let batch = Batch::try_deserialize(&mut &data[..])?;
+            (batch.multisig, batch.index, Some(batch.vault_index))
+        } else if data.starts_with(Endorsement::DISCRIMINATOR) {
+            let endorsement = Endorsement::try_deserialize(&mut &data[..])?;
+            (
+                endorsement.multisig,
+                endorsement.index,
+                Some(endorsement.vault_index),
+            )
+        } else if data.starts_with(ConfigTransaction::DISCRIMINATOR) {
+            let config_transaction = ConfigTransaction::try_deserialize(&mut &data[..])?;
+            (config_transaction.multisig, config_transaction.index, None)
+        } else {
+            return err!(MultisigError::InvalidAccount);
+        };
+
+    require_keys_eq!(
+        transaction_multisig,
+        *multisig,
+        MultisigError::TransactionForAnotherMultisig
+    );
+    require_eq!(
+        index,
+        transaction_index,
+        MultisigError::TransactionNotMatchingProposal
+    );
+
+    Ok(vault_index)
+}
@@ -1,9 +1,14 @@
+export * from './Allowance'
 export * from './Batch'
 export * from './ConfigTransaction'
+export * from './Endorsement'
 export * from './Multisig'
 export * from './ProgramConfig'
 export * from './Proposal'
+export * from './RateLimit'
+export * from './SessionKey'
 export * from './SpendingLimit'
+export * from './Stream'
 export * from './TransactionBuffer'
 export * from './VaultBatchTransaction'
 export * from './VaultTransaction'
@@ -17,6 +22,11 @@ import { Proposal } from './Proposal'
 import { SpendingLimit } from './SpendingLimit'
 import { TransactionBuffer } from './TransactionBuffer'
 import { VaultTransaction } from './VaultTransaction'
+import { Allowance } from './Allowance'
+import { Endorsement } from './Endorsement'
+import { RateLimit } from './RateLimit'
+import { SessionKey } from './SessionKey'
+import { Stream } from './Stream'
 
 export const accountProviders = {
   Batch,
@@ -28,4 +38,9 @@ export const accountProviders = {
   SpendingLimit,
   TransactionBuffer,
   VaultTransaction,
+  Allowance,
+  Endorsement,
+  RateLimit,
+  SessionKey,
+  Stream,
 }
@@ -1038,6 +1038,936 @@ createErrorFromNameLookup.set(
   () => new MultisigCreateDeprecatedError()
 )
 
+/**
+ * ThresholdUnreachable: 'Threshold must be reachable by the members that never expire'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ThresholdUnreachableError extends Error {
+  readonly code: number = 0x179d
+  readonly name: string = 'ThresholdUnreachable'
+  constructor() {
+    super('Threshold must be reachable by the members that never expire')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ThresholdUnreachableError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new ThresholdUnreachableError())
+createErrorFromNameLookup.set(
+  'ThresholdUnreachable',
+  () => new ThresholdUnreachableError()
+)
+
+/**
+ * VaultOutOfScope: 'Member's permission is not scoped to this vault'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VaultOutOfScopeError extends Error {
+  readonly code: number = 0x179e
+  readonly name: string = 'VaultOutOfScope'
+  constructor() {
+    super("Member's permission is not scoped to this vault")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VaultOutOfScopeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new VaultOutOfScopeError())
+createErrorFromNameLookup.set(
+  'VaultOutOfScope',
+  () => new VaultOutOfScopeError()
+)
+
+/**
+ * DuplicateAllowedProgram: 'Found multiple program allowlist entries for the same program and vault'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicateAllowedProgramError extends Error {
+  readonly code: number = 0x179f
+  readonly name: string = 'DuplicateAllowedProgram'
+  constructor() {
+    super(
+      'Found multiple program allowlist entries for the same program and vault'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicateAllowedProgramError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new DuplicateAllowedProgramError())
+createErrorFromNameLookup.set(
+  'DuplicateAllowedProgram',
+  () => new DuplicateAllowedProgramError()
+)
+
+/**
+ * ProgramNotAllowed: 'Program is not in the multisig program allowlist'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ProgramNotAllowedError extends Error {
+  readonly code: number = 0x17a0
+  readonly name: string = 'ProgramNotAllowed'
+  constructor() {
+    super('Program is not in the multisig program allowlist')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ProgramNotAllowedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new ProgramNotAllowedError())
+createErrorFromNameLookup.set(
+  'ProgramNotAllowed',
+  () => new ProgramNotAllowedError()
+)
+
+/**
+ * AuthorityChangeNotAllowed: 'Authority changes are not allowed for this program'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuthorityChangeNotAllowedError extends Error {
+  readonly code: number = 0x17a1
+  readonly name: string = 'AuthorityChangeNotAllowed'
+  constructor() {
+    super('Authority changes are not allowed for this program')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuthorityChangeNotAllowedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a1,
+  () => new AuthorityChangeNotAllowedError()
+)
+createErrorFromNameLookup.set(
+  'AuthorityChangeNotAllowed',
+  () => new AuthorityChangeNotAllowedError()
+)
+
+/**
+ * DuplicateTokenOutflowLimit: 'Found multiple token outflow limits for the same mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicateTokenOutflowLimitError extends Error {
+  readonly code: number = 0x17a2
+  readonly name: string = 'DuplicateTokenOutflowLimit'
+  constructor() {
+    super('Found multiple token outflow limits for the same mint')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicateTokenOutflowLimitError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a2,
+  () => new DuplicateTokenOutflowLimitError()
+)
+createErrorFromNameLookup.set(
+  'DuplicateTokenOutflowLimit',
+  () => new DuplicateTokenOutflowLimitError()
+)
+
+/**
+ * LamportsOutflowExceeded: 'Vault lamports outflow exceeds the balance guard'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class LamportsOutflowExceededError extends Error {
+  readonly code: number = 0x17a3
+  readonly name: string = 'LamportsOutflowExceeded'
+  constructor() {
+    super('Vault lamports outflow exceeds the balance guard')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, LamportsOutflowExceededError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new LamportsOutflowExceededError())
+createErrorFromNameLookup.set(
+  'LamportsOutflowExceeded',
+  () => new LamportsOutflowExceededError()
+)
+
+/**
+ * TokenOutflowExceeded: 'Vault token outflow exceeds the balance guard'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TokenOutflowExceededError extends Error {
+  readonly code: number = 0x17a4
+  readonly name: string = 'TokenOutflowExceeded'
+  constructor() {
+    super('Vault token outflow exceeds the balance guard')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TokenOutflowExceededError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new TokenOutflowExceededError())
+createErrorFromNameLookup.set(
+  'TokenOutflowExceeded',
+  () => new TokenOutflowExceededError()
+)
+
+/**
+ * SignerAccountStateChanged: 'Vault or ephemeral signer PDA is no longer a system-owned account without data'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SignerAccountStateChangedError extends Error {
+  readonly code: number = 0x17a5
+  readonly name: string = 'SignerAccountStateChanged'
+  constructor() {
+    super(
+      'Vault or ephemeral signer PDA is no longer a system-owned account without data'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SignerAccountStateChangedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a5,
+  () => new SignerAccountStateChangedError()
+)
+createErrorFromNameLookup.set(
+  'SignerAccountStateChanged',
+  () => new SignerAccountStateChangedError()
+)
+
+/**
+ * AllowanceInvalidMaxUses: 'Allowance max uses must be greater than 0'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AllowanceInvalidMaxUsesError extends Error {
+  readonly code: number = 0x17a6
+  readonly name: string = 'AllowanceInvalidMaxUses'
+  constructor() {
+    super('Allowance max uses must be greater than 0')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AllowanceInvalidMaxUsesError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new AllowanceInvalidMaxUsesError())
+createErrorFromNameLookup.set(
+  'AllowanceInvalidMaxUses',
+  () => new AllowanceInvalidMaxUsesError()
+)
+
+/**
+ * InvalidInstructionTemplate: 'Invalid instruction template'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidInstructionTemplateError extends Error {
+  readonly code: number = 0x17a7
+  readonly name: string = 'InvalidInstructionTemplate'
+  constructor() {
+    super('Invalid instruction template')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidInstructionTemplateError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a7,
+  () => new InvalidInstructionTemplateError()
+)
+createErrorFromNameLookup.set(
+  'InvalidInstructionTemplate',
+  () => new InvalidInstructionTemplateError()
+)
+
+/**
+ * InstructionNotMatchingTemplate: 'Instruction doesn't match the allowance template'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InstructionNotMatchingTemplateError extends Error {
+  readonly code: number = 0x17a8
+  readonly name: string = 'InstructionNotMatchingTemplate'
+  constructor() {
+    super("Instruction doesn't match the allowance template")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InstructionNotMatchingTemplateError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17a8,
+  () => new InstructionNotMatchingTemplateError()
+)
+createErrorFromNameLookup.set(
+  'InstructionNotMatchingTemplate',
+  () => new InstructionNotMatchingTemplateError()
+)
+
+/**
+ * AllowanceExpired: 'Allowance has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AllowanceExpiredError extends Error {
+  readonly code: number = 0x17a9
+  readonly name: string = 'AllowanceExpired'
+  constructor() {
+    super('Allowance has expired')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AllowanceExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new AllowanceExpiredError())
+createErrorFromNameLookup.set(
+  'AllowanceExpired',
+  () => new AllowanceExpiredError()
+)
+
+/**
+ * AllowanceExhausted: 'Allowance has no remaining uses in the current period'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AllowanceExhaustedError extends Error {
+  readonly code: number = 0x17aa
+  readonly name: string = 'AllowanceExhausted'
+  constructor() {
+    super('Allowance has no remaining uses in the current period')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AllowanceExhaustedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new AllowanceExhaustedError())
+createErrorFromNameLookup.set(
+  'AllowanceExhausted',
+  () => new AllowanceExhaustedError()
+)
+
+/**
+ * PreconditionFailed: 'Vault transaction precondition doesn't hold'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PreconditionFailedError extends Error {
+  readonly code: number = 0x17ab
+  readonly name: string = 'PreconditionFailed'
+  constructor() {
+    super("Vault transaction precondition doesn't hold")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PreconditionFailedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new PreconditionFailedError())
+createErrorFromNameLookup.set(
+  'PreconditionFailed',
+  () => new PreconditionFailedError()
+)
+
+/**
+ * PartialExecutionNotAllowed: 'Transaction can't be executed partially'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PartialExecutionNotAllowedError extends Error {
+  readonly code: number = 0x17ac
+  readonly name: string = 'PartialExecutionNotAllowed'
+  constructor() {
+    super("Transaction can't be executed partially")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PartialExecutionNotAllowedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17ac,
+  () => new PartialExecutionNotAllowedError()
+)
+createErrorFromNameLookup.set(
+  'PartialExecutionNotAllowed',
+  () => new PartialExecutionNotAllowedError()
+)
+
+/**
+ * VaultNotEmpty: 'Vault must be empty to close the multisig'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VaultNotEmptyError extends Error {
+  readonly code: number = 0x17ad
+  readonly name: string = 'VaultNotEmpty'
+  constructor() {
+    super('Vault must be empty to close the multisig')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VaultNotEmptyError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new VaultNotEmptyError())
+createErrorFromNameLookup.set('VaultNotEmpty', () => new VaultNotEmptyError())
+
+/**
+ * MultisigNotEmpty: 'Multisig can't be closed while it has open accounts'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MultisigNotEmptyError extends Error {
+  readonly code: number = 0x17ae
+  readonly name: string = 'MultisigNotEmpty'
+  constructor() {
+    super("Multisig can't be closed while it has open accounts")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MultisigNotEmptyError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new MultisigNotEmptyError())
+createErrorFromNameLookup.set(
+  'MultisigNotEmpty',
+  () => new MultisigNotEmptyError()
+)
+
+/**
+ * CloseMultisigNotLastAction: 'CloseMultisig must be the last action of the config transaction'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CloseMultisigNotLastActionError extends Error {
+  readonly code: number = 0x17af
+  readonly name: string = 'CloseMultisigNotLastAction'
+  constructor() {
+    super('CloseMultisig must be the last action of the config transaction')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CloseMultisigNotLastActionError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17af,
+  () => new CloseMultisigNotLastActionError()
+)
+createErrorFromNameLookup.set(
+  'CloseMultisigNotLastAction',
+  () => new CloseMultisigNotLastActionError()
+)
+
+/**
+ * ProposalNotStale: 'Only stale proposals can be rebased'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ProposalNotStaleError extends Error {
+  readonly code: number = 0x17b0
+  readonly name: string = 'ProposalNotStale'
+  constructor() {
+    super('Only stale proposals can be rebased')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ProposalNotStaleError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b0, () => new ProposalNotStaleError())
+createErrorFromNameLookup.set(
+  'ProposalNotStale',
+  () => new ProposalNotStaleError()
+)
+
+/**
+ * AlreadyAbstained: 'Member already abstained from voting on the transaction'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AlreadyAbstainedError extends Error {
+  readonly code: number = 0x17b1
+  readonly name: string = 'AlreadyAbstained'
+  constructor() {
+    super('Member already abstained from voting on the transaction')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AlreadyAbstainedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new AlreadyAbstainedError())
+createErrorFromNameLookup.set(
+  'AlreadyAbstained',
+  () => new AlreadyAbstainedError()
+)
+
+/**
+ * NoVoteToWithdraw: 'Member has no vote to withdraw'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoVoteToWithdrawError extends Error {
+  readonly code: number = 0x17b2
+  readonly name: string = 'NoVoteToWithdraw'
+  constructor() {
+    super('Member has no vote to withdraw')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoVoteToWithdrawError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b2, () => new NoVoteToWithdrawError())
+createErrorFromNameLookup.set(
+  'NoVoteToWithdraw',
+  () => new NoVoteToWithdrawError()
+)
+
+/**
+ * ExecutorIsCreator: 'The creator of the transaction can't execute it'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExecutorIsCreatorError extends Error {
+  readonly code: number = 0x17b3
+  readonly name: string = 'ExecutorIsCreator'
+  constructor() {
+    super("The creator of the transaction can't execute it")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExecutorIsCreatorError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b3, () => new ExecutorIsCreatorError())
+createErrorFromNameLookup.set(
+  'ExecutorIsCreator',
+  () => new ExecutorIsCreatorError()
+)
+
+/**
+ * ExecutorIsApprover: 'The members who approved the transaction can't execute it'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExecutorIsApproverError extends Error {
+  readonly code: number = 0x17b4
+  readonly name: string = 'ExecutorIsApprover'
+  constructor() {
+    super("The members who approved the transaction can't execute it")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExecutorIsApproverError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b4, () => new ExecutorIsApproverError())
+createErrorFromNameLookup.set(
+  'ExecutorIsApprover',
+  () => new ExecutorIsApproverError()
+)
+
+/**
+ * RateLimitInvalidPeriod: 'Rate limit period must be greater than 0'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RateLimitInvalidPeriodError extends Error {
+  readonly code: number = 0x17b5
+  readonly name: string = 'RateLimitInvalidPeriod'
+  constructor() {
+    super('Rate limit period must be greater than 0')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RateLimitInvalidPeriodError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b5, () => new RateLimitInvalidPeriodError())
+createErrorFromNameLookup.set(
+  'RateLimitInvalidPeriod',
+  () => new RateLimitInvalidPeriodError()
+)
+
+/**
+ * RateLimitExceeded: 'Execution exceeds the rate limit of the multisig'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RateLimitExceededError extends Error {
+  readonly code: number = 0x17b6
+  readonly name: string = 'RateLimitExceeded'
+  constructor() {
+    super('Execution exceeds the rate limit of the multisig')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RateLimitExceededError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b6, () => new RateLimitExceededError())
+createErrorFromNameLookup.set(
+  'RateLimitExceeded',
+  () => new RateLimitExceededError()
+)
+
+/**
+ * StreamInvalidAmount: 'Stream amount must be greater than 0'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class StreamInvalidAmountError extends Error {
+  readonly code: number = 0x17b7
+  readonly name: string = 'StreamInvalidAmount'
+  constructor() {
+    super('Stream amount must be greater than 0')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, StreamInvalidAmountError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b7, () => new StreamInvalidAmountError())
+createErrorFromNameLookup.set(
+  'StreamInvalidAmount',
+  () => new StreamInvalidAmountError()
+)
+
+/**
+ * StreamInvalidSchedule: 'Stream must start before it ends, and its cliff must be within the vesting period'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class StreamInvalidScheduleError extends Error {
+  readonly code: number = 0x17b8
+  readonly name: string = 'StreamInvalidSchedule'
+  constructor() {
+    super(
+      'Stream must start before it ends, and its cliff must be within the vesting period'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, StreamInvalidScheduleError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b8, () => new StreamInvalidScheduleError())
+createErrorFromNameLookup.set(
+  'StreamInvalidSchedule',
+  () => new StreamInvalidScheduleError()
+)
+
+/**
+ * StreamNothingToClaim: 'Stream has nothing vested to claim'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class StreamNothingToClaimError extends Error {
+  readonly code: number = 0x17b9
+  readonly name: string = 'StreamNothingToClaim'
+  constructor() {
+    super('Stream has nothing vested to claim')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, StreamNothingToClaimError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b9, () => new StreamNothingToClaimError())
+createErrorFromNameLookup.set(
+  'StreamNothingToClaim',
+  () => new StreamNothingToClaimError()
+)
+
+/**
+ * SessionKeyExpired: 'Session key is expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SessionKeyExpiredError extends Error {
+  readonly code: number = 0x17ba
+  readonly name: string = 'SessionKeyExpired'
+  constructor() {
+    super('Session key is expired')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SessionKeyExpiredError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ba, () => new SessionKeyExpiredError())
+createErrorFromNameLookup.set(
+  'SessionKeyExpired',
+  () => new SessionKeyExpiredError()
+)
+
+/**
+ * SessionKeyOutOfScope: 'Session key is not allowed to take this action'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SessionKeyOutOfScopeError extends Error {
+  readonly code: number = 0x17bb
+  readonly name: string = 'SessionKeyOutOfScope'
+  constructor() {
+    super('Session key is not allowed to take this action')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SessionKeyOutOfScopeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bb, () => new SessionKeyOutOfScopeError())
+createErrorFromNameLookup.set(
+  'SessionKeyOutOfScope',
+  () => new SessionKeyOutOfScopeError()
+)
+
+/**
+ * PasskeyAddressMismatch: 'Passkey member key must be the address derived from the passkey'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PasskeyAddressMismatchError extends Error {
+  readonly code: number = 0x17bc
+  readonly name: string = 'PasskeyAddressMismatch'
+  constructor() {
+    super('Passkey member key must be the address derived from the passkey')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PasskeyAddressMismatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bc, () => new PasskeyAddressMismatchError())
+createErrorFromNameLookup.set(
+  'PasskeyAddressMismatch',
+  () => new PasskeyAddressMismatchError()
+)
+
+/**
+ * PasskeyMissingSignature: 'Passkey vote must be preceded by a secp256r1 signature verification instruction'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PasskeyMissingSignatureError extends Error {
+  readonly code: number = 0x17bd
+  readonly name: string = 'PasskeyMissingSignature'
+  constructor() {
+    super(
+      'Passkey vote must be preceded by a secp256r1 signature verification instruction'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PasskeyMissingSignatureError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bd, () => new PasskeyMissingSignatureError())
+createErrorFromNameLookup.set(
+  'PasskeyMissingSignature',
+  () => new PasskeyMissingSignatureError()
+)
+
+/**
+ * PasskeyInvalidSignature: 'Passkey signature doesn't match the member or the challenge'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PasskeyInvalidSignatureError extends Error {
+  readonly code: number = 0x17be
+  readonly name: string = 'PasskeyInvalidSignature'
+  constructor() {
+    super("Passkey signature doesn't match the member or the challenge")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PasskeyInvalidSignatureError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17be, () => new PasskeyInvalidSignatureError())
+createErrorFromNameLookup.set(
+  'PasskeyInvalidSignature',
+  () => new PasskeyInvalidSignatureError()
+)
+
+/**
+ * EndorsementInvalidParent: 'Endorsement can't vote in the multisig it belongs to'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EndorsementInvalidParentError extends Error {
+  readonly code: number = 0x17bf
+  readonly name: string = 'EndorsementInvalidParent'
+  constructor() {
+    super("Endorsement can't vote in the multisig it belongs to")
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EndorsementInvalidParentError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bf, () => new EndorsementInvalidParentError())
+createErrorFromNameLookup.set(
+  'EndorsementInvalidParent',
+  () => new EndorsementInvalidParentError()
+)
+
+/**
+ * TransactionMessagePending: 'Transaction message is still being moved in from the transaction buffer'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionMessagePendingError extends Error {
+  readonly code: number = 0x17c0
+  readonly name: string = 'TransactionMessagePending'
+  constructor() {
+    super(
+      'Transaction message is still being moved in from the transaction buffer'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionMessagePendingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17c0,
+  () => new TransactionMessagePendingError()
+)
+createErrorFromNameLookup.set(
+  'TransactionMessagePending',
+  () => new TransactionMessagePendingError()
+)
+
+/**
+ * TransactionMessageNotPending: 'Transaction has no message pending to be moved in from a transaction buffer'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TransactionMessageNotPendingError extends Error {
+  readonly code: number = 0x17c1
+  readonly name: string = 'TransactionMessageNotPending'
+  constructor() {
+    super(
+      'Transaction has no message pending to be moved in from a transaction buffer'
+    )
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TransactionMessageNotPendingError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(
+  0x17c1,
+  () => new TransactionMessageNotPendingError()
+)
+createErrorFromNameLookup.set(
+  'TransactionMessageNotPending',
+  () => new TransactionMessageNotPendingError()
+)
+
+/**
+ * MultisigClosed: 'Multisig is closed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MultisigClosedError extends Error {
+  readonly code: number = 0x17c2
+  readonly name: string = 'MultisigClosed'
+  constructor() {
+    super('Multisig is closed')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MultisigClosedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c2, () => new MultisigClosedError())
+createErrorFromNameLookup.set('MultisigClosed', () => new MultisigClosedError())
+
+/**
+ * PasskeyMemberNotVoteOnly: 'Passkey members can only have the Vote permission'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PasskeyMemberNotVoteOnlyError extends Error {
+  readonly code: number = 0x17c3
+  readonly name: string = 'PasskeyMemberNotVoteOnly'
+  constructor() {
+    super('Passkey members can only have the Vote permission')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PasskeyMemberNotVoteOnlyError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17c3, () => new PasskeyMemberNotVoteOnlyError())
+createErrorFromNameLookup.set(
+  'PasskeyMemberNotVoteOnly',
+  () => new PasskeyMemberNotVoteOnlyError()
+)
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
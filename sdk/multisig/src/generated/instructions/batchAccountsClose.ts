@@ -26,6 +26,7 @@ export const batchAccountsCloseStruct = new beet.BeetArgsStruct<{
  * @property [_writable_] proposal
  * @property [_writable_] batch
  * @property [_writable_] rentCollector
+ * @property [_writable_] proposalRentPayer (optional)
  * @category Instructions
  * @category BatchAccountsClose
  * @category generated
@@ -36,6 +37,7 @@ export type BatchAccountsCloseInstructionAccounts = {
   batch: web3.PublicKey
   rentCollector: web3.PublicKey
   systemProgram?: web3.PublicKey
+  proposalRentPayer?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -46,6 +48,9 @@ export const batchAccountsCloseInstructionDiscriminator = [
 /**
  * Creates a _BatchAccountsClose_ instruction.
  *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
  * @param accounts that will be accessed while the instruction is processed
  * @category Instructions
  * @category BatchAccountsClose
@@ -84,6 +89,11 @@ export function createBatchAccountsCloseInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.proposalRentPayer ?? programId,
+      isWritable: accounts.proposalRentPayer != null,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
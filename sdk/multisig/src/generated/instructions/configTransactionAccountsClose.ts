@@ -26,6 +26,7 @@ export const configTransactionAccountsCloseStruct = new beet.BeetArgsStruct<{
  * @property [_writable_] proposal
  * @property [_writable_] transaction
  * @property [_writable_] rentCollector
+ * @property [_writable_] proposalRentPayer (optional)
  * @category Instructions
  * @category ConfigTransactionAccountsClose
  * @category generated
@@ -36,6 +37,7 @@ export type ConfigTransactionAccountsCloseInstructionAccounts = {
   transaction: web3.PublicKey
   rentCollector: web3.PublicKey
   systemProgram?: web3.PublicKey
+  proposalRentPayer?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -46,6 +48,9 @@ export const configTransactionAccountsCloseInstructionDiscriminator = [
 /**
  * Creates a _ConfigTransactionAccountsClose_ instruction.
  *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
  * @param accounts that will be accessed while the instruction is processed
  * @category Instructions
  * @category ConfigTransactionAccountsClose
@@ -85,6 +90,11 @@ export function createConfigTransactionAccountsCloseInstruction(
       isWritable: false,
       isSigner: false,
     },
+    {
+      pubkey: accounts.proposalRentPayer ?? programId,
+      isWritable: accounts.proposalRentPayer != null,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -1,36 +1,75 @@
+export * from './allowanceUse'
 export * from './batchAccountsClose'
 export * from './batchAddTransaction'
+export * from './batchAddTransactionFromBuffer'
+export * from './batchAddTransactionV2'
 export * from './batchCreate'
 export * from './batchExecuteTransaction'
+export * from './batchExecuteTransactions'
 export * from './configTransactionAccountsClose'
 export * from './configTransactionCreate'
 export * from './configTransactionExecute'
+export * from './endorsementAccountsClose'
+export * from './endorsementCreate'
+export * from './endorsementExecute'
+export * from './multisigAddAllowance'
 export * from './multisigAddMember'
 export * from './multisigAddSpendingLimit'
 export * from './multisigChangeThreshold'
+export * from './multisigClose'
 export * from './multisigCreate'
 export * from './multisigCreateV2'
+export * from './multisigRecordClosedTransactions'
+export * from './multisigRemoveAllowance'
+export * from './multisigRemoveExpiredMembers'
 export * from './multisigRemoveMember'
+export * from './multisigRemoveRateLimit'
 export * from './multisigRemoveSpendingLimit'
 export * from './multisigSetConfigAuthority'
+export * from './multisigSetMemberPasskey'
+export * from './multisigSetMemberValidUntil'
+export * from './multisigSetMemberVaultScope'
+export * from './multisigSetProgramAllowlist'
+export * from './multisigSetRateLimit'
 export * from './multisigSetRentCollector'
+export * from './multisigSetRentRefund'
 export * from './multisigSetTimeLock'
+export * from './multisigSetTwoPersonRule'
 export * from './programConfigInit'
 export * from './programConfigSetAuthority'
 export * from './programConfigSetMultisigCreationFee'
 export * from './programConfigSetTreasury'
+export * from './proposalAbstain'
 export * from './proposalActivate'
 export * from './proposalApprove'
+export * from './proposalApproveMany'
+export * from './proposalApproveV2'
 export * from './proposalCancel'
+export * from './proposalCancelMany'
 export * from './proposalCancelV2'
+export * from './proposalCancelV3'
 export * from './proposalCreate'
+export * from './proposalRebase'
 export * from './proposalReject'
+export * from './proposalRejectMany'
+export * from './proposalRejectV2'
+export * from './proposalVotePasskey'
+export * from './proposalWithdrawVote'
+export * from './sessionKeyCreate'
+export * from './sessionKeyRevoke'
 export * from './spendingLimitUse'
+export * from './streamClaim'
+export * from './transactionBufferAddCoWriters'
 export * from './transactionBufferClose'
 export * from './transactionBufferCreate'
 export * from './transactionBufferExtend'
+export * from './transactionsAccountsCloseMany'
 export * from './vaultBatchTransactionAccountClose'
 export * from './vaultTransactionAccountsClose'
 export * from './vaultTransactionCreate'
 export * from './vaultTransactionCreateFromBuffer'
+export * from './vaultTransactionCreateFromBufferV2'
+export * from './vaultTransactionCreateV2'
 export * from './vaultTransactionExecute'
+export * from './vaultTransactionExecutePartial'
+export * from './vaultTransactionExtendFromBuffer'
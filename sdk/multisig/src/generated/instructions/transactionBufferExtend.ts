@@ -42,6 +42,7 @@ export const transactionBufferExtendStruct = new beet.FixableBeetArgsStruct<
  * @property [] multisig
  * @property [_writable_] transactionBuffer
  * @property [**signer**] creator
+ * @property [_writable_, **signer**] rentPayer (optional)
  * @category Instructions
  * @category TransactionBufferExtend
  * @category generated
@@ -50,6 +51,8 @@ export type TransactionBufferExtendInstructionAccounts = {
   multisig: web3.PublicKey
   transactionBuffer: web3.PublicKey
   creator: web3.PublicKey
+  rentPayer?: web3.PublicKey
+  systemProgram?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -60,6 +63,9 @@ export const transactionBufferExtendInstructionDiscriminator = [
 /**
  * Creates a _TransactionBufferExtend_ instruction.
  *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
@@ -92,6 +98,16 @@ export function createTransactionBufferExtendInstruction(
       isWritable: false,
       isSigner: true,
     },
+    {
+      pubkey: accounts.rentPayer ?? programId,
+      isWritable: accounts.rentPayer != null,
+      isSigner: accounts.rentPayer != null,
+    },
+    {
+      pubkey: accounts.systemProgram ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -47,6 +47,7 @@ export const vaultTransactionCreateFromBufferStruct =
  * @property [] vaultTransactionCreateItemSystemProgram
  * @property [_writable_] transactionBuffer
  * @property [_writable_, **signer**] creator
+ * @property [_writable_] bufferCreator (optional)
  * @category Instructions
  * @category VaultTransactionCreateFromBuffer
  * @category generated
@@ -59,6 +60,7 @@ export type VaultTransactionCreateFromBufferInstructionAccounts = {
   vaultTransactionCreateItemSystemProgram: web3.PublicKey
   transactionBuffer: web3.PublicKey
   creator: web3.PublicKey
+  bufferCreator?: web3.PublicKey
   anchorRemainingAccounts?: web3.AccountMeta[]
 }
 
@@ -69,6 +71,9 @@ export const vaultTransactionCreateFromBufferInstructionDiscriminator = [
 /**
  * Creates a _VaultTransactionCreateFromBuffer_ instruction.
  *
+ * Optional accounts that are not provided default to the program ID since
+ * this was indicated in the IDL from which this instruction was generated.
+ *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
@@ -122,6 +127,11 @@ export function createVaultTransactionCreateFromBufferInstruction(
       isWritable: true,
       isSigner: true,
     },
+    {
+      pubkey: accounts.bufferCreator ?? programId,
+      isWritable: accounts.bufferCreator != null,
+      isSigner: false,
+    },
   ]
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -10,6 +10,13 @@ import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
 import { Member, memberBeet } from './Member'
 import { Period, periodBeet } from './Period'
+import { VaultScope, vaultScopeBeet } from './VaultScope'
+import { AllowedProgram, allowedProgramBeet } from './AllowedProgram'
+import { TwoPersonRule, twoPersonRuleBeet } from './TwoPersonRule'
+import {
+  InstructionTemplate,
+  instructionTemplateBeet,
+} from './InstructionTemplate'
 /**
  * This type is used to derive the {@link ConfigAction} type as well as the de/serializer.
  * However don't refer to it in your code but use the {@link ConfigAction} type instead.
@@ -35,6 +42,51 @@ export type ConfigActionRecord = {
   }
   RemoveSpendingLimit: { spendingLimit: web3.PublicKey }
   SetRentCollector: { newRentCollector: beet.COption<web3.PublicKey> }
+  SetMemberVaultScope: {
+    member: web3.PublicKey
+    vaultScope: beet.COption<VaultScope>
+  }
+  SetProgramAllowlist: { newProgramAllowlist: beet.COption<AllowedProgram[]> }
+  SetTwoPersonRule: { newTwoPersonRule: TwoPersonRule }
+  AddAllowance: {
+    createKey: web3.PublicKey
+    vaultIndex: number
+    template: InstructionTemplate
+    maxUses: number
+    period: Period
+    expiresAt: beet.COption<beet.bignum>
+    members: web3.PublicKey[]
+  }
+  RemoveAllowance: { allowance: web3.PublicKey }
+  AddStream: {
+    createKey: web3.PublicKey
+    vaultIndex: number
+    recipient: web3.PublicKey
+    mint: web3.PublicKey
+    totalAmount: beet.bignum
+    startTime: beet.bignum
+    endTime: beet.bignum
+    cliffTime: beet.COption<beet.bignum>
+  }
+  CancelStream: { stream: web3.PublicKey }
+  SetRateLimit: {
+    period: beet.bignum
+    maxExecutions: beet.COption<number>
+    maxLamports: beet.COption<beet.bignum>
+    overLimitThreshold: beet.COption<number>
+    overLimitTimeLock: beet.COption<number>
+  }
+  RemoveRateLimit: void /* scalar variant */
+  CloseMultisig: { rentDestination: web3.PublicKey; vaultIndices: Uint8Array }
+  SetMemberValidUntil: {
+    member: web3.PublicKey
+    validUntil: beet.COption<beet.bignum>
+  }
+  SetMemberPasskey: {
+    member: web3.PublicKey
+    passkey: beet.COption<number[] /* size: 33 */>
+  }
+  SetRentRefund: { newRefundRentToPayers: boolean }
 }
 
 /**
@@ -75,6 +127,54 @@ export const isConfigActionSetRentCollector = (
   x: ConfigAction
 ): x is ConfigAction & { __kind: 'SetRentCollector' } =>
   x.__kind === 'SetRentCollector'
+export const isConfigActionSetMemberVaultScope = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'SetMemberVaultScope' } =>
+  x.__kind === 'SetMemberVaultScope'
+export const isConfigActionSetProgramAllowlist = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'SetProgramAllowlist' } =>
+  x.__kind === 'SetProgramAllowlist'
+export const isConfigActionSetTwoPersonRule = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'SetTwoPersonRule' } =>
+  x.__kind === 'SetTwoPersonRule'
+export const isConfigActionAddAllowance = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'AddAllowance' } => x.__kind === 'AddAllowance'
+export const isConfigActionRemoveAllowance = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'RemoveAllowance' } =>
+  x.__kind === 'RemoveAllowance'
+export const isConfigActionAddStream = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'AddStream' } => x.__kind === 'AddStream'
+export const isConfigActionCancelStream = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'CancelStream' } => x.__kind === 'CancelStream'
+export const isConfigActionSetRateLimit = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'SetRateLimit' } => x.__kind === 'SetRateLimit'
+export const isConfigActionRemoveRateLimit = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'RemoveRateLimit' } =>
+  x.__kind === 'RemoveRateLimit'
+export const isConfigActionCloseMultisig = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'CloseMultisig' } =>
+  x.__kind === 'CloseMultisig'
+export const isConfigActionSetMemberValidUntil = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'SetMemberValidUntil' } =>
+  x.__kind === 'SetMemberValidUntil'
+export const isConfigActionSetMemberPasskey = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'SetMemberPasskey' } =>
+  x.__kind === 'SetMemberPasskey'
+export const isConfigActionSetRentRefund = (
+  x: ConfigAction
+): x is ConfigAction & { __kind: 'SetRentRefund' } =>
+  x.__kind === 'SetRentRefund'
 
 /**
  * @category userTypes
@@ -144,4 +244,136 @@ export const configActionBeet = beet.dataEnum<ConfigActionRecord>([
       'ConfigActionRecord["SetRentCollector"]'
     ),
   ],
+
+  [
+    'SetMemberVaultScope',
+    new beet.FixableBeetArgsStruct<ConfigActionRecord['SetMemberVaultScope']>(
+      [
+        ['member', beetSolana.publicKey],
+        ['vaultScope', beet.coption(vaultScopeBeet)],
+      ],
+      'ConfigActionRecord["SetMemberVaultScope"]'
+    ),
+  ],
+
+  [
+    'SetProgramAllowlist',
+    new beet.FixableBeetArgsStruct<ConfigActionRecord['SetProgramAllowlist']>(
+      [['newProgramAllowlist', beet.coption(beet.array(allowedProgramBeet))]],
+      'ConfigActionRecord["SetProgramAllowlist"]'
+    ),
+  ],
+
+  [
+    'SetTwoPersonRule',
+    new beet.BeetArgsStruct<ConfigActionRecord['SetTwoPersonRule']>(
+      [['newTwoPersonRule', twoPersonRuleBeet]],
+      'ConfigActionRecord["SetTwoPersonRule"]'
+    ),
+  ],
+
+  [
+    'AddAllowance',
+    new beet.FixableBeetArgsStruct<ConfigActionRecord['AddAllowance']>(
+      [
+        ['createKey', beetSolana.publicKey],
+        ['vaultIndex', beet.u8],
+        ['template', instructionTemplateBeet],
+        ['maxUses', beet.u32],
+        ['period', periodBeet],
+        ['expiresAt', beet.coption(beet.i64)],
+        ['members', beet.array(beetSolana.publicKey)],
+      ],
+      'ConfigActionRecord["AddAllowance"]'
+    ),
+  ],
+
+  [
+    'RemoveAllowance',
+    new beet.BeetArgsStruct<ConfigActionRecord['RemoveAllowance']>(
+      [['allowance', beetSolana.publicKey]],
+      'ConfigActionRecord["RemoveAllowance"]'
+    ),
+  ],
+
+  [
+    'AddStream',
+    new beet.FixableBeetArgsStruct<ConfigActionRecord['AddStream']>(
+      [
+        ['createKey', beetSolana.publicKey],
+        ['vaultIndex', beet.u8],
+        ['recipient', beetSolana.publicKey],
+        ['mint', beetSolana.publicKey],
+        ['totalAmount', beet.u64],
+        ['startTime', beet.i64],
+        ['endTime', beet.i64],
+        ['cliffTime', beet.coption(beet.i64)],
+      ],
+      'ConfigActionRecord["AddStream"]'
+    ),
+  ],
+
+  [
+    'CancelStream',
+    new beet.BeetArgsStruct<ConfigActionRecord['CancelStream']>(
+      [['stream', beetSolana.publicKey]],
+      'ConfigActionRecord["CancelStream"]'
+    ),
+  ],
+
+  [
+    'SetRateLimit',
+    new beet.FixableBeetArgsStruct<ConfigActionRecord['SetRateLimit']>(
+      [
+        ['period', beet.i64],
+        ['maxExecutions', beet.coption(beet.u32)],
+        ['maxLamports', beet.coption(beet.u64)],
+        ['overLimitThreshold', beet.coption(beet.u16)],
+        ['overLimitTimeLock', beet.coption(beet.u32)],
+      ],
+      'ConfigActionRecord["SetRateLimit"]'
+    ),
+  ],
+  ['RemoveRateLimit', beet.unit],
+
+  [
+    'CloseMultisig',
+    new beet.FixableBeetArgsStruct<ConfigActionRecord['CloseMultisig']>(
+      [
+        ['rentDestination', beetSolana.publicKey],
+        ['vaultIndices', beet.bytes],
+      ],
+      'ConfigActionRecord["CloseMultisig"]'
+    ),
+  ],
+
+  [
+    'SetMemberValidUntil',
+    new beet.FixableBeetArgsStruct<ConfigActionRecord['SetMemberValidUntil']>(
+      [
+        ['member', beetSolana.publicKey],
+        ['validUntil', beet.coption(beet.i64)],
+      ],
+      'ConfigActionRecord["SetMemberValidUntil"]'
+    ),
+  ],
+
+  [
+    'SetMemberPasskey',
+    new beet.FixableBeetArgsStruct<ConfigActionRecord['SetMemberPasskey']>(
+      [
+        ['member', beetSolana.publicKey],
+        ['passkey', beet.coption(beet.uniformFixedSizeArray(beet.u8, 33))],
+      ],
+      'ConfigActionRecord["SetMemberPasskey"]'
+    ),
+  ],
+
+  [
+    'SetRentRefund',
+    new beet.BeetArgsStruct<ConfigActionRecord['SetRentRefund']>(
+      [['newRefundRentToPayers', beet.bool]],
+      'ConfigActionRecord["SetRentRefund"]'
+    ),
+  ],
 ]) as beet.FixableBeet<ConfigAction, ConfigAction>
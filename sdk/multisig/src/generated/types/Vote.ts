@@ -14,6 +14,8 @@ export enum Vote {
   Approve,
   Reject,
   Cancel,
+  Abstain,
+  Withdraw,
 }
 
 /**
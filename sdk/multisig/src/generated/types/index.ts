@@ -1,31 +1,72 @@
+export * from './AllowanceUseArgs'
+export * from './AllowedProgram'
+export * from './BalanceGuards'
 export * from './BatchAddTransactionArgs'
+export * from './BatchAddTransactionArgsV2'
 export * from './BatchCreateArgs'
+export * from './BatchExtension'
 export * from './ConfigAction'
 export * from './ConfigTransactionCreateArgs'
+export * from './ConfigTransactionExtension'
+export * from './EndorsementCreateArgs'
+export * from './FixedAccount'
+export * from './InstructionTemplate'
 export * from './Member'
+export * from './MemberSettings'
+export * from './MultisigAddAllowanceArgs'
 export * from './MultisigAddMemberArgs'
 export * from './MultisigAddSpendingLimitArgs'
 export * from './MultisigChangeThresholdArgs'
+export * from './MultisigCloseArgs'
 export * from './MultisigCompiledInstruction'
 export * from './MultisigCreateArgsV2'
+export * from './MultisigExtension'
 export * from './MultisigMessageAddressTableLookup'
+export * from './MultisigRemoveAllowanceArgs'
 export * from './MultisigRemoveMemberArgs'
+export * from './MultisigRemoveRateLimitArgs'
 export * from './MultisigRemoveSpendingLimitArgs'
 export * from './MultisigSetConfigAuthorityArgs'
+export * from './MultisigSetMemberPasskeyArgs'
+export * from './MultisigSetMemberValidUntilArgs'
+export * from './MultisigSetMemberVaultScopeArgs'
+export * from './MultisigSetProgramAllowlistArgs'
+export * from './MultisigSetRateLimitArgs'
 export * from './MultisigSetRentCollectorArgs'
+export * from './MultisigSetRentRefundArgs'
 export * from './MultisigSetTimeLockArgs'
+export * from './MultisigSetTwoPersonRuleArgs'
+export * from './Passkey'
 export * from './Period'
 export * from './Permissions'
+export * from './Precondition'
+export * from './PreconditionCheck'
 export * from './ProgramConfigInitArgs'
 export * from './ProgramConfigSetAuthorityArgs'
 export * from './ProgramConfigSetMultisigCreationFeeArgs'
 export * from './ProgramConfigSetTreasuryArgs'
 export * from './ProposalCreateArgs'
+export * from './ProposalExtension'
+export * from './ProposalRebaseArgs'
 export * from './ProposalStatus'
 export * from './ProposalVoteArgs'
+export * from './ProposalVotePasskeyArgs'
+export * from './SessionActions'
+export * from './SessionKeyCreateArgs'
+export * from './SessionKeyRevokeArgs'
 export * from './SpendingLimitUseArgs'
+export * from './StreamClaimArgs'
+export * from './TokenOutflowLimit'
+export * from './TransactionBufferAddCoWritersArgs'
 export * from './TransactionBufferCreateArgs'
 export * from './TransactionBufferExtendArgs'
+export * from './TransactionBufferExtension'
+export * from './TwoPersonRule'
+export * from './VaultBatchTransactionExtension'
+export * from './VaultScope'
 export * from './VaultTransactionCreateArgs'
+export * from './VaultTransactionCreateArgsV2'
+export * from './VaultTransactionExecutePartialArgs'
+export * from './VaultTransactionExtension'
 export * from './VaultTransactionMessage'
 export * from './Vote'
@@ -13,6 +13,7 @@ import {
 import {
   getBatchTransactionPda,
   getProposalPda,
+  getRateLimitPda,
   getTransactionPda,
   getVaultPda,
 } from "../pda";
@@ -66,6 +67,11 @@ export async function batchExecuteTransaction({
       batchTransactionPda
     );
 
+  // The rate limit account must follow the message accounts if the multisig has one.
+  // Its lamports limit only counts the native lamports moved out of the vault, not tokens.
+  const [rateLimitPda] = getRateLimitPda({ multisigPda, programId });
+  const rateLimitAccount = await connection.getAccountInfo(rateLimitPda);
+
   const { accountMetas, lookupTableAccounts } =
     await accountsForTransactionExecute({
       connection,
@@ -83,7 +89,12 @@ export async function batchExecuteTransaction({
         proposal: proposalPda,
         batch: batchPda,
         transaction: batchTransactionPda,
-        anchorRemainingAccounts: accountMetas,
+        anchorRemainingAccounts: rateLimitAccount
+          ? [
+              ...accountMetas,
+              { pubkey: rateLimitPda, isWritable: true, isSigner: false },
+            ]
+          : accountMetas,
       },
       programId
     ),
@@ -21,6 +21,7 @@ export function spendingLimitUse({
   decimals,
   destination,
   tokenProgram = TOKEN_PROGRAM_ID,
+  rateLimit,
   memo,
   programId = PROGRAM_ID,
 }: {
@@ -34,6 +35,11 @@ export function spendingLimitUse({
   decimals: number;
   destination: PublicKey;
   tokenProgram?: PublicKey;
+  /**
+   * Provide if the multisig has a rate limit, see `getRateLimitPda`.
+   * Only the lamports transferred count against its `maxLamports`, token transfers don't.
+   */
+  rateLimit?: PublicKey;
   memo?: string;
   programId?: PublicKey;
 }): TransactionInstruction {
@@ -71,6 +77,9 @@ export function spendingLimitUse({
       vaultTokenAccount,
       destinationTokenAccount,
       tokenProgram: mint ? tokenProgram : undefined,
+      anchorRemainingAccounts: rateLimit
+        ? [{ pubkey: rateLimit, isWritable: true, isSigner: false }]
+        : undefined,
     },
     { args: { amount, decimals, memo: memo ?? null } },
     programId
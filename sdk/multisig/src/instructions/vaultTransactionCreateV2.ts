@@ -0,0 +1,98 @@
+import {
+  BalanceGuards,
+  createVaultTransactionCreateV2Instruction,
+  Precondition,
+  PROGRAM_ID,
+} from "../generated";
+import {
+  AddressLookupTableAccount,
+  PublicKey,
+  TransactionMessage,
+} from "@solana/web3.js";
+import { getTransactionPda, getVaultPda } from "../pda";
+import { transactionMessageToMultisigTransactionMessageBytes } from "../utils";
+
+export function vaultTransactionCreateV2({
+  multisigPda,
+  transactionIndex,
+  creator,
+  rentPayer,
+  vaultIndex,
+  ephemeralSigners,
+  transactionMessage,
+  addressLookupTableAccounts,
+  balanceGuards,
+  allowVaultAccountChanges,
+  denyEphemeralSignerAccountChanges,
+  preconditions,
+  sessionKey,
+  memo,
+  programId = PROGRAM_ID,
+}: {
+  multisigPda: PublicKey;
+  transactionIndex: bigint;
+  creator: PublicKey;
+  rentPayer?: PublicKey;
+  vaultIndex: number;
+  /** Number of additional signing PDAs required by the transaction. */
+  ephemeralSigners: number;
+  /** Transaction message to wrap into a multisig transaction. */
+  transactionMessage: TransactionMessage;
+  /** `AddressLookupTableAccount`s referenced in `transaction_message`. */
+  addressLookupTableAccounts?: AddressLookupTableAccount[];
+  /** Limits on the vault balance outflows, checked after the transaction is executed. */
+  balanceGuards?: BalanceGuards;
+  /** Whether the vault may be assigned to another program or have data allocated on it during execution. */
+  allowVaultAccountChanges?: boolean;
+  /** Whether the ephemeral signers must stay owned by the System Program with no data after execution. */
+  denyEphemeralSignerAccountChanges?: boolean;
+  /** Checks on the state of accounts that must hold right before the transaction is executed. */
+  preconditions?: Precondition[];
+  /** Session key of a multisig member, if `creator` is a session key acting on their behalf. */
+  sessionKey?: PublicKey;
+  memo?: string;
+  programId?: PublicKey;
+}) {
+  const [vaultPda] = getVaultPda({
+    multisigPda,
+    index: vaultIndex,
+    programId,
+  });
+
+  const [transactionPda] = getTransactionPda({
+    multisigPda,
+    index: transactionIndex,
+    programId,
+  });
+
+  const transactionMessageBytes =
+    transactionMessageToMultisigTransactionMessageBytes({
+      message: transactionMessage,
+      addressLookupTableAccounts,
+      vaultPda,
+    });
+
+  return createVaultTransactionCreateV2Instruction(
+    {
+      multisig: multisigPda,
+      transaction: transactionPda,
+      creator,
+      rentPayer: rentPayer ?? creator,
+      sessionKey,
+    },
+    {
+      args: {
+        vaultIndex,
+        ephemeralSigners,
+        transactionMessage: transactionMessageBytes,
+        balanceGuards: balanceGuards ?? null,
+        allowVaultAccountChanges: allowVaultAccountChanges ?? false,
+        denyEphemeralSignerAccountChanges:
+          denyEphemeralSignerAccountChanges ?? false,
+        preconditions: preconditions ?? [],
+        memo: memo ?? null,
+      },
+    },
+    programId
+  );
+}
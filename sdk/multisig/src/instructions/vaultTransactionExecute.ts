@@ -4,7 +4,12 @@ import {
   PublicKey,
   TransactionInstruction,
 } from "@solana/web3.js";
-import { getProposalPda, getTransactionPda, getVaultPda } from "../pda";
+import {
+  getProposalPda,
+  getRateLimitPda,
+  getTransactionPda,
+  getVaultPda,
+} from "../pda";
 import {
   createVaultTransactionExecuteInstruction,
   PROGRAM_ID,
@@ -49,6 +54,11 @@ export async function vaultTransactionExecute({
     programId,
   });
 
+  // The rate limit account must follow the message accounts if the multisig has one.
+  // Its lamports limit only counts the native lamports moved out of the vault, not tokens.
+  const [rateLimitPda] = getRateLimitPda({ multisigPda, programId });
+  const rateLimitAccount = await connection.getAccountInfo(rateLimitPda);
+
   const { accountMetas, lookupTableAccounts } =
     await accountsForTransactionExecute({
       connection,
@@ -66,7 +76,12 @@ export async function vaultTransactionExecute({
         member,
         proposal: proposalPda,
         transaction: transactionPda,
-        anchorRemainingAccounts: accountMetas,
+        anchorRemainingAccounts: rateLimitAccount
+          ? [
+              ...accountMetas,
+              { pubkey: rateLimitPda, isWritable: true, isSigner: false },
+            ]
+          : accountMetas,
       },
       programId
     ),
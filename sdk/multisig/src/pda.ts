@@ -12,6 +12,10 @@ const SEED_PROPOSAL = toUtfBytes("proposal");
 const SEED_BATCH_TRANSACTION = toUtfBytes("batch_transaction");
 const SEED_EPHEMERAL_SIGNER = toUtfBytes("ephemeral_signer");
 const SEED_SPENDING_LIMIT = toUtfBytes("spending_limit");
+const SEED_ALLOWANCE = toUtfBytes("allowance");
+const SEED_RATE_LIMIT = toUtfBytes("rate_limit");
+const SEED_STREAM = toUtfBytes("stream");
+const SEED_SESSION_KEY = toUtfBytes("session_key");
 
 export function getProgramConfigPda({
   programId = PROGRAM_ID,
@@ -156,3 +160,69 @@ export function getSpendingLimitPda({
     programId
   );
 }
+
+export function getAllowancePda({
+  multisigPda,
+  createKey,
+  programId = PROGRAM_ID,
+}: {
+  multisigPda: PublicKey;
+  createKey: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, multisigPda.toBytes(), SEED_ALLOWANCE, createKey.toBytes()],
+    programId
+  );
+}
+
+export function getRateLimitPda({
+  multisigPda,
+  programId = PROGRAM_ID,
+}: {
+  multisigPda: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, multisigPda.toBytes(), SEED_RATE_LIMIT],
+    programId
+  );
+}
+
+export function getStreamPda({
+  multisigPda,
+  createKey,
+  programId = PROGRAM_ID,
+}: {
+  multisigPda: PublicKey;
+  createKey: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [SEED_PREFIX, multisigPda.toBytes(), SEED_STREAM, createKey.toBytes()],
+    programId
+  );
+}
+
+export function getSessionKeyPda({
+  multisigPda,
+  member,
+  key,
+  programId = PROGRAM_ID,
+}: {
+  multisigPda: PublicKey;
+  member: PublicKey;
+  key: PublicKey;
+  programId?: PublicKey;
+}): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      SEED_PREFIX,
+      multisigPda.toBytes(),
+      SEED_SESSION_KEY,
+      member.toBytes(),
+      key.toBytes(),
+    ],
+    programId
+  );
+}
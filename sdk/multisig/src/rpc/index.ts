@@ -24,4 +24,5 @@ export * from "./spendingLimitUse.js";
 export * from "./vaultBatchTransactionAccountClose.js";
 export * from "./vaultTransactionAccountsClose.js";
 export * from "./vaultTransactionCreate.js";
+export * from "./vaultTransactionCreateV2.js";
 export * from "./vaultTransactionExecute.js";
@@ -20,6 +20,7 @@ export async function spendingLimitUse({
   decimals,
   destination,
   tokenProgram,
+  rateLimit,
   memo,
   sendOptions,
   programId,
@@ -36,6 +37,11 @@ export async function spendingLimitUse({
   decimals: number;
   destination: PublicKey;
   tokenProgram?: PublicKey;
+  /**
+   * Provide if the multisig has a rate limit, see `getRateLimitPda`.
+   * Only the lamports transferred count against its `maxLamports`, token transfers don't.
+   */
+  rateLimit?: PublicKey;
   memo?: string;
   sendOptions?: SendOptions;
   programId?: PublicKey;
@@ -54,6 +60,7 @@ export async function spendingLimitUse({
     decimals,
     destination,
     tokenProgram,
+    rateLimit,
     memo,
     programId,
   });
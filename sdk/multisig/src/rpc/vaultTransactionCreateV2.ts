@@ -0,0 +1,95 @@
+import {
+  AddressLookupTableAccount,
+  Connection,
+  PublicKey,
+  SendOptions,
+  Signer,
+  TransactionMessage,
+  TransactionSignature,
+} from "@solana/web3.js";
+import * as transactions from "../transactions";
+import { translateAndThrowAnchorError } from "../errors";
+import { BalanceGuards, Precondition } from "../generated";
+
+/** Create a new vault transaction with balance guards, preconditions and the account change flags. */
+export async function vaultTransactionCreateV2({
+  connection,
+  feePayer,
+  multisigPda,
+  transactionIndex,
+  creator,
+  rentPayer,
+  vaultIndex,
+  ephemeralSigners,
+  transactionMessage,
+  addressLookupTableAccounts,
+  balanceGuards,
+  allowVaultAccountChanges,
+  denyEphemeralSignerAccountChanges,
+  preconditions,
+  sessionKey,
+  memo,
+  signers,
+  sendOptions,
+  programId,
+}: {
+  connection: Connection;
+  feePayer: Signer;
+  multisigPda: PublicKey;
+  transactionIndex: bigint;
+  /** Member of the multisig that is creating the transaction. */
+  creator: PublicKey;
+  /** Payer for the transaction account rent. If not provided, `creator` is used. */
+  rentPayer?: PublicKey;
+  vaultIndex: number;
+  /** Number of ephemeral signing PDAs required by the transaction. */
+  ephemeralSigners: number;
+  /** Transaction message to wrap into a multisig transaction. */
+  transactionMessage: TransactionMessage;
+  /** `AddressLookupTableAccount`s referenced in `transaction_message`. */
+  addressLookupTableAccounts?: AddressLookupTableAccount[];
+  /** Limits on the vault balance outflows, checked after the transaction is executed. */
+  balanceGuards?: BalanceGuards;
+  /** Whether the vault may be assigned to another program or have data allocated on it during execution. */
+  allowVaultAccountChanges?: boolean;
+  /** Whether the ephemeral signers must stay owned by the System Program with no data after execution. */
+  denyEphemeralSignerAccountChanges?: boolean;
+  /** Checks on the state of accounts that must hold right before the transaction is executed. */
+  preconditions?: Precondition[];
+  /** Session key of a multisig member, if `creator` is a session key acting on their behalf. */
+  sessionKey?: PublicKey;
+  memo?: string;
+  signers?: Signer[];
+  sendOptions?: SendOptions;
+  programId?: PublicKey;
+}): Promise<TransactionSignature> {
+  const blockhash = (await connection.getLatestBlockhash()).blockhash;
+
+  const tx = transactions.vaultTransactionCreateV2({
+    blockhash,
+    feePayer: feePayer.publicKey,
+    multisigPda,
+    transactionIndex,
+    creator,
+    rentPayer,
+    vaultIndex,
+    ephemeralSigners,
+    transactionMessage,
+    addressLookupTableAccounts,
+    balanceGuards,
+    allowVaultAccountChanges,
+    denyEphemeralSignerAccountChanges,
+    preconditions,
+    sessionKey,
+    memo,
+    programId,
+  });
+
+  tx.sign([feePayer, ...(signers ?? [])]);
+
+  try {
+    return await connection.sendTransaction(tx, sendOptions);
+  } catch (err) {
+    translateAndThrowAnchorError(err);
+  }
+}
@@ -21,6 +21,7 @@ export function spendingLimitUse({
   decimals,
   destination,
   tokenProgram,
+  rateLimit,
   memo,
   programId,
 }: {
@@ -36,6 +37,11 @@ export function spendingLimitUse({
   decimals: number;
   destination: PublicKey;
   tokenProgram?: PublicKey;
+  /**
+   * Provide if the multisig has a rate limit, see `getRateLimitPda`.
+   * Only the lamports transferred count against its `maxLamports`, token transfers don't.
+   */
+  rateLimit?: PublicKey;
   memo?: string;
   programId?: PublicKey;
 }): VersionedTransaction {
@@ -53,6 +59,7 @@ export function spendingLimitUse({
         decimals,
         destination,
         tokenProgram,
+        rateLimit,
         memo,
         programId,
       }),
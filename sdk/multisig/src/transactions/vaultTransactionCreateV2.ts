@@ -0,0 +1,86 @@
+import {
+  AddressLookupTableAccount,
+  PublicKey,
+  TransactionMessage,
+  VersionedTransaction,
+} from "@solana/web3.js";
+import * as instructions from "../instructions/index";
+import { BalanceGuards, Precondition } from "../generated";
+
+/**
+ * Returns unsigned `VersionedTransaction` that needs to be
+ * signed by `creator`, `rentPayer` and `feePayer` before sending it.
+ */
+export function vaultTransactionCreateV2({
+  blockhash,
+  feePayer,
+  multisigPda,
+  transactionIndex,
+  creator,
+  rentPayer,
+  vaultIndex,
+  ephemeralSigners,
+  transactionMessage,
+  addressLookupTableAccounts,
+  balanceGuards,
+  allowVaultAccountChanges,
+  denyEphemeralSignerAccountChanges,
+  preconditions,
+  sessionKey,
+  memo,
+  programId,
+}: {
+  blockhash: string;
+  feePayer: PublicKey;
+  multisigPda: PublicKey;
+  transactionIndex: bigint;
+  /** Member of the multisig that is creating the transaction. */
+  creator: PublicKey;
+  /** Payer for the transaction account rent. If not provided, `creator` is used. */
+  rentPayer?: PublicKey;
+  vaultIndex: number;
+  /** Number of additional signing PDAs required by the transaction. */
+  ephemeralSigners: number;
+  /** Transaction message to wrap into a multisig transaction. */
+  transactionMessage: TransactionMessage;
+  /** `AddressLookupTableAccount`s referenced in `transaction_message`. */
+  addressLookupTableAccounts?: AddressLookupTableAccount[];
+  /** Limits on the vault balance outflows, checked after the transaction is executed. */
+  balanceGuards?: BalanceGuards;
+  /** Whether the vault may be assigned to another program or have data allocated on it during execution. */
+  allowVaultAccountChanges?: boolean;
+  /** Whether the ephemeral signers must stay owned by the System Program with no data after execution. */
+  denyEphemeralSignerAccountChanges?: boolean;
+  /** Checks on the state of accounts that must hold right before the transaction is executed. */
+  preconditions?: Precondition[];
+  /** Session key of a multisig member, if `creator` is a session key acting on their behalf. */
+  sessionKey?: PublicKey;
+  memo?: string;
+  programId?: PublicKey;
+}): VersionedTransaction {
+  const message = new TransactionMessage({
+    payerKey: feePayer,
+    recentBlockhash: blockhash,
+    instructions: [
+      instructions.vaultTransactionCreateV2({
+        multisigPda,
+        transactionIndex,
+        creator,
+        rentPayer,
+        vaultIndex,
+        ephemeralSigners,
+        transactionMessage,
+        addressLookupTableAccounts,
+        balanceGuards,
+        allowVaultAccountChanges,
+        denyEphemeralSignerAccountChanges,
+        preconditions,
+        sessionKey,
+        memo,
+        programId,
+      }),
+    ],
+  }).compileToV0Message();
+
+  return new VersionedTransaction(message);
+}
@@ -4,33 +4,61 @@ pub use squads_multisig_program::accounts::BatchAccountsClose as BatchAccountsCl
 pub use squads_multisig_program::accounts::ConfigTransactionAccountsClose as ConfigTransactionAccountsCloseAccounts;
 pub use squads_multisig_program::accounts::ConfigTransactionCreate as ConfigTransactionCreateAccounts;
 pub use squads_multisig_program::accounts::ConfigTransactionExecute as ConfigTransactionExecuteAccounts;
+pub use squads_multisig_program::accounts::EndorsementAccountsClose as EndorsementAccountsCloseAccounts;
+pub use squads_multisig_program::accounts::EndorsementCreate as EndorsementCreateAccounts;
+pub use squads_multisig_program::accounts::EndorsementExecute as EndorsementExecuteAccounts;
 pub use squads_multisig_program::accounts::MultisigCreateV2 as MultisigCreateAccountsV2;
 pub use squads_multisig_program::accounts::ProposalCreate as ProposalCreateAccounts;
 pub use squads_multisig_program::accounts::ProposalVote as ProposalVoteAccounts;
+pub use squads_multisig_program::accounts::ProposalVoteMany as ProposalVoteManyAccounts;
+pub use squads_multisig_program::accounts::ProposalVotePasskey as ProposalVotePasskeyAccounts;
+pub use squads_multisig_program::accounts::SessionKeyCreate as SessionKeyCreateAccounts;
+pub use squads_multisig_program::accounts::SessionKeyRevoke as SessionKeyRevokeAccounts;
 pub use squads_multisig_program::accounts::SpendingLimitUse as SpendingLimitUseAccounts;
+pub use squads_multisig_program::accounts::StreamClaim as StreamClaimAccounts;
+pub use squads_multisig_program::accounts::TransactionsAccountsCloseMany as TransactionsAccountsCloseManyAccounts;
 pub use squads_multisig_program::accounts::VaultBatchTransactionAccountClose as VaultBatchTransactionAccountCloseAccounts;
 pub use squads_multisig_program::accounts::VaultTransactionAccountsClose as VaultTransactionAccountsCloseAccounts;
 pub use squads_multisig_program::accounts::VaultTransactionCreate as VaultTransactionCreateAccounts;
+pub use squads_multisig_program::accounts::VaultTransactionCreateV2 as VaultTransactionCreateV2Accounts;
 pub use squads_multisig_program::accounts::VaultTransactionExecute as VaultTransactionExecuteAccounts;
 use squads_multisig_program::anchor_lang::AnchorSerialize;
 pub use squads_multisig_program::instruction::ConfigTransactionAccountsClose as ConfigTransactionAccountsCloseData;
 pub use squads_multisig_program::instruction::ConfigTransactionCreate as ConfigTransactionCreateData;
 pub use squads_multisig_program::instruction::ConfigTransactionExecute as ConfigTransactionExecuteData;
+pub use squads_multisig_program::instruction::EndorsementAccountsClose as EndorsementAccountsCloseData;
+pub use squads_multisig_program::instruction::EndorsementCreate as EndorsementCreateData;
+pub use squads_multisig_program::instruction::EndorsementExecute as EndorsementExecuteData;
 pub use squads_multisig_program::instruction::MultisigCreate as MultisigCreateData;
 pub use squads_multisig_program::instruction::MultisigCreateV2 as MultisigCreateDataV2;
 pub use squads_multisig_program::instruction::ProposalApprove as ProposalApproveData;
+pub use squads_multisig_program::instruction::ProposalApproveMany as ProposalApproveManyData;
 pub use squads_multisig_program::instruction::ProposalCancel as ProposalCancelData;
+pub use squads_multisig_program::instruction::ProposalCancelMany as ProposalCancelManyData;
 pub use squads_multisig_program::instruction::ProposalCreate as ProposalCreateData;
+pub use squads_multisig_program::instruction::ProposalVotePasskey as ProposalVotePasskeyData;
+pub use squads_multisig_program::instruction::SessionKeyCreate as SessionKeyCreateData;
+pub use squads_multisig_program::instruction::SessionKeyRevoke as SessionKeyRevokeData;
 pub use squads_multisig_program::instruction::SpendingLimitUse as SpendingLimitUseData;
+pub use squads_multisig_program::instruction::StreamClaim as StreamClaimData;
+pub use squads_multisig_program::instruction::TransactionsAccountsCloseMany as TransactionsAccountsCloseManyData;
 pub use squads_multisig_program::instruction::VaultTransactionAccountsClose as VaultTransactionAccountsCloseData;
 pub use squads_multisig_program::instruction::VaultTransactionCreate as VaultTransactionCreateData;
+pub use squads_multisig_program::instruction::VaultTransactionCreateV2 as VaultTransactionCreateV2Data;
 pub use squads_multisig_program::instruction::VaultTransactionExecute as VaultTransactionExecuteData;
 pub use squads_multisig_program::instructions::ConfigTransactionCreateArgs;
+pub use squads_multisig_program::instructions::EndorsementCreateArgs;
 pub use squads_multisig_program::instructions::MultisigCreateArgsV2;
 pub use squads_multisig_program::instructions::ProposalCreateArgs;
 pub use squads_multisig_program::instructions::ProposalVoteArgs;
+pub use squads_multisig_program::instructions::ProposalVotePasskeyArgs;
+pub use squads_multisig_program::instructions::Vote;
+pub use squads_multisig_program::instructions::SessionKeyCreateArgs;
+pub use squads_multisig_program::instructions::SessionKeyRevokeArgs;
 pub use squads_multisig_program::instructions::SpendingLimitUseArgs;
+pub use squads_multisig_program::instructions::StreamClaimArgs;
 pub use squads_multisig_program::instructions::VaultTransactionCreateArgs;
+pub use squads_multisig_program::instructions::VaultTransactionCreateArgsV2;
 use squads_multisig_program::TransactionMessage;
 
 use crate::anchor_lang::prelude::Pubkey;
@@ -42,7 +70,7 @@ use crate::client::utils::IntoAccountMetas;
 use crate::error::ClientError;
 use crate::pda::get_vault_pda;
 use crate::solana_program::instruction::AccountMeta;
-use crate::state::{Multisig, SpendingLimit};
+use crate::state::{BalanceGuards, Multisig, Precondition, SpendingLimit};
 use crate::vault_transaction::{Error, VaultTransactionMessageExt};
 use crate::ClientResult;
 use solana_message::AddressLookupTableAccount;
@@ -315,7 +343,259 @@ pub fn proposal_cancel(
     }
 }
 
+/// Votes "approve" on several multisig proposals at once.
+/// `proposals` are the proposal account keys, each paired with the key of its transaction,
+/// which is only required if the `Vote` permission of any member is scoped to particular vaults.
+/// Example:
+/// ```
+/// use squads_multisig::solana_program::{pubkey::Pubkey, system_program};
+/// use squads_multisig::client::{
+///     ProposalVoteManyAccounts,
+///     ProposalVoteArgs,
+///     proposal_approve_many,
+/// };
+///
+/// let ix = proposal_approve_many(
+///     ProposalVoteManyAccounts {
+///         multisig: Pubkey::new_unique(),
+///         member: Pubkey::new_unique(),
+///         system_program: system_program::id(),
+///     },
+///     &[(Pubkey::new_unique(), None), (Pubkey::new_unique(), None)],
+///     ProposalVoteArgs { memo: None },
+///     Some(squads_multisig_program::ID)
+/// );
+/// ```
+pub fn proposal_approve_many(
+    accounts: ProposalVoteManyAccounts,
+    proposals: &[(Pubkey, Option<Pubkey>)],
+    args: ProposalVoteArgs,
+    program_id: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        accounts: proposal_vote_many_account_metas(accounts, proposals),
+        data: ProposalApproveManyData { args }.data(),
+        program_id: program_id.unwrap_or(squads_multisig_program::ID),
+    }
+}
+
+/// Votes "cancel" on several multisig proposals at once.
+/// `proposals` are paired with their transactions the same way as in `proposal_approve_many`.
+/// Example:
+/// ```
+/// use squads_multisig::solana_program::{pubkey::Pubkey, system_program};
+/// use squads_multisig::client::{
+///     ProposalVoteManyAccounts,
+///     ProposalVoteArgs,
+///     proposal_cancel_many,
+/// };
+///
+/// let ix = proposal_cancel_many(
+///     ProposalVoteManyAccounts {
+///         multisig: Pubkey::new_unique(),
+///         member: Pubkey::new_unique(),
+///         system_program: system_program::id(),
+///     },
+///     &[(Pubkey::new_unique(), None), (Pubkey::new_unique(), None)],
+///     ProposalVoteArgs { memo: None },
+///     Some(squads_multisig_program::ID)
+/// );
+/// ```
+pub fn proposal_cancel_many(
+    accounts: ProposalVoteManyAccounts,
+    proposals: &[(Pubkey, Option<Pubkey>)],
+    args: ProposalVoteArgs,
+    program_id: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        accounts: proposal_vote_many_account_metas(accounts, proposals),
+        data: ProposalCancelManyData { args }.data(),
+        program_id: program_id.unwrap_or(squads_multisig_program::ID),
+    }
+}
+
+/// Registers a session key that can act on behalf of the multisig member for the specified actions until it expires.
+/// Example:
+/// ```
+/// use squads_multisig::solana_program::pubkey::Pubkey;
+/// use squads_multisig::solana_program::system_program;
+/// use squads_multisig::client::{
+///     SessionKeyCreateAccounts,
+///     SessionKeyCreateArgs,
+///     session_key_create,
+/// };
+/// use squads_multisig::state::{SessionAction, SessionActions};
+///
+/// let ix = session_key_create(
+///     SessionKeyCreateAccounts {
+///         multisig: Pubkey::new_unique(),
+///         session_key: Pubkey::new_unique(),
+///         member: Pubkey::new_unique(),
+///         system_program: system_program::id(),
+///     },
+///     SessionKeyCreateArgs {
+///         key: Pubkey::new_unique(),
+///         actions: SessionActions::from_vec(&[SessionAction::Approve]),
+///         max_lamports_outflow: Some(1_000_000_000),
+///         expires_at: 1_700_000_000,
+///         memo: None,
+///     },
+///     None,
+/// );
+/// ```
+pub fn session_key_create(
+    accounts: SessionKeyCreateAccounts,
+    args: SessionKeyCreateArgs,
+    program_id: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        accounts: accounts.to_account_metas(Some(false)),
+        data: SessionKeyCreateData { args }.data(),
+        program_id: program_id.unwrap_or(squads_multisig_program::ID),
+    }
+}
+
+/// Revokes a session key of the multisig member.
+pub fn session_key_revoke(
+    accounts: SessionKeyRevokeAccounts,
+    args: SessionKeyRevokeArgs,
+    program_id: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        accounts: accounts.to_account_metas(Some(false)),
+        data: SessionKeyRevokeData { args }.data(),
+        program_id: program_id.unwrap_or(squads_multisig_program::ID),
+    }
+}
+
+fn proposal_vote_many_account_metas(
+    accounts: ProposalVoteManyAccounts,
+    proposals: &[(Pubkey, Option<Pubkey>)],
+) -> Vec<AccountMeta> {
+    let mut account_metas = accounts.to_account_metas(Some(false));
+    for (proposal, transaction) in proposals {
+        account_metas.push(AccountMeta::new(*proposal, false));
+        if let Some(transaction) = transaction {
+            account_metas.push(AccountMeta::new_readonly(*transaction, false));
+        }
+    }
+    account_metas
+}
+
+/// Casts a vote on a multisig proposal on behalf of a passkey member.
+/// Must be preceded by a secp256r1 signature verification instruction that verifies the WebAuthn assertion
+/// of the member's passkey over the challenge returned by `Passkey::challenge()` for the vote.
+/// Example:
+/// ```
+/// use squads_multisig::solana_program::pubkey::Pubkey;
+/// use squads_multisig::solana_program::{system_program, sysvar};
+/// use squads_multisig::client::{
+///     ProposalVotePasskeyAccounts,
+///     ProposalVotePasskeyArgs,
+///     Vote,
+///     proposal_vote_passkey,
+/// };
+///
+/// let ix = proposal_vote_passkey(
+///     ProposalVotePasskeyAccounts {
+///         multisig: Pubkey::new_unique(),
+///         proposal: Pubkey::new_unique(),
+///         payer: Pubkey::new_unique(),
+///         instructions_sysvar: sysvar::instructions::id(),
+///         system_program: system_program::id(),
+///         transaction: None,
+///     },
+///     ProposalVotePasskeyArgs {
+///         member: Pubkey::new_unique(),
+///         vote: Vote::Approve,
+///         client_data_json: br#"{"type":"webauthn.get","challenge":"..."}"#.to_vec(),
+///         memo: None,
+///     },
+///     None,
+/// );
+/// ```
+pub fn proposal_vote_passkey(
+    accounts: ProposalVotePasskeyAccounts,
+    args: ProposalVotePasskeyArgs,
+    program_id: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        accounts: accounts.to_account_metas(Some(false)),
+        data: ProposalVotePasskeyData { args }.data(),
+        program_id: program_id.unwrap_or(squads_multisig_program::ID),
+    }
+}
+
+/// Creates an endorsement: a transaction casting the vote of a vault of the multisig
+/// on a proposal of a parent multisig the vault is a member of.
+/// Example:
+/// ```
+/// use squads_multisig::solana_program::pubkey::Pubkey;
+/// use squads_multisig::solana_program::system_program;
+/// use squads_multisig::client::{
+///     EndorsementCreateAccounts,
+///     EndorsementCreateArgs,
+///     Vote,
+///     endorsement_create,
+/// };
+///
+/// let ix = endorsement_create(
+///     EndorsementCreateAccounts {
+///         multisig: Pubkey::new_unique(),
+///         endorsement: Pubkey::new_unique(),
+///         creator: Pubkey::new_unique(),
+///         rent_payer: Pubkey::new_unique(),
+///         system_program: system_program::id(),
+///     },
+///     EndorsementCreateArgs {
+///         vault_index: 0,
+///         parent_multisig: Pubkey::new_unique(),
+///         parent_transaction_index: 1,
+///         vote: Vote::Approve,
+///         memo: None,
+///     },
+///     None,
+/// );
+/// ```
+pub fn endorsement_create(
+    accounts: EndorsementCreateAccounts,
+    args: EndorsementCreateArgs,
+    program_id: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        accounts: accounts.to_account_metas(Some(false)),
+        data: EndorsementCreateData { args }.data(),
+        program_id: program_id.unwrap_or(squads_multisig_program::ID),
+    }
+}
+
+/// Executes an approved endorsement, casting the vote of its vault on the parent multisig proposal.
+pub fn endorsement_execute(
+    accounts: EndorsementExecuteAccounts,
+    program_id: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        accounts: accounts.to_account_metas(Some(false)),
+        data: EndorsementExecuteData {}.data(),
+        program_id: program_id.unwrap_or(squads_multisig_program::ID),
+    }
+}
+
+/// Closes an endorsement and the corresponding proposal.
+pub fn endorsement_accounts_close(
+    accounts: EndorsementAccountsCloseAccounts,
+    program_id: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        accounts: accounts.to_account_metas(Some(false)),
+        data: EndorsementAccountsCloseData {}.data(),
+        program_id: program_id.unwrap_or(squads_multisig_program::ID),
+    }
+}
+
 /// Use a Spending Limit to transfer tokens from a multisig vault to a destination account.
+/// If the multisig has a rate limit, its `RateLimit` account must be appended to the instruction accounts,
+/// writable. Only the lamports transferred count against its `max_lamports`.
 /// Example:
 /// ```
 /// use squads_multisig::solana_program::pubkey::Pubkey;
@@ -363,6 +643,52 @@ pub fn spending_limit_use(
     }
 }
 
+/// Claim the vested amount of a Stream, transferring it from a multisig vault to the stream recipient.
+/// Example:
+/// ```
+/// use squads_multisig::solana_program::pubkey::Pubkey;
+/// use squads_multisig::solana_program::system_program;
+/// use squads_multisig::client::{
+///     StreamClaimAccounts,
+///     StreamClaimArgs,
+///     stream_claim,
+/// };
+///
+/// let ix = stream_claim(
+///     StreamClaimAccounts {
+///         multisig: Pubkey::new_unique(),
+///         recipient: Pubkey::new_unique(),
+///         stream: Pubkey::new_unique(),
+///         vault: Pubkey::new_unique(),
+///         system_program: Some(system_program::id()),
+///         mint: None,
+///         vault_token_account: None,
+///         recipient_token_account: None,
+///         token_program: None,
+///         rate_limit: None,
+///     },
+///     StreamClaimArgs {
+///         decimals: 9,
+///         memo: None
+///     },
+///     None,
+/// );
+/// ```
+///
+pub fn stream_claim(
+    accounts: StreamClaimAccounts,
+    args: StreamClaimArgs,
+    program_id: Option<Pubkey>,
+) -> Instruction {
+    let program_id = program_id.unwrap_or(squads_multisig_program::ID);
+
+    Instruction {
+        accounts: accounts.into_account_metas(program_id),
+        data: StreamClaimData { args }.data(),
+        program_id,
+    }
+}
+
 /// Creates a new vault transaction.
 /// Example:
 /// ```
@@ -426,7 +752,86 @@ pub fn vault_transaction_create(
     }
 }
 
+/// Creates a new vault transaction with balance guards, preconditions and the account change flags.
+/// Example:
+/// ```
+/// use squads_multisig::anchor_lang::AnchorSerialize;
+/// use squads_multisig::solana_program::pubkey::Pubkey;
+/// use squads_multisig::solana_program::{system_instruction, system_program};
+/// use squads_multisig::client::{
+///     VaultTransactionCreateV2Accounts,
+///     VaultTransactionCreateArgsV2,
+///     vault_transaction_create_v2,
+/// };
+/// use squads_multisig::pda::get_vault_pda;
+/// use squads_multisig::vault_transaction::VaultTransactionMessageExt;
+/// use squads_multisig_program::TransactionMessage;
+///
+/// let multisig = Pubkey::new_unique();
+/// let vault_index = 0;
+/// let vault_pda = get_vault_pda(&multisig, vault_index, None).0;
+///
+/// // Create a vault transaction that includes 1 instruction - SOL transfer from the default vault.
+/// let message = TransactionMessage::try_compile(
+///     &vault_pda,
+///     &[system_instruction::transfer(&vault_pda, &Pubkey::new_unique(), 1_000_000)],
+///     &[]
+/// ).unwrap();
+///
+/// let ix = vault_transaction_create_v2(
+///     VaultTransactionCreateV2Accounts {
+///         multisig,
+///         transaction: Pubkey::new_unique(),
+///         creator: Pubkey::new_unique(),
+///         rent_payer: Pubkey::new_unique(),
+///         system_program: system_program::id(),
+///         session_key: None,
+///     },
+///     vault_index,
+///     0,
+///     &message,
+///     None,
+///     false,
+///     false,
+///     vec![],
+///     None,
+///     None,
+/// );
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn vault_transaction_create_v2(
+    accounts: VaultTransactionCreateV2Accounts,
+    vault_index: u8,
+    num_ephemeral_signers: u8,
+    message: &TransactionMessage,
+    balance_guards: Option<BalanceGuards>,
+    allow_vault_account_changes: bool,
+    deny_ephemeral_signer_account_changes: bool,
+    preconditions: Vec<Precondition>,
+    memo: Option<String>,
+    program_id: Option<Pubkey>,
+) -> Instruction {
+    let args = VaultTransactionCreateArgsV2 {
+        vault_index,
+        ephemeral_signers: num_ephemeral_signers,
+        transaction_message: message.try_to_vec().unwrap(),
+        balance_guards,
+        allow_vault_account_changes,
+        deny_ephemeral_signer_account_changes,
+        preconditions,
+        memo,
+    };
+
+    Instruction {
+        accounts: accounts.to_account_metas(Some(false)),
+        data: VaultTransactionCreateV2Data { args }.data(),
+        program_id: program_id.unwrap_or(squads_multisig_program::ID),
+    }
+}
+
 /// Executes a vault transaction.
+/// If the multisig has a rate limit, its `RateLimit` account must be appended to the instruction accounts,
+/// writable, right after the accounts of the message.
 /// Example:
 /// ```
 /// use squads_multisig::anchor_lang::AnchorSerialize;
@@ -524,6 +929,7 @@ pub fn vault_transaction_execute(
 ///         transaction: Pubkey::new_unique(),
 ///         rent_collector: Pubkey::new_unique(),
 ///         system_program: system_program::id(),
+///         proposal_rent_payer: None,
 ///     },
 ///     None,
 /// );
@@ -558,6 +964,7 @@ pub fn config_transaction_accounts_close(
 ///         transaction: Pubkey::new_unique(),
 ///         rent_collector: Pubkey::new_unique(),
 ///         system_program: system_program::id(),
+///         proposal_rent_payer: None,
 ///     },
 ///     None,
 /// );
@@ -573,8 +980,50 @@ pub fn vault_transaction_accounts_close(
     }
 }
 
+/// Closes multiple `VaultTransaction`s and the corresponding `Proposal`s at once.
+/// `transactions` are pairs of `(proposal, transaction)` account keys,
+/// each transaction can be closed on the same conditions as in `vault_transaction_accounts_close`.
+///
+/// Example:
+/// ```
+/// use squads_multisig::solana_program::{pubkey::Pubkey, system_program};
+/// use squads_multisig::client::{
+///     TransactionsAccountsCloseManyAccounts,
+///     transactions_accounts_close_many
+/// };
+///
+/// let ix = transactions_accounts_close_many(
+///     TransactionsAccountsCloseManyAccounts {
+///         multisig: Pubkey::new_unique(),
+///         rent_collector: Pubkey::new_unique(),
+///         system_program: system_program::id(),
+///     },
+///     &[(Pubkey::new_unique(), Pubkey::new_unique())],
+///     None,
+/// );
+/// ```
+pub fn transactions_accounts_close_many(
+    accounts: TransactionsAccountsCloseManyAccounts,
+    transactions: &[(Pubkey, Pubkey)],
+    program_id: Option<Pubkey>,
+) -> Instruction {
+    let mut accounts = accounts.to_account_metas(Some(false));
+    for (proposal, transaction) in transactions {
+        accounts.push(AccountMeta::new(*proposal, false));
+        accounts.push(AccountMeta::new(*transaction, false));
+    }
+
+    Instruction {
+        accounts,
+        data: TransactionsAccountsCloseManyData {}.data(),
+        program_id: program_id.unwrap_or(squads_multisig_program::ID),
+    }
+}
+
 pub mod utils {
-    use squads_multisig_program::accounts::{ConfigTransactionExecute, SpendingLimitUse};
+    use squads_multisig_program::accounts::{
+        ConfigTransactionExecute, SpendingLimitUse, StreamClaim,
+    };
 
     use crate::solana_program::instruction::AccountMeta;
     use crate::solana_program::pubkey::Pubkey;
@@ -591,7 +1040,7 @@ pub mod utils {
                 AccountMeta::new(self.multisig, false),
                 AccountMeta::new_readonly(self.member, true),
                 AccountMeta::new(self.proposal, false),
-                AccountMeta::new_readonly(self.transaction, false),
+                AccountMeta::new(self.transaction, false),
                 if let Some(rent_payer) = self.rent_payer {
                     AccountMeta::new(rent_payer, true)
                 } else {
@@ -643,12 +1092,53 @@ pub mod utils {
         }
     }
 
+    impl IntoAccountMetas for StreamClaim {
+        fn into_account_metas(self, program_id: Pubkey) -> Vec<AccountMeta> {
+            vec![
+                AccountMeta::new_readonly(self.multisig, false),
+                AccountMeta::new(self.recipient, true),
+                AccountMeta::new(self.stream, false),
+                AccountMeta::new(self.vault, false),
+                if let Some(system_program) = self.system_program {
+                    AccountMeta::new_readonly(system_program, false)
+                } else {
+                    AccountMeta::new_readonly(program_id, false)
+                },
+                if let Some(mint) = self.mint {
+                    AccountMeta::new_readonly(mint, false)
+                } else {
+                    AccountMeta::new_readonly(program_id, false)
+                },
+                if let Some(vault_token_account) = self.vault_token_account {
+                    AccountMeta::new(vault_token_account, false)
+                } else {
+                    AccountMeta::new_readonly(program_id, false)
+                },
+                if let Some(recipient_token_account) = self.recipient_token_account {
+                    AccountMeta::new(recipient_token_account, false)
+                } else {
+                    AccountMeta::new_readonly(program_id, false)
+                },
+                if let Some(token_program) = self.token_program {
+                    AccountMeta::new_readonly(token_program, false)
+                } else {
+                    AccountMeta::new_readonly(program_id, false)
+                },
+                if let Some(rate_limit) = self.rate_limit {
+                    AccountMeta::new(rate_limit, false)
+                } else {
+                    AccountMeta::new_readonly(program_id, false)
+                },
+            ]
+        }
+    }
+
     #[cfg(test)]
     mod test {
         use crate::anchor_lang::prelude::Pubkey;
         use crate::anchor_lang::ToAccountMetas;
         use crate::client::utils::IntoAccountMetas;
-        use squads_multisig_program::accounts::SpendingLimitUse;
+        use squads_multisig_program::accounts::{SpendingLimitUse, StreamClaim};
 
         #[test]
         fn spending_limit_use_into_account_metas_matches_anchor_implementation() {
@@ -672,6 +1162,28 @@ pub mod utils {
             assert_eq!(anchor_metas, sdk_metas);
         }
 
+        #[test]
+        fn stream_claim_into_account_metas_matches_anchor_implementation() {
+            let accounts = StreamClaim {
+                multisig: Pubkey::new_unique(),
+                recipient: Pubkey::new_unique(),
+                stream: Pubkey::new_unique(),
+                vault: Pubkey::new_unique(),
+                system_program: Some(Pubkey::new_unique()),
+                mint: Some(Pubkey::new_unique()),
+                vault_token_account: Some(Pubkey::new_unique()),
+                recipient_token_account: Some(Pubkey::new_unique()),
+                token_program: Some(Pubkey::new_unique()),
+                rate_limit: Some(Pubkey::new_unique()),
+            };
+
+            // When program_id is the canonical one our implementation should match the anchor one.
+            let anchor_metas = accounts.to_account_metas(Some(false));
+            let sdk_metas = accounts.into_account_metas(squads_multisig_program::ID);
+
+            assert_eq!(anchor_metas, sdk_metas);
+        }
+
         #[test]
         fn config_transaction_execute_into_account_metas_matches_anchor_implementation() {
             let accounts = squads_multisig_program::accounts::ConfigTransactionExecute {
@@ -31,9 +31,12 @@ pub type ClientResult<T> = Result<T, error::ClientError>;
 pub mod state {
     pub use squads_multisig_program::instructions::TransactionMessage;
     pub use squads_multisig_program::state::{
-        Batch, ConfigAction, ConfigTransaction, Member, Multisig, MultisigCompiledInstruction,
-        MultisigMessageAddressTableLookup, Period, Permission, Permissions, Proposal,
-        ProposalStatus, SpendingLimit, VaultTransactionMessage,
+        Allowance, AllowedProgram, BalanceGuards, Batch, ConfigAction, ConfigTransaction,
+        Endorsement, FixedAccount, InstructionTemplate, Member, Multisig,
+        MultisigCompiledInstruction, MultisigMessageAddressTableLookup, Passkey, Period,
+        Permission, Permissions, Precondition, PreconditionCheck, Proposal, ProposalStatus,
+        SessionAction, SessionActions, SessionKey, SpendingLimit, Stream, TokenOutflowLimit,
+        TwoPersonRule, VaultScope, VaultTransactionMessage,
     };
     pub use squads_multisig_program::SmallVec;
 }
@@ -1,6 +1,7 @@
 use squads_multisig_program::{
-    SEED_EPHEMERAL_SIGNER, SEED_MULTISIG, SEED_PREFIX, SEED_PROGRAM_CONFIG, SEED_PROPOSAL,
-    SEED_SPENDING_LIMIT, SEED_TRANSACTION, SEED_VAULT,
+    SEED_ALLOWANCE, SEED_EPHEMERAL_SIGNER, SEED_MULTISIG, SEED_PREFIX, SEED_PROGRAM_CONFIG,
+    SEED_PROPOSAL, SEED_RATE_LIMIT, SEED_SESSION_KEY, SEED_SPENDING_LIMIT, SEED_STREAM,
+    SEED_TRANSACTION, SEED_VAULT,
 };
 
 use crate::solana_program::pubkey::Pubkey;
@@ -84,6 +85,63 @@ pub fn get_spending_limit_pda(
     )
 }
 
+pub fn get_allowance_pda(
+    multisig_pda: &Pubkey,
+    create_key: &Pubkey,
+    program_id: Option<&Pubkey>,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig_pda.to_bytes().as_ref(),
+            SEED_ALLOWANCE,
+            create_key.to_bytes().as_ref(),
+        ],
+        program_id.unwrap_or(&squads_multisig_program::ID),
+    )
+}
+
+pub fn get_stream_pda(
+    multisig_pda: &Pubkey,
+    create_key: &Pubkey,
+    program_id: Option<&Pubkey>,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig_pda.to_bytes().as_ref(),
+            SEED_STREAM,
+            create_key.to_bytes().as_ref(),
+        ],
+        program_id.unwrap_or(&squads_multisig_program::ID),
+    )
+}
+
+pub fn get_session_key_pda(
+    multisig_pda: &Pubkey,
+    member: &Pubkey,
+    key: &Pubkey,
+    program_id: Option<&Pubkey>,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig_pda.to_bytes().as_ref(),
+            SEED_SESSION_KEY,
+            member.to_bytes().as_ref(),
+            key.to_bytes().as_ref(),
+        ],
+        program_id.unwrap_or(&squads_multisig_program::ID),
+    )
+}
+
+pub fn get_rate_limit_pda(multisig_pda: &Pubkey, program_id: Option<&Pubkey>) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[SEED_PREFIX, multisig_pda.to_bytes().as_ref(), SEED_RATE_LIMIT],
+        program_id.unwrap_or(&squads_multisig_program::ID),
+    )
+}
+
 pub fn get_ephemeral_signer_pda(
     transaction_pda: &Pubkey,
     ephemeral_signer_index: u8,
@@ -6,19 +6,39 @@ import "./suites/examples/create-mint";
 import "./suites/examples/immediate-execution";
 import "./suites/examples/spending-limits";
 import "./suites/examples/transaction-buffer";
+import "./suites/instructions/allowanceUse";
 import "./suites/instructions/batchAccountsClose";
+import "./suites/instructions/batchAddTransactionFromBuffer";
+import "./suites/instructions/batchExecuteTransactions";
 import "./suites/instructions/cancelRealloc";
 import "./suites/instructions/configTransactionAccountsClose";
 import "./suites/instructions/configTransactionExecute";
+import "./suites/instructions/endorsementExecute";
+import "./suites/instructions/multisigClose";
 import "./suites/instructions/multisigCreate";
 import "./suites/instructions/multisigCreateV2";
+import "./suites/instructions/multisigSetMemberValidUntil";
+import "./suites/instructions/multisigSetMemberVaultScope";
+import "./suites/instructions/multisigSetProgramAllowlist";
+import "./suites/instructions/multisigSetRateLimit";
 import "./suites/instructions/multisigSetRentCollector";
+import "./suites/instructions/multisigSetRentRefund";
+import "./suites/instructions/multisigSetTwoPersonRule";
+import "./suites/instructions/proposalAbstain";
+import "./suites/instructions/proposalRebase";
+import "./suites/instructions/proposalVoteMany";
+import "./suites/instructions/proposalVotePasskey";
+import "./suites/instructions/proposalWithdrawVote";
+import "./suites/instructions/sessionKeyCreate";
+import "./suites/instructions/streamClaim";
 import "./suites/instructions/transactionBufferClose";
 import "./suites/instructions/transactionBufferCreate";
 import "./suites/instructions/transactionBufferExtend";
+import "./suites/instructions/transactionsAccountsCloseMany";
 import "./suites/instructions/vaultBatchTransactionAccountClose";
 import "./suites/instructions/vaultTransactionAccountsClose";
 import "./suites/instructions/vaultTransactionCreateFromBuffer";
+import "./suites/instructions/vaultTransactionExecute";
 import "./suites/multisig-sdk";
 
 // // Uncomment to enable the heapTest instruction testing
@@ -1,9 +1,13 @@
 import assert from "assert";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
 import * as multisig from "@sqds/multisig";
-import { createLocalhostConnection, getTestProgramId } from "../utils";
+import {
+  createLocalhostConnection,
+  getTestProgramId,
+  sendInstructions,
+} from "../utils";
 
-const { Multisig } = multisig.accounts;
+const { Multisig, Proposal } = multisig.accounts;
 const { toBigInt } = multisig.utils;
 
 const programId = getTestProgramId();
@@ -55,5 +59,50 @@ describe("Account Schema Migrations", () => {
       programId,
     });
     await connection.confirmTransaction(sig);
+
+    // Should vote on the old multisig account.
+    const transactionIndex = toBigInt(oldMultisigAccount.transactionIndex) + 1n;
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.proposalCreate({
+          multisigPda: oldMultisigPda,
+          transactionIndex,
+          creator: memberKeypair.publicKey,
+          programId,
+        }),
+        multisig.instructions.proposalApprove({
+          multisigPda: oldMultisigPda,
+          transactionIndex,
+          member: memberKeypair.publicKey,
+          programId,
+        }),
+      ],
+      [memberKeypair]
+    );
+
+    const [proposalPda] = multisig.getProposalPda({
+      multisigPda: oldMultisigPda,
+      transactionIndex,
+      programId,
+    });
+    const proposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      proposalPda
+    );
+    assert.deepEqual(
+      proposalAccount.approved.map((key) => key.toBase58()),
+      [memberKeypair.publicKey.toBase58()]
+    );
+
+    // The multisig account should keep its original size,
+    // as no extension is written while it stays default.
+    const multisigAccountInfo = await connection.getAccountInfo(oldMultisigPda);
+    assert.strictEqual(multisigAccountInfo!.data.length, 232);
+    const [multisigAccount] = Multisig.fromAccountInfo(multisigAccountInfo!);
+    assert.strictEqual(
+      multisigAccount.transactionIndex.toString(),
+      transactionIndex.toString()
+    );
   });
 });
@@ -182,6 +182,8 @@ describe("Examples / Transaction Buffers", () => {
           multisig: multisigPda,
           transactionBuffer,
           creator: members.almighty.publicKey,
+          rentPayer: members.almighty.publicKey,
+          systemProgram: SystemProgram.programId,
         },
         {
           args: {
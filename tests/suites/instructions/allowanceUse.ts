@@ -0,0 +1,197 @@
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createControlledMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Allowance } = multisig.accounts;
+const { Period } = multisig.generated;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / allowance_use", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let vaultPda: PublicKey;
+  let allowancePda: PublicKey;
+
+  /** Transfers `amount` from the vault to `recipient` using the allowance, signed by `member`. */
+  const useAllowance = (
+    member: Keypair,
+    recipient: PublicKey,
+    amount: number
+  ) => {
+    const transferIx = createTestTransferInstruction(
+      vaultPda,
+      recipient,
+      amount
+    );
+
+    return sendInstructions(
+      connection,
+      [
+        multisig.generated.createAllowanceUseInstruction(
+          {
+            multisig: multisigPda,
+            member: member.publicKey,
+            allowance: allowancePda,
+            vault: vaultPda,
+            program: SystemProgram.programId,
+            anchorRemainingAccounts: transferIx.keys.map((key) => ({
+              ...key,
+              // The vault signs via the program.
+              isSigner: false,
+            })),
+          },
+          { args: { data: transferIx.data, memo: null } },
+          programId
+        ),
+      ],
+      [member]
+    );
+  };
+
+  before(async () => {
+    const configAuthority = await generateFundedKeypair(connection);
+
+    members = await generateMultisigMembers(connection);
+
+    multisigPda = (
+      await createControlledMultisig({
+        connection,
+        createKey: Keypair.generate(),
+        configAuthority: configAuthority.publicKey,
+        members,
+        threshold: 1,
+        timeLock: 0,
+        programId,
+      })
+    )[0];
+
+    [vaultPda] = multisig.getVaultPda({ multisigPda, index: 0, programId });
+
+    // Fund the vault.
+    const signature = await connection.requestAirdrop(
+      vaultPda,
+      10 * LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+
+    // Let `voter` transfer from the vault to anyone, once.
+    const createKey = Keypair.generate().publicKey;
+    [allowancePda] = multisig.getAllowancePda({
+      multisigPda,
+      createKey,
+      programId,
+    });
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigAddAllowanceInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            allowance: allowancePda,
+            rentPayer: configAuthority.publicKey,
+          },
+          {
+            args: {
+              createKey,
+              vaultIndex: 0,
+              template: {
+                programId: SystemProgram.programId,
+                writableFlags: [true, true],
+                fixedAccounts: [{ index: 0, pubkey: vaultPda }],
+                // `SystemInstruction::Transfer`, any amount.
+                dataPattern: new Uint8Array([2, 0, 0, 0]),
+                dataMask: new Uint8Array([0xff, 0xff, 0xff, 0xff]),
+              },
+              maxUses: 1,
+              period: Period.OneTime,
+              expiresAt: null,
+              members: [members.voter.publicKey],
+              memo: null,
+            },
+          },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+  });
+
+  it("error: member not in the allowance", async () => {
+    await assert.rejects(
+      () =>
+        useAllowance(members.almighty, Keypair.generate().publicKey, 1_000_000),
+      /Unauthorized/
+    );
+  });
+
+  it("error: instruction not matching the template", async () => {
+    // The template only permits transfers from the vault.
+    const transferIx = SystemProgram.transfer({
+      fromPubkey: members.voter.publicKey,
+      toPubkey: vaultPda,
+      lamports: 1_000_000,
+    });
+
+    await assert.rejects(
+      () =>
+        sendInstructions(
+          connection,
+          [
+            multisig.generated.createAllowanceUseInstruction(
+              {
+                multisig: multisigPda,
+                member: members.voter.publicKey,
+                allowance: allowancePda,
+                vault: vaultPda,
+                program: SystemProgram.programId,
+                anchorRemainingAccounts: transferIx.keys,
+              },
+              { args: { data: transferIx.data, memo: null } },
+              programId
+            ),
+          ],
+          [members.voter]
+        ),
+      /InstructionNotMatchingTemplate/
+    );
+  });
+
+  it("use the allowance", async () => {
+    const recipient = Keypair.generate().publicKey;
+
+    await useAllowance(members.voter, recipient, 1_000_000);
+
+    assert.strictEqual(await connection.getBalance(recipient), 1_000_000);
+
+    const allowanceAccount = await Allowance.fromAccountAddress(
+      connection,
+      allowancePda
+    );
+    assert.strictEqual(allowanceAccount.remainingUses, 0);
+  });
+
+  it("error: allowance exhausted", async () => {
+    await assert.rejects(
+      () => useAllowance(members.voter, Keypair.generate().publicKey, 1_000_000),
+      /AllowanceExhausted/
+    );
+  });
+});
@@ -0,0 +1,277 @@
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import * as crypto from "crypto";
+import {
+  createAutonomousMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateMultisigMembers,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig, Batch, VaultBatchTransaction } = multisig.accounts;
+const { toBigInt } = multisig.utils;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / batch_add_transaction_from_buffer", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let vaultPda: PublicKey;
+  let batchIndex: bigint;
+
+  /** Creates a buffer holding a transfer to `recipient`, written in one go by `almighty`. */
+  const createTransactionBuffer = async (
+    bufferIndex: number,
+    recipient: PublicKey
+  ) => {
+    const messageBuffer =
+      multisig.utils.transactionMessageToMultisigTransactionMessageBytes({
+        message: new TransactionMessage({
+          payerKey: vaultPda,
+          recentBlockhash: PublicKey.default.toBase58(),
+          instructions: [createTestTransferInstruction(vaultPda, recipient)],
+        }),
+        addressLookupTableAccounts: [],
+        vaultPda,
+      });
+    const [transactionBuffer] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("multisig"),
+        multisigPda.toBuffer(),
+        Buffer.from("transaction_buffer"),
+        members.almighty.publicKey.toBuffer(),
+        Uint8Array.from([bufferIndex]),
+      ],
+      programId
+    );
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createTransactionBufferCreateInstruction(
+          {
+            multisig: multisigPda,
+            transactionBuffer,
+            creator: members.almighty.publicKey,
+            rentPayer: members.almighty.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          {
+            args: {
+              bufferIndex,
+              vaultIndex: 0,
+              finalBufferHash: Array.from(
+                crypto.createHash("sha256").update(messageBuffer).digest()
+              ),
+              finalBufferSize: messageBuffer.length,
+              buffer: messageBuffer,
+            },
+          },
+          programId
+        ),
+      ],
+      [members.almighty]
+    );
+
+    return transactionBuffer;
+  };
+
+  const addTransactionFromBuffer = (
+    transactionBuffer: PublicKey,
+    transactionIndex: number,
+    transactionMessage = new Uint8Array([0, 0, 0, 0, 0, 0])
+  ) => {
+    const [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex: batchIndex,
+      programId,
+    });
+    const [batchPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: batchIndex,
+      programId,
+    });
+    const [batchTransactionPda] = multisig.getBatchTransactionPda({
+      multisigPda,
+      batchIndex,
+      transactionIndex,
+      programId,
+    });
+
+    return sendInstructions(
+      connection,
+      [
+        multisig.generated.createBatchAddTransactionFromBufferInstruction(
+          {
+            batchAddTransactionItemMultisig: multisigPda,
+            batchAddTransactionItemProposal: proposalPda,
+            batchAddTransactionItemBatch: batchPda,
+            batchAddTransactionItemTransaction: batchTransactionPda,
+            batchAddTransactionItemMember: members.almighty.publicKey,
+            batchAddTransactionItemRentPayer: members.almighty.publicKey,
+            batchAddTransactionItemSystemProgram: SystemProgram.programId,
+            transactionBuffer,
+            member: members.almighty.publicKey,
+          },
+          {
+            args: {
+              ephemeralSigners: 0,
+              // Must be empty, the message is taken from the buffer.
+              transactionMessage,
+              balanceGuards: null,
+              allowVaultAccountChanges: false,
+              denyEphemeralSignerAccountChanges: false,
+            },
+          },
+          programId
+        ),
+      ],
+      [members.almighty]
+    );
+  };
+
+  before(async () => {
+    members = await generateMultisigMembers(connection);
+
+    [multisigPda] = await createAutonomousMultisig({
+      connection,
+      members,
+      threshold: 1,
+      timeLock: 0,
+      programId,
+    });
+
+    [vaultPda] = multisig.getVaultPda({ multisigPda, index: 0, programId });
+
+    // Fund the vault.
+    const signature = await connection.requestAirdrop(
+      vaultPda,
+      10 * LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+
+    // Create a draft batch.
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    batchIndex = toBigInt(multisigAccount.transactionIndex) + 1n;
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.batchCreate({
+          multisigPda,
+          creator: members.almighty.publicKey,
+          batchIndex,
+          vaultIndex: 0,
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex: batchIndex,
+          creator: members.almighty.publicKey,
+          isDraft: true,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+  });
+
+  it("error: non-empty transaction message", async () => {
+    const transactionBuffer = await createTransactionBuffer(
+      0,
+      Keypair.generate().publicKey
+    );
+
+    await assert.rejects(
+      () =>
+        addTransactionFromBuffer(
+          transactionBuffer,
+          1,
+          new Uint8Array([1, 0, 0, 0, 0, 0])
+        ),
+      /InvalidInstructionArgs/
+    );
+  });
+
+  it("add a transaction to the batch from a buffer and execute it", async () => {
+    const recipient = Keypair.generate().publicKey;
+    const transactionBuffer = await createTransactionBuffer(1, recipient);
+
+    await addTransactionFromBuffer(transactionBuffer, 1);
+
+    // The buffer is closed.
+    assert.strictEqual(
+      await connection.getAccountInfo(transactionBuffer),
+      null
+    );
+
+    const [batchPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: batchIndex,
+      programId,
+    });
+    const batchAccount = await Batch.fromAccountAddress(connection, batchPda);
+    assert.strictEqual(batchAccount.size, 1);
+    const batchTransactionAccount =
+      await VaultBatchTransaction.fromAccountAddress(
+        connection,
+        multisig.getBatchTransactionPda({
+          multisigPda,
+          batchIndex,
+          transactionIndex: 1,
+          programId,
+        })[0]
+      );
+    assert.strictEqual(
+      batchTransactionAccount.message.instructions.length,
+      1
+    );
+
+    // Activate, approve and execute the batch.
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.proposalActivate({
+          multisigPda,
+          transactionIndex: batchIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+        multisig.instructions.proposalApprove({
+          multisigPda,
+          transactionIndex: batchIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+    const { instruction } = await multisig.instructions.batchExecuteTransaction(
+      {
+        connection,
+        multisigPda,
+        member: members.almighty.publicKey,
+        batchIndex,
+        transactionIndex: 1,
+        programId,
+      }
+    );
+    await sendInstructions(connection, [instruction], [members.almighty]);
+
+    assert.strictEqual(await connection.getBalance(recipient), 1_000_000);
+  });
+});
@@ -0,0 +1,305 @@
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createAutonomousMultisigV2,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateMultisigMembers,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig, Batch, Proposal } = multisig.accounts;
+const { toBigInt } = multisig.utils;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / batch_execute_transactions", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let vaultPda: PublicKey;
+  let rentCollector: PublicKey;
+
+  /** Creates and approves a batch with a transfer to each of the `recipients`. */
+  const createApprovedBatch = async (recipients: PublicKey[]) => {
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    const batchIndex = toBigInt(multisigAccount.transactionIndex) + 1n;
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.batchCreate({
+          multisigPda,
+          creator: members.almighty.publicKey,
+          batchIndex,
+          vaultIndex: 0,
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex: batchIndex,
+          creator: members.almighty.publicKey,
+          isDraft: true,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+    await sendInstructions(
+      connection,
+      recipients.map((recipient, index) =>
+        multisig.instructions.batchAddTransaction({
+          multisigPda,
+          member: members.almighty.publicKey,
+          vaultIndex: 0,
+          batchIndex,
+          // Batch transaction indices start at 1.
+          transactionIndex: index + 1,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(vaultPda, recipient),
+            ],
+          }),
+          programId,
+        })
+      ),
+      [members.almighty]
+    );
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.proposalActivate({
+          multisigPda,
+          transactionIndex: batchIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+        multisig.instructions.proposalApprove({
+          multisigPda,
+          transactionIndex: batchIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+
+    return batchIndex;
+  };
+
+  /** Executes the batch transactions at `transactionIndexes` in a single instruction. */
+  const executeBatchTransactions = async (
+    batchIndex: bigint,
+    transactionIndexes: number[]
+  ) => {
+    const remainingAccounts = [];
+    let fixedAccounts;
+    for (const transactionIndex of transactionIndexes) {
+      const { instruction } =
+        await multisig.instructions.batchExecuteTransaction({
+          connection,
+          multisigPda,
+          member: members.almighty.publicKey,
+          batchIndex,
+          transactionIndex,
+          programId,
+        });
+      const [
+        multisigMeta,
+        memberMeta,
+        proposalMeta,
+        batchMeta,
+        transactionMeta,
+        ...accounts
+      ] = instruction.keys;
+      fixedAccounts = {
+        multisig: multisigMeta.pubkey,
+        member: memberMeta.pubkey,
+        proposal: proposalMeta.pubkey,
+        batch: batchMeta.pubkey,
+      };
+      remainingAccounts.push(transactionMeta, ...accounts);
+    }
+
+    return sendInstructions(
+      connection,
+      [
+        multisig.generated.createBatchExecuteTransactionsInstruction(
+          { ...fixedAccounts!, anchorRemainingAccounts: remainingAccounts },
+          programId
+        ),
+      ],
+      [members.almighty]
+    );
+  };
+
+  before(async () => {
+    members = await generateMultisigMembers(connection);
+
+    rentCollector = Keypair.generate().publicKey;
+
+    [multisigPda] = await createAutonomousMultisigV2({
+      connection,
+      members,
+      threshold: 1,
+      timeLock: 0,
+      rentCollector,
+      programId,
+    });
+
+    [vaultPda] = multisig.getVaultPda({ multisigPda, index: 0, programId });
+
+    // Fund the vault.
+    const signature = await connection.requestAirdrop(
+      vaultPda,
+      10 * LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+  });
+
+  it("execute several batch transactions at once", async () => {
+    const recipients = [
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+    ];
+    const batchIndex = await createApprovedBatch(recipients);
+    const [batchPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: batchIndex,
+      programId,
+    });
+    const [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex: batchIndex,
+      programId,
+    });
+
+    // Execute the first two transactions.
+    await executeBatchTransactions(batchIndex, [1, 2]);
+
+    assert.strictEqual(await connection.getBalance(recipients[0]), 1_000_000);
+    assert.strictEqual(await connection.getBalance(recipients[1]), 1_000_000);
+    assert.strictEqual(await connection.getBalance(recipients[2]), 0);
+    let batchAccount = await Batch.fromAccountAddress(connection, batchPda);
+    assert.strictEqual(batchAccount.executedTransactionIndex, 2);
+    let proposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      proposalPda
+    );
+    assert.ok(multisig.types.isProposalStatusApproved(proposalAccount.status));
+
+    // Execute the last one.
+    await executeBatchTransactions(batchIndex, [3]);
+
+    assert.strictEqual(await connection.getBalance(recipients[2]), 1_000_000);
+    batchAccount = await Batch.fromAccountAddress(connection, batchPda);
+    assert.strictEqual(batchAccount.executedTransactionIndex, 3);
+    proposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      proposalPda
+    );
+    assert.ok(multisig.types.isProposalStatusExecuted(proposalAccount.status));
+  });
+
+  it("error: batch transactions out of order", async () => {
+    const batchIndex = await createApprovedBatch([
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+    ]);
+
+    // The first transaction not executed yet must come first.
+    await assert.rejects(
+      () => executeBatchTransactions(batchIndex, [2, 1]),
+      /InvalidAccount/
+    );
+  });
+
+  it("cancel a partially executed batch", async () => {
+    const recipients = [
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+    ];
+    const batchIndex = await createApprovedBatch(recipients);
+    const [batchPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: batchIndex,
+      programId,
+    });
+    const [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex: batchIndex,
+      programId,
+    });
+
+    await executeBatchTransactions(batchIndex, [1]);
+
+    // Cancelling the proposal aborts the rest of the batch.
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.proposalCancel({
+          multisigPda,
+          transactionIndex: batchIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+
+    const proposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      proposalPda
+    );
+    assert.ok(multisig.types.isProposalStatusCancelled(proposalAccount.status));
+
+    await assert.rejects(
+      () => executeBatchTransactions(batchIndex, [2]),
+      /InvalidProposalStatus/
+    );
+    assert.strictEqual(await connection.getBalance(recipients[1]), 0);
+
+    // The transaction that was never executed can be closed.
+    const [batchTransactionPda] = multisig.getBatchTransactionPda({
+      multisigPda,
+      batchIndex,
+      transactionIndex: 2,
+      programId,
+    });
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultBatchTransactionAccountClose({
+          multisigPda,
+          rentCollector,
+          batchIndex,
+          transactionIndex: 2,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+
+    assert.strictEqual(
+      await connection.getAccountInfo(batchTransactionPda),
+      null
+    );
+    const batchAccount = await Batch.fromAccountAddress(connection, batchPda);
+    assert.strictEqual(batchAccount.size, 1);
+  });
+});
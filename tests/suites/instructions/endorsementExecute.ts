@@ -0,0 +1,296 @@
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createAutonomousMultisigV2,
+  createControlledMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Endorsement, Proposal } = multisig.accounts;
+const { Permission, Permissions } = multisig.types;
+const { Vote } = multisig.generated;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / endorsement_create, endorsement_execute, endorsement_accounts_close", () => {
+  let members: TestMembers;
+  let parentMembers: TestMembers;
+  let multisigPda: PublicKey;
+  let vaultPda: PublicKey;
+  let rentCollector: PublicKey;
+  let parentMultisigPda: PublicKey;
+  let parentProposalPda: PublicKey;
+  let endorsementPda: PublicKey;
+  let proposalPda: PublicKey;
+
+  const transactionIndex = 1n;
+  const parentTransactionIndex = 1n;
+
+  const createEndorsement = (creator: Keypair, parentMultisig: PublicKey) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createEndorsementCreateInstruction(
+          {
+            multisig: multisigPda,
+            endorsement: endorsementPda,
+            creator: creator.publicKey,
+            rentPayer: creator.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          {
+            args: {
+              vaultIndex: 0,
+              parentMultisig,
+              parentTransactionIndex,
+              vote: Vote.Approve,
+              memo: null,
+            },
+          },
+          programId
+        ),
+      ],
+      [creator]
+    );
+
+  const executeEndorsement = () =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createEndorsementExecuteInstruction(
+          {
+            multisig: multisigPda,
+            proposal: proposalPda,
+            endorsement: endorsementPda,
+            member: members.executor.publicKey,
+            parentMultisig: parentMultisigPda,
+            parentProposal: parentProposalPda,
+            systemProgram: SystemProgram.programId,
+          },
+          programId
+        ),
+      ],
+      [members.executor]
+    );
+
+  before(async () => {
+    members = await generateMultisigMembers(connection);
+    parentMembers = await generateMultisigMembers(connection);
+    rentCollector = Keypair.generate().publicKey;
+
+    [multisigPda] = await createAutonomousMultisigV2({
+      connection,
+      members,
+      threshold: 1,
+      timeLock: 0,
+      rentCollector,
+      programId,
+    });
+    [vaultPda] = multisig.getVaultPda({ multisigPda, index: 0, programId });
+    [endorsementPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: transactionIndex,
+      programId,
+    });
+    [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex,
+      programId,
+    });
+
+    // The vault of the multisig votes in the parent multisig,
+    // where one more approval is needed on top of its own.
+    const configAuthority = await generateFundedKeypair(connection);
+    [parentMultisigPda] = await createControlledMultisig({
+      connection,
+      createKey: Keypair.generate(),
+      configAuthority: configAuthority.publicKey,
+      members: parentMembers,
+      threshold: 2,
+      timeLock: 0,
+      programId,
+    });
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.multisigAddMember({
+          multisigPda: parentMultisigPda,
+          configAuthority: configAuthority.publicKey,
+          rentPayer: configAuthority.publicKey,
+          newMember: {
+            key: vaultPda,
+            permissions: Permissions.fromPermissions([Permission.Vote]),
+          },
+          programId,
+        }),
+      ],
+      [configAuthority]
+    );
+
+    [parentProposalPda] = multisig.getProposalPda({
+      multisigPda: parentMultisigPda,
+      transactionIndex: parentTransactionIndex,
+      programId,
+    });
+    const [parentVaultPda] = multisig.getVaultPda({
+      multisigPda: parentMultisigPda,
+      index: 0,
+      programId,
+    });
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda: parentMultisigPda,
+          transactionIndex: parentTransactionIndex,
+          creator: parentMembers.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: parentVaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                parentVaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda: parentMultisigPda,
+          transactionIndex: parentTransactionIndex,
+          creator: parentMembers.almighty.publicKey,
+          programId,
+        }),
+        multisig.instructions.proposalApprove({
+          multisigPda: parentMultisigPda,
+          transactionIndex: parentTransactionIndex,
+          member: parentMembers.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [parentMembers.almighty]
+    );
+  });
+
+  it("error: member without the `Initiate` permission", async () => {
+    await assert.rejects(
+      () => createEndorsement(members.voter, parentMultisigPda),
+      /Unauthorized/
+    );
+  });
+
+  it("error: endorsement for its own multisig", async () => {
+    await assert.rejects(
+      () => createEndorsement(members.proposer, multisigPda),
+      /EndorsementInvalidParent/
+    );
+  });
+
+  it("create an endorsement", async () => {
+    await createEndorsement(members.proposer, parentMultisigPda);
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.proposer.publicKey,
+          programId,
+        }),
+      ],
+      [members.proposer]
+    );
+
+    const endorsementAccount = await Endorsement.fromAccountAddress(
+      connection,
+      endorsementPda
+    );
+    assert.strictEqual(endorsementAccount.index.toString(), "1");
+    assert.strictEqual(
+      endorsementAccount.parentMultisig.toBase58(),
+      parentMultisigPda.toBase58()
+    );
+    assert.strictEqual(endorsementAccount.vote, Vote.Approve);
+
+    // The endorsement must be approved first.
+    await assert.rejects(() => executeEndorsement(), /InvalidProposalStatus/);
+  });
+
+  it("execute an endorsement", async () => {
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.proposalApprove({
+          multisigPda,
+          transactionIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+
+    await executeEndorsement();
+
+    const proposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      proposalPda
+    );
+    assert.ok(multisig.types.isProposalStatusExecuted(proposalAccount.status));
+
+    // The vault approval is recorded on the parent proposal.
+    const parentProposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      parentProposalPda
+    );
+    assert.ok(
+      multisig.types.isProposalStatusApproved(parentProposalAccount.status)
+    );
+    assert.ok(
+      parentProposalAccount.approved.some((key) => key.equals(vaultPda))
+    );
+  });
+
+  it("close the endorsement accounts", async () => {
+    const rent =
+      (await connection.getBalance(endorsementPda)) +
+      (await connection.getBalance(proposalPda));
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createEndorsementAccountsCloseInstruction(
+          {
+            multisig: multisigPda,
+            proposal: proposalPda,
+            endorsement: endorsementPda,
+            rentCollector,
+            systemProgram: SystemProgram.programId,
+          },
+          programId
+        ),
+      ],
+      [members.almighty]
+    );
+
+    assert.strictEqual(await connection.getAccountInfo(endorsementPda), null);
+    assert.strictEqual(await connection.getAccountInfo(proposalPda), null);
+    assert.strictEqual(await connection.getBalance(rentCollector), rent);
+  });
+});
@@ -0,0 +1,316 @@
+import {
+  AccountMeta,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createControlledMultisigV2,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getMultisigExtension,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig } = multisig.accounts;
+const { Period } = multisig.generated;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / multisig_close", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let configAuthority: Keypair;
+  let rentCollector: PublicKey;
+  let transactionPda: PublicKey;
+  let proposalPda: PublicKey;
+
+  const closeMultisig = (
+    rentDestination: PublicKey,
+    vaultIndices: number[],
+    accounts: PublicKey[]
+  ) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigCloseInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            anchorRemainingAccounts: [rentDestination, ...accounts].map(
+              (pubkey): AccountMeta => ({
+                pubkey,
+                isSigner: false,
+                isWritable: pubkey.equals(rentDestination),
+              })
+            ),
+          },
+          {
+            args: {
+              rentDestination,
+              vaultIndices: new Uint8Array(vaultIndices),
+              memo: null,
+            },
+          },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+
+  const getVaultPda = (index: number) =>
+    multisig.getVaultPda({ multisigPda, index, programId })[0];
+
+  before(async () => {
+    configAuthority = await generateFundedKeypair(connection);
+
+    members = await generateMultisigMembers(connection);
+
+    rentCollector = Keypair.generate().publicKey;
+
+    [multisigPda] = await createControlledMultisigV2({
+      connection,
+      configAuthority: configAuthority.publicKey,
+      members,
+      threshold: 1,
+      timeLock: 0,
+      rentCollector,
+      programId,
+    });
+
+    // Create a vault transaction for the multisig to hold.
+    const transactionIndex = 1n;
+    [transactionPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: transactionIndex,
+      programId,
+    });
+    [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex,
+      programId,
+    });
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: getVaultPda(0),
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                getVaultPda(0),
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+  });
+
+  it("error: transactions not closed", async () => {
+    await assert.rejects(
+      () =>
+        closeMultisig(rentCollector, [0], [getVaultPda(0), transactionPda]),
+      /MultisigNotEmpty/
+    );
+  });
+
+  it("record the closed transactions", async () => {
+    // Make the transaction stale and close its accounts.
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.multisigSetTimeLock({
+          multisigPda,
+          configAuthority: configAuthority.publicKey,
+          timeLock: 0,
+          programId,
+        }),
+        multisig.instructions.vaultTransactionAccountsClose({
+          multisigPda,
+          rentCollector,
+          transactionIndex: 1n,
+          programId,
+        }),
+      ],
+      [configAuthority]
+    );
+
+    // Anyone can record the closed transactions.
+    const payer = await generateFundedKeypair(connection);
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigRecordClosedTransactionsInstruction(
+          {
+            multisig: multisigPda,
+            rentPayer: payer.publicKey,
+            systemProgram: SystemProgram.programId,
+            anchorRemainingAccounts: [transactionPda, proposalPda].map(
+              (pubkey) => ({ pubkey, isSigner: false, isWritable: false })
+            ),
+          },
+          programId
+        ),
+      ],
+      [payer]
+    );
+
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.closedTransactionIndex.toString(), "1");
+  });
+
+  it("error: allowance not removed", async () => {
+    const createKey = Keypair.generate().publicKey;
+    const [allowancePda] = multisig.getAllowancePda({
+      multisigPda,
+      createKey,
+      programId,
+    });
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigAddAllowanceInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            allowance: allowancePda,
+            rentPayer: configAuthority.publicKey,
+          },
+          {
+            args: {
+              createKey,
+              vaultIndex: 0,
+              template: {
+                programId: SystemProgram.programId,
+                writableFlags: [true, true],
+                fixedAccounts: [{ index: 0, pubkey: getVaultPda(0) }],
+                // `SystemInstruction::Transfer`, any amount.
+                dataPattern: new Uint8Array([2, 0, 0, 0]),
+                dataMask: new Uint8Array([0xff, 0xff, 0xff, 0xff]),
+              },
+              maxUses: 1,
+              period: Period.OneTime,
+              expiresAt: null,
+              members: [members.almighty.publicKey],
+              memo: null,
+            },
+          },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+
+    let extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.allowanceCount, 1);
+
+    // The allowance is counted, so it doesn't have to be passed to be caught.
+    await assert.rejects(
+      () => closeMultisig(rentCollector, [0], [getVaultPda(0)]),
+      /MultisigNotEmpty/
+    );
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigRemoveAllowanceInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            allowance: allowancePda,
+            rentCollector,
+          },
+          { args: { memo: null } },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+
+    extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.allowanceCount, 0);
+  });
+
+  it("error: vault not empty", async () => {
+    const signature = await connection.requestAirdrop(
+      getVaultPda(1),
+      LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+
+    await assert.rejects(
+      () =>
+        closeMultisig(
+          rentCollector,
+          [0, 1],
+          [getVaultPda(0), getVaultPda(1)]
+        ),
+      /VaultNotEmpty/
+    );
+  });
+
+  it("close the multisig", async () => {
+    const multisigAccountInfoPreClose = await connection.getAccountInfo(
+      multisigPda
+    );
+    const rentCollectorBalancePreClose = await connection.getBalance(
+      rentCollector
+    );
+
+    await closeMultisig(rentCollector, [0], [getVaultPda(0)]);
+
+    // The multisig is left as a tombstone nobody can act on.
+    const multisigAccountInfoPostClose = await connection.getAccountInfo(
+      multisigPda
+    );
+    assert.ok(
+      multisigAccountInfoPostClose!.data.length <
+        multisigAccountInfoPreClose!.data.length
+    );
+    const [multisigAccount] = Multisig.fromAccountInfo(
+      multisigAccountInfoPostClose!
+    );
+    assert.strictEqual(multisigAccount.threshold, 0);
+    assert.strictEqual(multisigAccount.members.length, 0);
+    assert.strictEqual(
+      multisigAccount.configAuthority.toBase58(),
+      PublicKey.default.toBase58()
+    );
+    // The rent the tombstone doesn't need goes to the rent destination.
+    assert.ok(
+      multisigAccountInfoPostClose!.lamports <
+        multisigAccountInfoPreClose!.lamports
+    );
+    assert.strictEqual(
+      await connection.getBalance(rentCollector),
+      rentCollectorBalancePreClose +
+        multisigAccountInfoPreClose!.lamports -
+        multisigAccountInfoPostClose!.lamports
+    );
+  });
+});
@@ -0,0 +1,233 @@
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createControlledMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getMultisigExtension,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig } = multisig.accounts;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / multisig_set_member_valid_until", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let configAuthority: Keypair;
+
+  const setMemberValidUntil = (member: PublicKey, validUntil: number | null) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigSetMemberValidUntilInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            rentPayer: configAuthority.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          { args: { member, validUntil, memo: null } },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+
+  before(async () => {
+    configAuthority = await generateFundedKeypair(connection);
+
+    members = await generateMultisigMembers(connection);
+
+    multisigPda = (
+      await createControlledMultisig({
+        connection,
+        createKey: Keypair.generate(),
+        configAuthority: configAuthority.publicKey,
+        members,
+        threshold: 1,
+        timeLock: 0,
+        programId,
+      })
+    )[0];
+  });
+
+  it("set and unset `valid_until` for a member", async () => {
+    const multisigAccountInfoPreExecution = await connection.getAccountInfo(
+      multisigPda
+    );
+    const validUntil = Math.floor(Date.now() / 1000) + 3600;
+
+    await setMemberValidUntil(members.proposer.publicKey, validUntil);
+
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.memberSettings.length, 1);
+    assert.strictEqual(
+      extension!.memberSettings[0].key.toBase58(),
+      members.proposer.publicKey.toBase58()
+    );
+    assert.strictEqual(
+      extension!.memberSettings[0].validUntil?.toString(),
+      validUntil.toString()
+    );
+    // The multisig account is reallocated to fit the member settings.
+    const multisigAccountInfoPostExecution = await connection.getAccountInfo(
+      multisigPda
+    );
+    assert.ok(
+      multisigAccountInfoPostExecution!.data.length >
+        multisigAccountInfoPreExecution!.data.length
+    );
+
+    // Make the membership permanent again.
+    await setMemberValidUntil(members.proposer.publicKey, null);
+
+    const extensionPostUnset = await getMultisigExtension(
+      connection,
+      multisigPda
+    );
+    assert.strictEqual(extensionPostUnset!.memberSettings.length, 0);
+  });
+
+  it("error: threshold relying on expiring voters", async () => {
+    // `almighty` and `voter` are the only voters, so the threshold of 1
+    // can't rely on both of them expiring.
+    await setMemberValidUntil(
+      members.voter.publicKey,
+      Math.floor(Date.now() / 1000) + 3600
+    );
+
+    await assert.rejects(
+      () =>
+        setMemberValidUntil(
+          members.almighty.publicKey,
+          Math.floor(Date.now() / 1000) + 3600
+        ),
+      /ThresholdUnreachable/
+    );
+  });
+
+  it("error: `valid_until` for a non-member", async () => {
+    await assert.rejects(
+      () => setMemberValidUntil(Keypair.generate().publicKey, null),
+      /NotAMember/
+    );
+  });
+
+  it("expired member can't vote", async () => {
+    // Expire the `voter` membership.
+    await setMemberValidUntil(
+      members.voter.publicKey,
+      Math.floor(Date.now() / 1000) - 1
+    );
+
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    const transactionIndex =
+      multisig.utils.toBigInt(multisigAccount.transactionIndex) + 1n;
+    const [vaultPda] = multisig.getVaultPda({
+      multisigPda,
+      index: 0,
+      programId,
+    });
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+
+    await assert.rejects(
+      () =>
+        sendInstructions(
+          connection,
+          [
+            multisig.instructions.proposalApprove({
+              multisigPda,
+              transactionIndex,
+              member: members.voter.publicKey,
+              programId,
+            }),
+          ],
+          [members.voter]
+        ),
+      /NotAMember/
+    );
+  });
+
+  it("remove expired members", async () => {
+    // Anyone can remove the expired members.
+    const payer = await generateFundedKeypair(connection);
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigRemoveExpiredMembersInstruction(
+          {
+            multisig: multisigPda,
+          },
+          programId
+        ),
+      ],
+      [payer]
+    );
+
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    assert.strictEqual(multisigAccount.members.length, 3);
+    assert.ok(
+      !multisigAccount.members.some((m) =>
+        m.key.equals(members.voter.publicKey)
+      )
+    );
+    // Expired members can't vote anyway, so the transaction created
+    // before the removal stays valid.
+    assert.ok(
+      multisig.utils.toBigInt(multisigAccount.staleTransactionIndex) <
+        multisig.utils.toBigInt(multisigAccount.transactionIndex)
+    );
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.memberSettings.length, 0);
+  });
+});
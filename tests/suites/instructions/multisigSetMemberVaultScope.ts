@@ -0,0 +1,162 @@
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createControlledMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getMultisigExtension,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig } = multisig.accounts;
+const { Permission, Permissions } = multisig.types;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+/** Bitmap of the vault indexes within a vault scope. */
+function vaultIndexesBitmap(vaultIndexes: number[]) {
+  const bitmap = Array(32).fill(0);
+  for (const vaultIndex of vaultIndexes) {
+    bitmap[Math.floor(vaultIndex / 8)] |= 1 << vaultIndex % 8;
+  }
+  return bitmap;
+}
+
+describe("Instructions / multisig_set_member_vault_scope", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let configAuthority: Keypair;
+
+  const setMemberVaultScope = (
+    member: PublicKey,
+    vaultScope: multisig.generated.VaultScope | null
+  ) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigSetMemberVaultScopeInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            rentPayer: configAuthority.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          { args: { member, vaultScope, memo: null } },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+
+  /** Creates a vault transaction for the vault at `vaultIndex` by `proposer`. */
+  const createVaultTransaction = async (vaultIndex: number) => {
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    const [vaultPda] = multisig.getVaultPda({
+      multisigPda,
+      index: vaultIndex,
+      programId,
+    });
+
+    return sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex:
+            multisig.utils.toBigInt(multisigAccount.transactionIndex) + 1n,
+          creator: members.proposer.publicKey,
+          vaultIndex,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+      ],
+      [members.proposer]
+    );
+  };
+
+  before(async () => {
+    configAuthority = await generateFundedKeypair(connection);
+
+    members = await generateMultisigMembers(connection);
+
+    multisigPda = (
+      await createControlledMultisig({
+        connection,
+        createKey: Keypair.generate(),
+        configAuthority: configAuthority.publicKey,
+        members,
+        threshold: 1,
+        timeLock: 0,
+        programId,
+      })
+    )[0];
+  });
+
+  it("restrict the `Initiate` permission of a member to a vault", async () => {
+    await setMemberVaultScope(members.proposer.publicKey, {
+      permissions: Permissions.fromPermissions([Permission.Initiate]),
+      vaultIndexes: vaultIndexesBitmap([1]),
+    });
+
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.memberSettings.length, 1);
+    assert.deepEqual(
+      extension!.memberSettings[0].vaultScope?.vaultIndexes,
+      vaultIndexesBitmap([1])
+    );
+
+    // The member can create transactions for the vault in scope.
+    await createVaultTransaction(1);
+
+    // But not for the other vaults.
+    await assert.rejects(() => createVaultTransaction(0), /VaultOutOfScope/);
+  });
+
+  it("remove the vault scope of a member", async () => {
+    await setMemberVaultScope(members.proposer.publicKey, null);
+
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.memberSettings.length, 0);
+
+    await createVaultTransaction(0);
+  });
+
+  it("error: threshold unreachable for a vault", async () => {
+    // `almighty` and `voter` are the only voters, so the threshold of 1
+    // can't be reached for vault 0 if both can only vote for vault 1.
+    const voteScope = {
+      permissions: Permissions.fromPermissions([Permission.Vote]),
+      vaultIndexes: vaultIndexesBitmap([1]),
+    };
+    await setMemberVaultScope(members.voter.publicKey, voteScope);
+
+    await assert.rejects(
+      () => setMemberVaultScope(members.almighty.publicKey, voteScope),
+      /ThresholdUnreachable/
+    );
+  });
+});
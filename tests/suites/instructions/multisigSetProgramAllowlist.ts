@@ -0,0 +1,151 @@
+import { createMemoInstruction } from "@solana/spl-memo";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  TransactionInstruction,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createControlledMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getMultisigExtension,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig } = multisig.accounts;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / multisig_set_program_allowlist", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let vaultPda: PublicKey;
+  let configAuthority: Keypair;
+
+  const setProgramAllowlist = (
+    programAllowlist: multisig.generated.AllowedProgram[] | null
+  ) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigSetProgramAllowlistInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            rentPayer: configAuthority.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          { args: { programAllowlist, memo: null } },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+
+  const createVaultTransaction = async (
+    instructions: TransactionInstruction[]
+  ) => {
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+
+    return sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex:
+            multisig.utils.toBigInt(multisigAccount.transactionIndex) + 1n,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions,
+          }),
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+  };
+
+  before(async () => {
+    configAuthority = await generateFundedKeypair(connection);
+
+    members = await generateMultisigMembers(connection);
+
+    multisigPda = (
+      await createControlledMultisig({
+        connection,
+        createKey: Keypair.generate(),
+        configAuthority: configAuthority.publicKey,
+        members,
+        threshold: 1,
+        timeLock: 0,
+        programId,
+      })
+    )[0];
+
+    [vaultPda] = multisig.getVaultPda({ multisigPda, index: 0, programId });
+  });
+
+  it("set the program allowlist", async () => {
+    await setProgramAllowlist([
+      {
+        programId: SystemProgram.programId,
+        vaultIndex: null,
+        allowAuthorityChanges: false,
+      },
+    ]);
+
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.programAllowlist?.length, 1);
+    assert.strictEqual(
+      extension!.programAllowlist![0].programId.toBase58(),
+      SystemProgram.programId.toBase58()
+    );
+
+    // Transactions invoking the allowed programs can be created.
+    await createVaultTransaction([
+      createTestTransferInstruction(vaultPda, Keypair.generate().publicKey),
+    ]);
+  });
+
+  it("error: invoking a program not in the allowlist", async () => {
+    await assert.rejects(
+      () => createVaultTransaction([createMemoInstruction("Hello", [])]),
+      /ProgramNotAllowed/
+    );
+  });
+
+  it("error: authority change not allowed", async () => {
+    await assert.rejects(
+      () =>
+        createVaultTransaction([
+          SystemProgram.assign({ accountPubkey: vaultPda, programId }),
+        ]),
+      /AuthorityChangeNotAllowed/
+    );
+  });
+
+  it("unset the program allowlist", async () => {
+    await setProgramAllowlist(null);
+
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.programAllowlist, null);
+
+    await createVaultTransaction([createMemoInstruction("Hello", [])]);
+  });
+});
@@ -0,0 +1,268 @@
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createControlledMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getMultisigExtension,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig, RateLimit } = multisig.accounts;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / multisig_set_rate_limit, multisig_remove_rate_limit", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let vaultPda: PublicKey;
+  let rateLimitPda: PublicKey;
+  let configAuthority: Keypair;
+
+  const setRateLimit = (period: number) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigSetRateLimitInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            rateLimit: rateLimitPda,
+            rentPayer: configAuthority.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          {
+            args: {
+              period,
+              maxExecutions: 1,
+              maxLamports: null,
+              // Executing over the limit takes the approvals of both voters.
+              overLimitThreshold: 2,
+              overLimitTimeLock: null,
+              memo: null,
+            },
+          },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+
+  const changeThreshold = (newThreshold: number) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.instructions.multisigChangeThreshold({
+          multisigPda,
+          configAuthority: configAuthority.publicKey,
+          rentPayer: configAuthority.publicKey,
+          newThreshold,
+          programId,
+        }),
+      ],
+      [configAuthority]
+    );
+
+  /** Creates a vault transaction and approves it by the `approvers`. */
+  const createApprovedTransaction = async (approvers: Keypair[]) => {
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    const transactionIndex =
+      multisig.utils.toBigInt(multisigAccount.transactionIndex) + 1n;
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+    for (const approver of approvers) {
+      await sendInstructions(
+        connection,
+        [
+          multisig.instructions.proposalApprove({
+            multisigPda,
+            transactionIndex,
+            member: approver.publicKey,
+            programId,
+          }),
+        ],
+        [approver]
+      );
+    }
+
+    return transactionIndex;
+  };
+
+  const executeTransaction = async (transactionIndex: bigint) => {
+    const { instruction } = await multisig.instructions.vaultTransactionExecute(
+      {
+        connection,
+        multisigPda,
+        transactionIndex,
+        member: members.executor.publicKey,
+        programId,
+      }
+    );
+
+    return sendInstructions(connection, [instruction], [members.executor]);
+  };
+
+  before(async () => {
+    configAuthority = await generateFundedKeypair(connection);
+
+    members = await generateMultisigMembers(connection);
+
+    multisigPda = (
+      await createControlledMultisig({
+        connection,
+        createKey: Keypair.generate(),
+        configAuthority: configAuthority.publicKey,
+        members,
+        threshold: 1,
+        timeLock: 0,
+        programId,
+      })
+    )[0];
+
+    [vaultPda] = multisig.getVaultPda({ multisigPda, index: 0, programId });
+    [rateLimitPda] = multisig.getRateLimitPda({ multisigPda, programId });
+
+    // Fund the vault.
+    const signature = await connection.requestAirdrop(
+      vaultPda,
+      LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+  });
+
+  it("error: invalid period", async () => {
+    await assert.rejects(() => setRateLimit(0), /RateLimitInvalidPeriod/);
+  });
+
+  it("set the rate limit", async () => {
+    await setRateLimit(3600);
+
+    const rateLimitAccount = await RateLimit.fromAccountAddress(
+      connection,
+      rateLimitPda
+    );
+    assert.strictEqual(
+      rateLimitAccount.multisig.toBase58(),
+      multisigPda.toBase58()
+    );
+    assert.strictEqual(rateLimitAccount.period.toString(), "3600");
+    assert.strictEqual(rateLimitAccount.maxExecutions, 1);
+    assert.strictEqual(rateLimitAccount.overLimitThreshold, 2);
+    assert.strictEqual(rateLimitAccount.executions, 0);
+
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.hasRateLimit, true);
+  });
+
+  it("error: rate limit exceeded", async () => {
+    const firstTransactionIndex = await createApprovedTransaction([
+      members.almighty,
+    ]);
+    await executeTransaction(firstTransactionIndex);
+
+    const rateLimitAccount = await RateLimit.fromAccountAddress(
+      connection,
+      rateLimitPda
+    );
+    assert.strictEqual(rateLimitAccount.executions, 1);
+
+    const secondTransactionIndex = await createApprovedTransaction([
+      members.almighty,
+    ]);
+    await assert.rejects(
+      () => executeTransaction(secondTransactionIndex),
+      /RateLimitExceeded/
+    );
+  });
+
+  it("execute over the limit with more approvals", async () => {
+    // Raise the threshold so that a proposal can collect both approvals.
+    await changeThreshold(2);
+
+    const transactionIndex = await createApprovedTransaction([
+      members.almighty,
+      members.voter,
+    ]);
+    await executeTransaction(transactionIndex);
+
+    const rateLimitAccount = await RateLimit.fromAccountAddress(
+      connection,
+      rateLimitPda
+    );
+    assert.strictEqual(rateLimitAccount.executions, 2);
+  });
+
+  it("remove the rate limit", async () => {
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigRemoveRateLimitInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            rateLimit: rateLimitPda,
+            rentCollector: configAuthority.publicKey,
+          },
+          { args: { memo: null } },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+
+    assert.strictEqual(await connection.getAccountInfo(rateLimitPda), null);
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.hasRateLimit, false);
+
+    // Without the rate limit, the approvals of the threshold are enough again.
+    await changeThreshold(1);
+    const transactionIndex = await createApprovedTransaction([
+      members.almighty,
+    ]);
+    await executeTransaction(transactionIndex);
+  });
+});
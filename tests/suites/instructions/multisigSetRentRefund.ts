@@ -0,0 +1,216 @@
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createControlledMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getMultisigExtension,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig } = multisig.accounts;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / multisig_set_rent_refund", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let vaultPda: PublicKey;
+  let configAuthority: Keypair;
+  let transactionIndex: bigint;
+
+  const setRentRefund = (refundRentToPayers: boolean) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigSetRentRefundInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            rentPayer: configAuthority.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          { args: { refundRentToPayers, memo: null } },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+
+  const closeTransactionAccounts = (rentCollector: PublicKey) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionAccountsClose({
+          multisigPda,
+          rentCollector,
+          transactionIndex,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+
+  before(async () => {
+    configAuthority = await generateFundedKeypair(connection);
+
+    members = await generateMultisigMembers(connection);
+
+    // The multisig has no rent collector.
+    multisigPda = (
+      await createControlledMultisig({
+        connection,
+        createKey: Keypair.generate(),
+        configAuthority: configAuthority.publicKey,
+        members,
+        threshold: 1,
+        timeLock: 0,
+        programId,
+      })
+    )[0];
+
+    [vaultPda] = multisig.getVaultPda({ multisigPda, index: 0, programId });
+
+    // Fund the vault.
+    const signature = await connection.requestAirdrop(
+      vaultPda,
+      LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+
+    // Create and execute a vault transaction, `almighty` pays the rent.
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    transactionIndex =
+      multisig.utils.toBigInt(multisigAccount.transactionIndex) + 1n;
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+        multisig.instructions.proposalApprove({
+          multisigPda,
+          transactionIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+    const { instruction } = await multisig.instructions.vaultTransactionExecute(
+      {
+        connection,
+        multisigPda,
+        transactionIndex,
+        member: members.almighty.publicKey,
+        programId,
+      }
+    );
+    await sendInstructions(connection, [instruction], [members.almighty]);
+  });
+
+  it("error: rent refund disabled", async () => {
+    // Without a rent collector, the rent can't be reclaimed by default.
+    await assert.rejects(
+      () => closeTransactionAccounts(members.almighty.publicKey),
+      /RentReclamationDisabled/
+    );
+  });
+
+  it("refund the rent to the payers", async () => {
+    await setRentRefund(true);
+
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.refundRentToPayers, true);
+
+    // The rent can only go back to the account that paid it.
+    await assert.rejects(
+      () => closeTransactionAccounts(Keypair.generate().publicKey),
+      /InvalidRentCollector/
+    );
+
+    const [transactionPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: transactionIndex,
+      programId,
+    });
+    const [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex,
+      programId,
+    });
+    const transactionRent = (await connection.getAccountInfo(transactionPda))!
+      .lamports;
+    const proposalRent = (await connection.getAccountInfo(proposalPda))!
+      .lamports;
+    const payerBalancePreClose = await connection.getBalance(
+      members.almighty.publicKey
+    );
+
+    // Use another fee payer to isolate the rent payer balance change.
+    const feePayer = await generateFundedKeypair(connection);
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionAccountsClose({
+          multisigPda,
+          rentCollector: members.almighty.publicKey,
+          transactionIndex,
+          programId,
+        }),
+      ],
+      [feePayer]
+    );
+
+    assert.strictEqual(await connection.getAccountInfo(transactionPda), null);
+    assert.strictEqual(await connection.getAccountInfo(proposalPda), null);
+    assert.strictEqual(
+      await connection.getBalance(members.almighty.publicKey),
+      payerBalancePreClose + transactionRent + proposalRent
+    );
+  });
+
+  it("disable the rent refund", async () => {
+    await setRentRefund(false);
+
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(extension!.refundRentToPayers, false);
+  });
+});
@@ -0,0 +1,191 @@
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createControlledMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getMultisigExtension,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig } = multisig.accounts;
+const { TwoPersonRule } = multisig.generated;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / multisig_set_two_person_rule", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let vaultPda: PublicKey;
+  let configAuthority: Keypair;
+
+  const setTwoPersonRule = (twoPersonRule: multisig.generated.TwoPersonRule) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigSetTwoPersonRuleInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            rentPayer: configAuthority.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          { args: { twoPersonRule, memo: null } },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+
+  /** Creates a vault transaction by `creator` and approves it by `almighty`. */
+  const createApprovedTransaction = async (creator: Keypair) => {
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    const transactionIndex =
+      multisig.utils.toBigInt(multisigAccount.transactionIndex) + 1n;
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: creator.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: creator.publicKey,
+          programId,
+        }),
+      ],
+      [creator]
+    );
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.proposalApprove({
+          multisigPda,
+          transactionIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+
+    return transactionIndex;
+  };
+
+  const executeTransaction = async (
+    transactionIndex: bigint,
+    executor: Keypair
+  ) => {
+    const { instruction } = await multisig.instructions.vaultTransactionExecute(
+      {
+        connection,
+        multisigPda,
+        transactionIndex,
+        member: executor.publicKey,
+        programId,
+      }
+    );
+
+    return sendInstructions(connection, [instruction], [executor]);
+  };
+
+  before(async () => {
+    configAuthority = await generateFundedKeypair(connection);
+
+    members = await generateMultisigMembers(connection);
+
+    multisigPda = (
+      await createControlledMultisig({
+        connection,
+        createKey: Keypair.generate(),
+        configAuthority: configAuthority.publicKey,
+        members,
+        threshold: 1,
+        timeLock: 0,
+        programId,
+      })
+    )[0];
+
+    [vaultPda] = multisig.getVaultPda({ multisigPda, index: 0, programId });
+
+    // Fund the vault.
+    const signature = await connection.requestAirdrop(
+      vaultPda,
+      LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+  });
+
+  it("error: executor is the creator", async () => {
+    await setTwoPersonRule(TwoPersonRule.ExecutorNotCreator);
+
+    const extension = await getMultisigExtension(connection, multisigPda);
+    assert.strictEqual(
+      extension!.twoPersonRule,
+      TwoPersonRule.ExecutorNotCreator
+    );
+
+    const transactionIndex = await createApprovedTransaction(members.almighty);
+
+    await assert.rejects(
+      () => executeTransaction(transactionIndex, members.almighty),
+      /ExecutorIsCreator/
+    );
+
+    // Another member can execute it.
+    await executeTransaction(transactionIndex, members.executor);
+  });
+
+  it("error: executor is an approver", async () => {
+    await setTwoPersonRule(TwoPersonRule.ExecutorNotCreatorOrApprover);
+
+    const transactionIndex = await createApprovedTransaction(members.proposer);
+
+    await assert.rejects(
+      () => executeTransaction(transactionIndex, members.almighty),
+      /ExecutorIsApprover/
+    );
+
+    await executeTransaction(transactionIndex, members.executor);
+  });
+
+  it("disable the two-person rule", async () => {
+    await setTwoPersonRule(TwoPersonRule.Disabled);
+
+    const transactionIndex = await createApprovedTransaction(members.almighty);
+
+    await executeTransaction(transactionIndex, members.almighty);
+  });
+});
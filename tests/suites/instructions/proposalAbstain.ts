@@ -0,0 +1,153 @@
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createAutonomousMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateMultisigMembers,
+  getAccountExtension,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Proposal } = multisig.accounts;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / proposal_abstain", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let proposalPda: PublicKey;
+
+  const transactionIndex = 1n;
+
+  const abstain = (member: Keypair) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createProposalAbstainInstruction(
+          {
+            multisig: multisigPda,
+            member: member.publicKey,
+            proposal: proposalPda,
+            systemProgram: SystemProgram.programId,
+          },
+          { args: { memo: null } },
+          programId
+        ),
+      ],
+      [member]
+    );
+
+  const getProposal = async () => {
+    const proposalAccountInfo = await connection.getAccountInfo(proposalPda);
+    const [proposalAccount, offset] = Proposal.fromAccountInfo(
+      proposalAccountInfo!
+    );
+    const proposalExtension = getAccountExtension(
+      proposalAccountInfo!.data,
+      offset,
+      multisig.generated.proposalExtensionBeet
+    );
+
+    return { proposalAccount, proposalExtension };
+  };
+
+  before(async () => {
+    members = await generateMultisigMembers(connection);
+
+    // `almighty` and `voter` are the only voters, so the rejection cutoff is 2.
+    [multisigPda] = await createAutonomousMultisig({
+      connection,
+      members,
+      threshold: 1,
+      timeLock: 0,
+      programId,
+    });
+
+    [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex,
+      programId,
+    });
+    const [vaultPda] = multisig.getVaultPda({
+      multisigPda,
+      index: 0,
+      programId,
+    });
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+  });
+
+  it("error: member without the `Vote` permission", async () => {
+    await assert.rejects(() => abstain(members.proposer), /Unauthorized/);
+  });
+
+  it("abstain from voting on a proposal", async () => {
+    await abstain(members.voter);
+
+    const { proposalAccount, proposalExtension } = await getProposal();
+    assert.ok(multisig.types.isProposalStatusActive(proposalAccount.status));
+    assert.deepEqual(
+      proposalExtension!.abstained.map((key) => key.toBase58()),
+      [members.voter.publicKey.toBase58()]
+    );
+
+    await assert.rejects(() => abstain(members.voter), /AlreadyAbstained/);
+  });
+
+  it("abstentions count towards the rejection cutoff", async () => {
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.proposalReject({
+          multisigPda,
+          transactionIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+
+    const { proposalAccount } = await getProposal();
+    assert.ok(multisig.types.isProposalStatusRejected(proposalAccount.status));
+  });
+});
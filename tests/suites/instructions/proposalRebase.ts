@@ -0,0 +1,195 @@
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createControlledMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getAccountExtension,
+  getMultisigExtension,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Proposal } = multisig.accounts;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / proposal_rebase", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let configAuthority: Keypair;
+  let transactionPda: PublicKey;
+  let proposalPda: PublicKey;
+
+  const transactionIndex = 1n;
+
+  const rebaseProposal = (member: Keypair) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createProposalRebaseInstruction(
+          {
+            multisig: multisigPda,
+            member: member.publicKey,
+            proposal: proposalPda,
+            transaction: transactionPda,
+            systemProgram: SystemProgram.programId,
+          },
+          { args: { memo: null } },
+          programId
+        ),
+      ],
+      [member]
+    );
+
+  const approveProposal = () =>
+    sendInstructions(
+      connection,
+      [
+        multisig.instructions.proposalApprove({
+          multisigPda,
+          transactionIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+
+  before(async () => {
+    configAuthority = await generateFundedKeypair(connection);
+
+    members = await generateMultisigMembers(connection);
+
+    multisigPda = (
+      await createControlledMultisig({
+        connection,
+        createKey: Keypair.generate(),
+        configAuthority: configAuthority.publicKey,
+        members,
+        threshold: 1,
+        timeLock: 0,
+        programId,
+      })
+    )[0];
+
+    [transactionPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: transactionIndex,
+      programId,
+    });
+    [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex,
+      programId,
+    });
+    const [vaultPda] = multisig.getVaultPda({
+      multisigPda,
+      index: 0,
+      programId,
+    });
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+  });
+
+  it("error: proposal not stale", async () => {
+    await assert.rejects(
+      () => rebaseProposal(members.almighty),
+      /ProposalNotStale/
+    );
+  });
+
+  it("rebase a stale proposal", async () => {
+    // A config change makes the proposal stale.
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.multisigSetTimeLock({
+          multisigPda,
+          configAuthority: configAuthority.publicKey,
+          timeLock: 0,
+          programId,
+        }),
+      ],
+      [configAuthority]
+    );
+    await assert.rejects(() => approveProposal(), /StaleProposal/);
+
+    // Rebasing is up to the members who can vote.
+    await assert.rejects(
+      () => rebaseProposal(members.proposer),
+      /Unauthorized/
+    );
+
+    await rebaseProposal(members.almighty);
+
+    const proposalAccountInfo = await connection.getAccountInfo(proposalPda);
+    const [proposalAccount, offset] = Proposal.fromAccountInfo(
+      proposalAccountInfo!
+    );
+    assert.ok(multisig.types.isProposalStatusActive(proposalAccount.status));
+    const proposalExtension = getAccountExtension(
+      proposalAccountInfo!.data,
+      offset,
+      multisig.generated.proposalExtensionBeet
+    );
+    const multisigExtension = await getMultisigExtension(
+      connection,
+      multisigPda
+    );
+    assert.strictEqual(
+      proposalExtension!.rebasedInvalidationCount?.toString(),
+      multisigExtension!.invalidationCount.toString()
+    );
+
+    // The rebased proposal can be voted on again.
+    await approveProposal();
+
+    const approvedProposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      proposalPda
+    );
+    assert.ok(
+      multisig.types.isProposalStatusApproved(approvedProposalAccount.status)
+    );
+  });
+});
@@ -0,0 +1,195 @@
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createAutonomousMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateMultisigMembers,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Proposal } = multisig.accounts;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / proposal_approve_many, proposal_reject_many, proposal_cancel_many", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+
+  const getProposalPda = (transactionIndex: bigint) =>
+    multisig.getProposalPda({ multisigPda, transactionIndex, programId })[0];
+
+  const getProposal = (transactionIndex: bigint) =>
+    Proposal.fromAccountAddress(connection, getProposalPda(transactionIndex));
+
+  /** Casts the vote of `member` on the proposals of the `transactionIndexes`. */
+  const voteMany = (
+    createInstruction:
+      | typeof multisig.generated.createProposalApproveManyInstruction
+      | typeof multisig.generated.createProposalRejectManyInstruction
+      | typeof multisig.generated.createProposalCancelManyInstruction,
+    member: Keypair,
+    transactionIndexes: bigint[],
+    isWritable = true
+  ) =>
+    sendInstructions(
+      connection,
+      [
+        createInstruction(
+          {
+            multisig: multisigPda,
+            member: member.publicKey,
+            systemProgram: SystemProgram.programId,
+            anchorRemainingAccounts: transactionIndexes.map(
+              (transactionIndex) => ({
+                pubkey: getProposalPda(transactionIndex),
+                isSigner: false,
+                isWritable,
+              })
+            ),
+          },
+          { args: { memo: null } },
+          programId
+        ),
+      ],
+      [member]
+    );
+
+  before(async () => {
+    members = await generateMultisigMembers(connection);
+
+    // `almighty` and `voter` are the only voters, so the rejection cutoff is 2.
+    [multisigPda] = await createAutonomousMultisig({
+      connection,
+      members,
+      threshold: 1,
+      timeLock: 0,
+      programId,
+    });
+
+    const [vaultPda] = multisig.getVaultPda({
+      multisigPda,
+      index: 0,
+      programId,
+    });
+
+    // Create 3 transactions with active proposals.
+    for (const transactionIndex of [1n, 2n, 3n]) {
+      await sendInstructions(
+        connection,
+        [
+          multisig.instructions.vaultTransactionCreate({
+            multisigPda,
+            transactionIndex,
+            creator: members.almighty.publicKey,
+            vaultIndex: 0,
+            ephemeralSigners: 0,
+            transactionMessage: new TransactionMessage({
+              payerKey: vaultPda,
+              recentBlockhash: PublicKey.default.toBase58(),
+              instructions: [
+                createTestTransferInstruction(
+                  vaultPda,
+                  Keypair.generate().publicKey
+                ),
+              ],
+            }),
+            programId,
+          }),
+          multisig.instructions.proposalCreate({
+            multisigPda,
+            transactionIndex,
+            creator: members.almighty.publicKey,
+            programId,
+          }),
+        ],
+        [members.almighty]
+      );
+    }
+  });
+
+  it("error: read-only proposal", async () => {
+    await assert.rejects(
+      () =>
+        voteMany(
+          multisig.generated.createProposalApproveManyInstruction,
+          members.almighty,
+          [1n],
+          false
+        ),
+      /InvalidAccount/
+    );
+  });
+
+  it("approve many proposals", async () => {
+    await voteMany(
+      multisig.generated.createProposalApproveManyInstruction,
+      members.almighty,
+      [1n, 2n]
+    );
+
+    for (const transactionIndex of [1n, 2n]) {
+      const proposalAccount = await getProposal(transactionIndex);
+      assert.ok(
+        multisig.types.isProposalStatusApproved(proposalAccount.status)
+      );
+    }
+  });
+
+  it("reject many proposals", async () => {
+    await voteMany(
+      multisig.generated.createProposalRejectManyInstruction,
+      members.voter,
+      [3n]
+    );
+
+    let proposalAccount = await getProposal(3n);
+    assert.ok(multisig.types.isProposalStatusActive(proposalAccount.status));
+
+    await voteMany(
+      multisig.generated.createProposalRejectManyInstruction,
+      members.almighty,
+      [3n]
+    );
+
+    proposalAccount = await getProposal(3n);
+    assert.ok(multisig.types.isProposalStatusRejected(proposalAccount.status));
+  });
+
+  it("cancel many proposals", async () => {
+    await voteMany(
+      multisig.generated.createProposalCancelManyInstruction,
+      members.almighty,
+      [1n, 2n]
+    );
+
+    for (const transactionIndex of [1n, 2n]) {
+      const proposalAccount = await getProposal(transactionIndex);
+      assert.ok(
+        multisig.types.isProposalStatusCancelled(proposalAccount.status)
+      );
+    }
+  });
+
+  it("error: proposal in a wrong status", async () => {
+    // Cancelled proposals can't be approved.
+    await assert.rejects(
+      () =>
+        voteMany(
+          multisig.generated.createProposalApproveManyInstruction,
+          members.almighty,
+          [1n]
+        ),
+      /InvalidProposalStatus/
+    );
+  });
+});
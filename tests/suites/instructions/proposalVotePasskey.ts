@@ -0,0 +1,380 @@
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  TransactionInstruction,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import * as crypto from "crypto";
+import {
+  createControlledMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getAccountExtension,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Proposal } = multisig.accounts;
+const { Permission, Permissions } = multisig.types;
+const { Vote } = multisig.generated;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+const SECP256R1_PROGRAM_ID = new PublicKey(
+  "Secp256r1SigVerify1111111111111111111111111"
+);
+/** secp256r1 curve order, the precompile only accepts low-S signatures. */
+const SECP256R1_ORDER = BigInt(
+  "0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+);
+
+const sha256 = (...data: Uint8Array[]) => {
+  const hash = crypto.createHash("sha256");
+  data.forEach((chunk) => hash.update(chunk));
+  return hash.digest();
+};
+
+const toLeBytes = (value: bigint) => {
+  const bytes = Buffer.alloc(8);
+  bytes.writeBigUInt64LE(value);
+  return bytes;
+};
+
+/** A secp256r1 key pair standing in for the passkey of a member. */
+class TestPasskey {
+  readonly privateKey: crypto.KeyObject;
+  /** Compressed public key. */
+  readonly pubkey: Buffer;
+  /** The member key of the passkey member, see `Passkey::address()`. */
+  readonly address: PublicKey;
+
+  constructor() {
+    const { privateKey, publicKey } = crypto.generateKeyPairSync("ec", {
+      namedCurve: "prime256v1",
+    });
+    const { x, y } = publicKey.export({ format: "jwk" });
+    const yBytes = Buffer.from(y!, "base64url");
+
+    this.privateKey = privateKey;
+    this.pubkey = Buffer.concat([
+      Buffer.from([yBytes[yBytes.length - 1] % 2 === 0 ? 2 : 3]),
+      Buffer.from(x!, "base64url"),
+    ]);
+    this.address = new PublicKey(sha256(Buffer.from("passkey"), this.pubkey));
+  }
+
+  /**
+   * Signs a WebAuthn assertion of the challenge for the vote, returning
+   * the client data JSON and the precompile instruction verifying it.
+   */
+  signVote(
+    multisigPda: PublicKey,
+    transactionIndex: bigint,
+    vote: multisig.generated.Vote,
+    nonce: bigint
+  ) {
+    const challenge = sha256(
+      multisigPda.toBuffer(),
+      toLeBytes(transactionIndex),
+      Buffer.from([vote]),
+      toLeBytes(nonce)
+    );
+    const clientDataJson = Buffer.from(
+      JSON.stringify({
+        type: "webauthn.get",
+        challenge: challenge.toString("base64url"),
+        origin: "https://example.com",
+      })
+    );
+
+    // RP ID hash, "user present" flag and signature counter.
+    const authenticatorData = Buffer.alloc(37);
+    authenticatorData[32] = 0x01;
+    const message = Buffer.concat([authenticatorData, sha256(clientDataJson)]);
+
+    const signature = crypto.sign("sha256", message, {
+      key: this.privateKey,
+      dsaEncoding: "ieee-p1363",
+    });
+    const s = BigInt("0x" + signature.subarray(32).toString("hex"));
+    if (s > SECP256R1_ORDER / 2n) {
+      const lowS = (SECP256R1_ORDER - s).toString(16).padStart(64, "0");
+      Buffer.from(lowS, "hex").copy(signature, 32);
+    }
+
+    // A single signature with everything in the instruction itself.
+    const signatureOffset = 2 + 14;
+    const publicKeyOffset = signatureOffset + 64;
+    const messageDataOffset = publicKeyOffset + 33;
+    const offsets = Buffer.alloc(14);
+    [
+      signatureOffset,
+      0xffff,
+      publicKeyOffset,
+      0xffff,
+      messageDataOffset,
+      message.length,
+      0xffff,
+    ].forEach((offset, index) => offsets.writeUInt16LE(offset, index * 2));
+
+    const verifyInstruction = new TransactionInstruction({
+      programId: SECP256R1_PROGRAM_ID,
+      keys: [],
+      data: Buffer.concat([
+        Buffer.from([1, 0]),
+        offsets,
+        signature,
+        this.pubkey,
+        message,
+      ]),
+    });
+
+    return { clientDataJson, verifyInstruction };
+  }
+}
+
+describe("Instructions / proposal_vote_passkey", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let configAuthority: Keypair;
+  let proposalPda: PublicKey;
+  let passkey: TestPasskey;
+
+  const transactionIndex = 1n;
+
+  const setMemberPasskey = (member: PublicKey, pubkey: Buffer) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createMultisigSetMemberPasskeyInstruction(
+          {
+            multisig: multisigPda,
+            configAuthority: configAuthority.publicKey,
+            rentPayer: configAuthority.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          {
+            args: { member, passkey: [...pubkey], memo: null },
+          },
+          programId
+        ),
+      ],
+      [configAuthority]
+    );
+
+  /** Casts the `vote` of the passkey member signed with `nonce`. */
+  const voteWithPasskey = (
+    vote: multisig.generated.Vote,
+    nonce: bigint,
+    payer: Keypair,
+    withSignature = true
+  ) => {
+    const { clientDataJson, verifyInstruction } = passkey.signVote(
+      multisigPda,
+      transactionIndex,
+      vote,
+      nonce
+    );
+
+    return sendInstructions(
+      connection,
+      [
+        ...(withSignature ? [verifyInstruction] : []),
+        multisig.generated.createProposalVotePasskeyInstruction(
+          {
+            multisig: multisigPda,
+            proposal: proposalPda,
+            payer: payer.publicKey,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            systemProgram: SystemProgram.programId,
+          },
+          {
+            args: {
+              member: passkey.address,
+              vote,
+              clientDataJson,
+              memo: null,
+            },
+          },
+          programId
+        ),
+      ],
+      [payer]
+    );
+  };
+
+  const getProposal = async () => {
+    const proposalAccountInfo = await connection.getAccountInfo(proposalPda);
+    const [proposalAccount, offset] = Proposal.fromAccountInfo(
+      proposalAccountInfo!
+    );
+    const proposalExtension = getAccountExtension(
+      proposalAccountInfo!.data,
+      offset,
+      multisig.generated.proposalExtensionBeet
+    );
+
+    return { proposalAccount, proposalExtension };
+  };
+
+  before(async () => {
+    configAuthority = await generateFundedKeypair(connection);
+
+    members = await generateMultisigMembers(connection);
+
+    // Two of `almighty`, `voter` and the passkey member must approve.
+    multisigPda = (
+      await createControlledMultisig({
+        connection,
+        createKey: Keypair.generate(),
+        configAuthority: configAuthority.publicKey,
+        members,
+        threshold: 2,
+        timeLock: 0,
+        programId,
+      })
+    )[0];
+
+    passkey = new TestPasskey();
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.multisigAddMember({
+          multisigPda,
+          configAuthority: configAuthority.publicKey,
+          rentPayer: configAuthority.publicKey,
+          newMember: {
+            key: passkey.address,
+            permissions: Permissions.fromPermissions([Permission.Vote]),
+          },
+          programId,
+        }),
+      ],
+      [configAuthority]
+    );
+  });
+
+  it("error: passkey not matching the member", async () => {
+    await assert.rejects(
+      () => setMemberPasskey(passkey.address, new TestPasskey().pubkey),
+      /PasskeyAddressMismatch/
+    );
+  });
+
+  it("error: passkey member with other permissions than `Vote`", async () => {
+    const otherPasskey = new TestPasskey();
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.multisigAddMember({
+          multisigPda,
+          configAuthority: configAuthority.publicKey,
+          rentPayer: configAuthority.publicKey,
+          newMember: {
+            key: otherPasskey.address,
+            permissions: Permissions.fromPermissions([
+              Permission.Initiate,
+              Permission.Vote,
+            ]),
+          },
+          programId,
+        }),
+      ],
+      [configAuthority]
+    );
+
+    await assert.rejects(
+      () => setMemberPasskey(otherPasskey.address, otherPasskey.pubkey),
+      /PasskeyMemberNotVoteOnly/
+    );
+  });
+
+  it("set the passkey of a member", async () => {
+    await setMemberPasskey(passkey.address, passkey.pubkey);
+
+    // Setting the passkey invalidates prior transactions,
+    // so the transaction to vote on is created afterwards.
+    [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex,
+      programId,
+    });
+    const [vaultPda] = multisig.getVaultPda({
+      multisigPda,
+      index: 0,
+      programId,
+    });
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+  });
+
+  it("error: missing passkey signature", async () => {
+    await assert.rejects(
+      () => voteWithPasskey(Vote.Approve, 0n, members.almighty, false),
+      /PasskeyMissingSignature/
+    );
+  });
+
+  it("approve with a passkey", async () => {
+    await voteWithPasskey(Vote.Approve, 0n, members.almighty);
+
+    const { proposalAccount, proposalExtension } = await getProposal();
+    assert.ok(multisig.types.isProposalStatusActive(proposalAccount.status));
+    assert.deepEqual(
+      proposalAccount.approved.map((key) => key.toBase58()),
+      [passkey.address.toBase58()]
+    );
+    assert.strictEqual(proposalExtension!.passkeyNonce.toString(), "1");
+  });
+
+  it("error: replayed passkey signature", async () => {
+    // The challenge signed for the previous vote is no longer valid.
+    await assert.rejects(
+      () => voteWithPasskey(Vote.Withdraw, 0n, members.voter),
+      /PasskeyInvalidSignature/
+    );
+
+    await voteWithPasskey(Vote.Withdraw, 1n, members.voter);
+
+    const { proposalAccount, proposalExtension } = await getProposal();
+    assert.strictEqual(proposalAccount.approved.length, 0);
+    assert.strictEqual(proposalExtension!.passkeyNonce.toString(), "2");
+  });
+});
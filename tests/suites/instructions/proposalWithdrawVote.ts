@@ -0,0 +1,164 @@
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createAutonomousMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateMultisigMembers,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Proposal } = multisig.accounts;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / proposal_withdraw_vote", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let proposalPda: PublicKey;
+
+  const transactionIndex = 1n;
+
+  const withdrawVote = (member: Keypair) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createProposalWithdrawVoteInstruction(
+          {
+            multisig: multisigPda,
+            member: member.publicKey,
+            proposal: proposalPda,
+            systemProgram: SystemProgram.programId,
+          },
+          { args: { memo: null } },
+          programId
+        ),
+      ],
+      [member]
+    );
+
+  const approve = (member: Keypair) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.instructions.proposalApprove({
+          multisigPda,
+          transactionIndex,
+          member: member.publicKey,
+          programId,
+        }),
+      ],
+      [member]
+    );
+
+  before(async () => {
+    members = await generateMultisigMembers(connection);
+
+    // Both `almighty` and `voter` must approve.
+    [multisigPda] = await createAutonomousMultisig({
+      connection,
+      members,
+      threshold: 2,
+      timeLock: 0,
+      programId,
+    });
+
+    [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex,
+      programId,
+    });
+    const [vaultPda] = multisig.getVaultPda({
+      multisigPda,
+      index: 0,
+      programId,
+    });
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+  });
+
+  it("withdraw an approval", async () => {
+    await approve(members.voter);
+
+    await withdrawVote(members.voter);
+
+    const proposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      proposalPda
+    );
+    assert.ok(multisig.types.isProposalStatusActive(proposalAccount.status));
+    assert.strictEqual(proposalAccount.approved.length, 0);
+  });
+
+  it("error: no vote to withdraw", async () => {
+    await assert.rejects(
+      () => withdrawVote(members.voter),
+      /NoVoteToWithdraw/
+    );
+  });
+
+  it("withdraw a cancellation", async () => {
+    await approve(members.voter);
+    await approve(members.almighty);
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.proposalCancel({
+          multisigPda,
+          transactionIndex,
+          member: members.voter.publicKey,
+          programId,
+        }),
+      ],
+      [members.voter]
+    );
+
+    await withdrawVote(members.voter);
+
+    const proposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      proposalPda
+    );
+    assert.ok(multisig.types.isProposalStatusApproved(proposalAccount.status));
+    assert.strictEqual(proposalAccount.cancelled.length, 0);
+  });
+});
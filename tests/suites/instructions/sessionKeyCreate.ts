@@ -0,0 +1,272 @@
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createAutonomousMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateMultisigMembers,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Proposal, SessionKey } = multisig.accounts;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+/** `SessionAction::Approve` bit of the session key actions mask. */
+const SESSION_ACTION_APPROVE = 1 << 1;
+
+describe("Instructions / session_key_create, session_key_revoke", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let transactionPda: PublicKey;
+  let proposalPda: PublicKey;
+
+  const transactionIndex = 1n;
+
+  /** Registers `key` as a session key of `voter` that can only approve. */
+  const createSessionKey = (
+    key: PublicKey,
+    expiresAt: number,
+    maxLamportsOutflow: number | null = null
+  ) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createSessionKeyCreateInstruction(
+          {
+            multisig: multisigPda,
+            sessionKey: multisig.getSessionKeyPda({
+              multisigPda,
+              member: members.voter.publicKey,
+              key,
+              programId,
+            })[0],
+            member: members.voter.publicKey,
+          },
+          {
+            args: {
+              key,
+              actions: { mask: SESSION_ACTION_APPROVE },
+              maxLamportsOutflow,
+              expiresAt,
+              memo: null,
+            },
+          },
+          programId
+        ),
+      ],
+      [members.voter]
+    );
+
+  /** Casts the vote with `sessionKey` on behalf of `voter`, paid by `voter`. */
+  const voteWithSessionKey = (
+    createInstruction:
+      | typeof multisig.generated.createProposalApproveV2Instruction
+      | typeof multisig.generated.createProposalRejectV2Instruction,
+    sessionKey: Keypair
+  ) =>
+    sendInstructions(
+      connection,
+      [
+        createInstruction(
+          {
+            multisig: multisigPda,
+            member: sessionKey.publicKey,
+            proposal: proposalPda,
+            transaction: transactionPda,
+            sessionKey: multisig.getSessionKeyPda({
+              multisigPda,
+              member: members.voter.publicKey,
+              key: sessionKey.publicKey,
+              programId,
+            })[0],
+          },
+          { args: { memo: null } },
+          programId
+        ),
+      ],
+      [members.voter, sessionKey]
+    );
+
+  before(async () => {
+    members = await generateMultisigMembers(connection);
+
+    // Both `almighty` and `voter` must approve.
+    [multisigPda] = await createAutonomousMultisig({
+      connection,
+      members,
+      threshold: 2,
+      timeLock: 0,
+      programId,
+    });
+
+    [transactionPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: transactionIndex,
+      programId,
+    });
+    [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex,
+      programId,
+    });
+    const [vaultPda] = multisig.getVaultPda({
+      multisigPda,
+      index: 0,
+      programId,
+    });
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+  });
+
+  it("error: already expired", async () => {
+    const now = Math.floor(Date.now() / 1000);
+
+    await assert.rejects(
+      () => createSessionKey(Keypair.generate().publicKey, now - 1000),
+      /SessionKeyExpired/
+    );
+  });
+
+  it("error: lamports limit without balance guards", async () => {
+    const sessionKey = Keypair.generate();
+    const now = Math.floor(Date.now() / 1000);
+    await createSessionKey(sessionKey.publicKey, now + 3600, 1_000_000);
+
+    // The transaction doesn't limit what it moves out of the vault.
+    await assert.rejects(
+      () =>
+        voteWithSessionKey(
+          multisig.generated.createProposalApproveV2Instruction,
+          sessionKey
+        ),
+      /SessionKeyOutOfScope/
+    );
+  });
+
+  it("vote with a session key", async () => {
+    const sessionKey = Keypair.generate();
+    const now = Math.floor(Date.now() / 1000);
+    await createSessionKey(sessionKey.publicKey, now + 3600);
+
+    const sessionKeyAccount = await SessionKey.fromAccountAddress(
+      connection,
+      multisig.getSessionKeyPda({
+        multisigPda,
+        member: members.voter.publicKey,
+        key: sessionKey.publicKey,
+        programId,
+      })[0]
+    );
+    assert.strictEqual(
+      sessionKeyAccount.member.toBase58(),
+      members.voter.publicKey.toBase58()
+    );
+    assert.strictEqual(sessionKeyAccount.actions.mask, SESSION_ACTION_APPROVE);
+
+    // The session key can only approve.
+    await assert.rejects(
+      () =>
+        voteWithSessionKey(
+          multisig.generated.createProposalRejectV2Instruction,
+          sessionKey
+        ),
+      /SessionKeyOutOfScope/
+    );
+
+    await voteWithSessionKey(
+      multisig.generated.createProposalApproveV2Instruction,
+      sessionKey
+    );
+
+    // The approval is recorded for the member, not the session key.
+    const proposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      proposalPda
+    );
+    assert.ok(multisig.types.isProposalStatusActive(proposalAccount.status));
+    assert.deepEqual(
+      proposalAccount.approved.map((key) => key.toBase58()),
+      [members.voter.publicKey.toBase58()]
+    );
+  });
+
+  it("revoke a session key", async () => {
+    const sessionKey = Keypair.generate();
+    const now = Math.floor(Date.now() / 1000);
+    await createSessionKey(sessionKey.publicKey, now + 3600);
+
+    const [sessionKeyPda] = multisig.getSessionKeyPda({
+      multisigPda,
+      member: members.voter.publicKey,
+      key: sessionKey.publicKey,
+      programId,
+    });
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createSessionKeyRevokeInstruction(
+          {
+            multisig: multisigPda,
+            sessionKey: sessionKeyPda,
+            member: members.voter.publicKey,
+          },
+          { args: { memo: null } },
+          programId
+        ),
+      ],
+      [members.voter]
+    );
+
+    assert.strictEqual(await connection.getAccountInfo(sessionKeyPda), null);
+
+    // The revoked session key can no longer vote.
+    await assert.rejects(
+      () =>
+        voteWithSessionKey(
+          multisig.generated.createProposalApproveV2Instruction,
+          sessionKey
+        ),
+      /AccountNotInitialized/
+    );
+  });
+});
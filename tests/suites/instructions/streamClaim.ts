@@ -0,0 +1,280 @@
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createAutonomousMultisig,
+  createLocalhostConnection,
+  generateFundedKeypair,
+  generateMultisigMembers,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig, Stream } = multisig.accounts;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / stream_claim", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let vaultPda: PublicKey;
+  let recipient: Keypair;
+  // Has fully vested already.
+  let vestedStreamPda: PublicKey;
+  // Vests between a while ago and a while from now.
+  let vestingStreamPda: PublicKey;
+  // Doesn't vest anything until a while from now.
+  let pendingStreamPda: PublicKey;
+
+  /** Creates, approves and executes a config transaction with the `actions`. */
+  const executeConfigTransaction = async (
+    actions: multisig.types.ConfigAction[],
+    streams: PublicKey[]
+  ) => {
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    const transactionIndex =
+      multisig.utils.toBigInt(multisigAccount.transactionIndex) + 1n;
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.configTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          actions,
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+        multisig.instructions.proposalApprove({
+          multisigPda,
+          transactionIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+
+    const [transactionPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: transactionIndex,
+      programId,
+    });
+    const [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex,
+      programId,
+    });
+
+    return sendInstructions(
+      connection,
+      [
+        multisig.generated.createConfigTransactionExecuteInstruction(
+          {
+            multisig: multisigPda,
+            member: members.almighty.publicKey,
+            proposal: proposalPda,
+            transaction: transactionPda,
+            rentPayer: members.almighty.publicKey,
+            systemProgram: SystemProgram.programId,
+            anchorRemainingAccounts: streams.map((stream) => ({
+              pubkey: stream,
+              isWritable: true,
+              isSigner: false,
+            })),
+          },
+          programId
+        ),
+      ],
+      [members.almighty]
+    );
+  };
+
+  const addStream = (startTime: number, endTime: number) => {
+    const createKey = Keypair.generate().publicKey;
+    const [streamPda] = multisig.getStreamPda({
+      multisigPda,
+      createKey,
+      programId,
+    });
+    const action: multisig.types.ConfigAction = {
+      __kind: "AddStream",
+      createKey,
+      vaultIndex: 0,
+      recipient: recipient.publicKey,
+      // SOL.
+      mint: PublicKey.default,
+      totalAmount: LAMPORTS_PER_SOL,
+      startTime,
+      endTime,
+      cliffTime: null,
+    };
+
+    return { streamPda, action };
+  };
+
+  /** Claims from the stream on behalf of `claimer`, paid by `almighty`. */
+  const claim = (streamPda: PublicKey, claimer: Keypair) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createStreamClaimInstruction(
+          {
+            multisig: multisigPda,
+            recipient: claimer.publicKey,
+            stream: streamPda,
+            vault: vaultPda,
+            systemProgram: SystemProgram.programId,
+          },
+          { args: { decimals: 9, memo: null } },
+          programId
+        ),
+      ],
+      [members.almighty, claimer]
+    );
+
+  before(async () => {
+    members = await generateMultisigMembers(connection);
+    recipient = await generateFundedKeypair(connection);
+
+    [multisigPda] = await createAutonomousMultisig({
+      connection,
+      members,
+      threshold: 1,
+      timeLock: 0,
+      programId,
+    });
+
+    [vaultPda] = multisig.getVaultPda({ multisigPda, index: 0, programId });
+
+    // Fund the vault.
+    const signature = await connection.requestAirdrop(
+      vaultPda,
+      3 * LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+  });
+
+  it("error: invalid schedule", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const { streamPda, action } = addStream(now, now);
+
+    await assert.rejects(
+      () => executeConfigTransaction([action], [streamPda]),
+      /StreamInvalidSchedule/
+    );
+  });
+
+  it("add streams", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    const vestedStream = addStream(now - 2000, now - 1000);
+    const vestingStream = addStream(now - 1000, now + 1000);
+    const pendingStream = addStream(now + 1000, now + 2000);
+    vestedStreamPda = vestedStream.streamPda;
+    vestingStreamPda = vestingStream.streamPda;
+    pendingStreamPda = pendingStream.streamPda;
+
+    await executeConfigTransaction(
+      [vestedStream.action, vestingStream.action, pendingStream.action],
+      [vestedStreamPda, vestingStreamPda, pendingStreamPda]
+    );
+
+    const streamAccount = await Stream.fromAccountAddress(
+      connection,
+      vestedStreamPda
+    );
+    assert.strictEqual(
+      streamAccount.recipient.toBase58(),
+      recipient.publicKey.toBase58()
+    );
+    assert.strictEqual(
+      streamAccount.totalAmount.toString(),
+      LAMPORTS_PER_SOL.toString()
+    );
+    assert.strictEqual(streamAccount.claimedAmount.toString(), "0");
+  });
+
+  it("error: claimed by someone else than the recipient", async () => {
+    await assert.rejects(
+      () => claim(vestedStreamPda, members.voter),
+      /Unauthorized/
+    );
+  });
+
+  it("error: nothing vested yet", async () => {
+    await assert.rejects(
+      () => claim(pendingStreamPda, recipient),
+      /StreamNothingToClaim/
+    );
+  });
+
+  it("claim the vested amount", async () => {
+    const recipientBalanceBefore = await connection.getBalance(
+      recipient.publicKey
+    );
+
+    await claim(vestedStreamPda, recipient);
+
+    const streamAccount = await Stream.fromAccountAddress(
+      connection,
+      vestedStreamPda
+    );
+    assert.strictEqual(
+      streamAccount.claimedAmount.toString(),
+      LAMPORTS_PER_SOL.toString()
+    );
+    assert.strictEqual(
+      await connection.getBalance(recipient.publicKey),
+      recipientBalanceBefore + LAMPORTS_PER_SOL
+    );
+
+    await assert.rejects(
+      () => claim(vestedStreamPda, recipient),
+      /StreamNothingToClaim/
+    );
+  });
+
+  it("cancel the streams", async () => {
+    const streamPdas = [vestedStreamPda, vestingStreamPda, pendingStreamPda];
+    await executeConfigTransaction(
+      streamPdas.map(
+        (stream): multisig.types.ConfigAction => ({
+          __kind: "CancelStream",
+          stream,
+        })
+      ),
+      streamPdas
+    );
+
+    // Nothing is owed on the fully claimed and the pending streams,
+    // so they are closed right away.
+    assert.strictEqual(await connection.getAccountInfo(vestedStreamPda), null);
+    assert.strictEqual(await connection.getAccountInfo(pendingStreamPda), null);
+
+    // The vesting stream stops, only what's vested so far stays claimable.
+    const streamAccount = await Stream.fromAccountAddress(
+      connection,
+      vestingStreamPda
+    );
+    const totalAmount = Number(streamAccount.totalAmount);
+    assert.ok(totalAmount > 0);
+    assert.ok(totalAmount < LAMPORTS_PER_SOL);
+    assert.strictEqual(streamAccount.claimedAmount.toString(), "0");
+  });
+});
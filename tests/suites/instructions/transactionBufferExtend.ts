@@ -22,6 +22,7 @@ import {
   createTestTransferInstruction,
   generateMultisigMembers,
   getTestProgramId,
+  sendInstructions,
 } from "../../utils";
 
 const programId = getTestProgramId();
@@ -276,6 +277,8 @@ describe("Instructions / transaction_buffer_extend", () => {
           multisig: multisigPda,
           transactionBuffer,
           creator: members.proposer.publicKey,
+          rentPayer: members.proposer.publicKey,
+          systemProgram: SystemProgram.programId,
         },
         {
           args: {
@@ -335,6 +338,8 @@ describe("Instructions / transaction_buffer_extend", () => {
         multisig: multisigPda,
         transactionBuffer,
         creator: nonMember.publicKey,
+        rentPayer: nonMember.publicKey,
+        systemProgram: SystemProgram.programId,
       },
       {
         args: {
@@ -373,6 +378,8 @@ describe("Instructions / transaction_buffer_extend", () => {
         multisig: multisigPda,
         transactionBuffer,
         creator: members.almighty.publicKey,
+        rentPayer: members.almighty.publicKey,
+        systemProgram: SystemProgram.programId,
       },
       {
         args: {
@@ -411,6 +418,8 @@ describe("Instructions / transaction_buffer_extend", () => {
         multisig: multisigPda,
         transactionBuffer,
         creator: members.almighty.publicKey,
+        rentPayer: members.almighty.publicKey,
+        systemProgram: SystemProgram.programId,
       },
       {
         args: {
@@ -438,4 +447,213 @@ describe("Instructions / transaction_buffer_extend", () => {
     await closeTransactionBuffer(members.proposer, transactionBuffer);
   });
 
-});
\ No newline at end of file
+  it("co-writer extends the buffer and creates the transaction from it", async () => {
+    const bufferIndex = 2;
+
+    const [transactionBuffer] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("multisig"),
+        multisigPda.toBuffer(),
+        Buffer.from("transaction_buffer"),
+        members.proposer.publicKey.toBuffer(),
+        Uint8Array.from([bufferIndex]),
+      ],
+      programId
+    );
+
+    const messageBuffer =
+      multisig.utils.transactionMessageToMultisigTransactionMessageBytes({
+        message: new TransactionMessage({
+          payerKey: vaultPda,
+          recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+          instructions: Array(10)
+            .fill(null)
+            .map(() =>
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              )
+            ),
+        }),
+        addressLookupTableAccounts: [],
+        vaultPda,
+      });
+    const messageHash = crypto
+      .createHash("sha256")
+      .update(messageBuffer)
+      .digest();
+
+    // `proposer` creates the buffer, and adds `almighty` as a co-writer.
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createTransactionBufferCreateInstruction(
+          {
+            multisig: multisigPda,
+            transactionBuffer,
+            creator: members.proposer.publicKey,
+            rentPayer: members.proposer.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          {
+            args: {
+              bufferIndex,
+              vaultIndex: 0,
+              finalBufferHash: Array.from(messageHash),
+              finalBufferSize: messageBuffer.length,
+              buffer: messageBuffer.slice(0, 200),
+            } as TransactionBufferCreateArgs,
+          } as TransactionBufferCreateInstructionArgs,
+          programId
+        ),
+        multisig.generated.createTransactionBufferAddCoWritersInstruction(
+          {
+            multisig: multisigPda,
+            transactionBuffer,
+            creator: members.proposer.publicKey,
+            rentPayer: members.proposer.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          { args: { coWriters: [members.almighty.publicKey] } },
+          programId
+        ),
+      ],
+      [members.proposer]
+    );
+    const bufferRent = (await connection.getAccountInfo(transactionBuffer))!
+      .lamports;
+
+    // `almighty` extends it.
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createTransactionBufferExtendInstruction(
+          {
+            multisig: multisigPda,
+            transactionBuffer,
+            creator: members.almighty.publicKey,
+            rentPayer: members.almighty.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          {
+            args: {
+              buffer: messageBuffer.slice(200),
+            } as TransactionBufferExtendArgs,
+          } as TransactionBufferExtendInstructionArgs,
+          programId
+        ),
+      ],
+      [members.almighty]
+    );
+
+    // And creates the transaction from it.
+    const multisigAccount = await multisig.accounts.Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    const [transactionPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: multisig.utils.toBigInt(multisigAccount.transactionIndex) + 1n,
+      programId,
+    });
+    const proposerBalance = await connection.getBalance(
+      members.proposer.publicKey
+    );
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createVaultTransactionCreateFromBufferInstruction(
+          {
+            vaultTransactionCreateItemMultisig: multisigPda,
+            vaultTransactionCreateItemTransaction: transactionPda,
+            vaultTransactionCreateItemCreator: members.almighty.publicKey,
+            vaultTransactionCreateItemRentPayer: members.almighty.publicKey,
+            vaultTransactionCreateItemSystemProgram: SystemProgram.programId,
+            creator: members.almighty.publicKey,
+            transactionBuffer,
+            bufferCreator: members.proposer.publicKey,
+          },
+          {
+            args: {
+              vaultIndex: 0,
+              ephemeralSigners: 0,
+              transactionMessage: new Uint8Array(6).fill(0),
+              memo: null,
+            },
+          },
+          programId
+        ),
+      ],
+      [members.almighty]
+    );
+
+    const transactionAccount =
+      await multisig.accounts.VaultTransaction.fromAccountAddress(
+        connection,
+        transactionPda
+      );
+    assert.equal(transactionAccount.message.instructions.length, 10);
+
+    // The buffer rent goes back to its creator.
+    assert.equal(await connection.getAccountInfo(transactionBuffer), null);
+    assert.equal(
+      await connection.getBalance(members.proposer.publicKey),
+      proposerBalance + bufferRent
+    );
+  });
+
+  it("error: co-writer without initiate permissions", async () => {
+    const transactionBuffer = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("multisig"),
+        multisigPda.toBuffer(),
+        Buffer.from("transaction_buffer"),
+        members.proposer.publicKey.toBuffer(),
+        Uint8Array.from([3]),
+      ],
+      programId
+    )[0];
+
+    await assert.rejects(
+      () =>
+        sendInstructions(
+          connection,
+          [
+            multisig.generated.createTransactionBufferCreateInstruction(
+              {
+                multisig: multisigPda,
+                transactionBuffer,
+                creator: members.proposer.publicKey,
+                rentPayer: members.proposer.publicKey,
+                systemProgram: SystemProgram.programId,
+              },
+              {
+                args: {
+                  bufferIndex: 3,
+                  vaultIndex: 0,
+                  finalBufferHash: Array(32).fill(0),
+                  finalBufferSize: 100,
+                  buffer: Buffer.alloc(100),
+                } as TransactionBufferCreateArgs,
+              } as TransactionBufferCreateInstructionArgs,
+              programId
+            ),
+            multisig.generated.createTransactionBufferAddCoWritersInstruction(
+              {
+                multisig: multisigPda,
+                transactionBuffer,
+                creator: members.proposer.publicKey,
+                rentPayer: members.proposer.publicKey,
+                systemProgram: SystemProgram.programId,
+              },
+              { args: { coWriters: [members.voter.publicKey] } },
+              programId
+            ),
+          ],
+          [members.proposer]
+        ),
+      /Unauthorized/
+    );
+  });
+});
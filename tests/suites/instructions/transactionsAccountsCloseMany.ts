@@ -0,0 +1,206 @@
+import {
+  AccountMeta,
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createAutonomousMultisigV2,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateMultisigMembers,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig } = multisig.accounts;
+const { toBigInt } = multisig.utils;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / transactions_accounts_close_many", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let vaultPda: PublicKey;
+  let rentCollector: PublicKey;
+  let executedTransactionIndexes: bigint[];
+  let activeTransactionIndex: bigint;
+
+  /** Creates a vault transaction with a proposal, approved by `almighty` if `approve` is set. */
+  const createTransaction = async (approve: boolean) => {
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    const transactionIndex = toBigInt(multisigAccount.transactionIndex) + 1n;
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions: [
+              createTestTransferInstruction(
+                vaultPda,
+                Keypair.generate().publicKey
+              ),
+            ],
+          }),
+          programId,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+        ...(approve
+          ? [
+              multisig.instructions.proposalApprove({
+                multisigPda,
+                transactionIndex,
+                member: members.almighty.publicKey,
+                programId,
+              }),
+            ]
+          : []),
+      ],
+      [members.almighty]
+    );
+
+    return transactionIndex;
+  };
+
+  /** The `Proposal` and `VaultTransaction` accounts of each transaction. */
+  const transactionsAccounts = (transactionIndexes: bigint[]) =>
+    transactionIndexes.flatMap((transactionIndex): AccountMeta[] => [
+      {
+        pubkey: multisig.getProposalPda({
+          multisigPda,
+          transactionIndex,
+          programId,
+        })[0],
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: multisig.getTransactionPda({
+          multisigPda,
+          index: transactionIndex,
+          programId,
+        })[0],
+        isSigner: false,
+        isWritable: true,
+      },
+    ]);
+
+  const closeTransactionsAccounts = (accounts: AccountMeta[]) =>
+    sendInstructions(
+      connection,
+      [
+        multisig.generated.createTransactionsAccountsCloseManyInstruction(
+          {
+            multisig: multisigPda,
+            rentCollector,
+            systemProgram: SystemProgram.programId,
+            anchorRemainingAccounts: accounts,
+          },
+          programId
+        ),
+      ],
+      [members.almighty]
+    );
+
+  before(async () => {
+    members = await generateMultisigMembers(connection);
+
+    rentCollector = Keypair.generate().publicKey;
+
+    [multisigPda] = await createAutonomousMultisigV2({
+      connection,
+      members,
+      threshold: 1,
+      timeLock: 0,
+      rentCollector,
+      programId,
+    });
+
+    [vaultPda] = multisig.getVaultPda({ multisigPda, index: 0, programId });
+
+    // Fund the vault.
+    const signature = await connection.requestAirdrop(
+      vaultPda,
+      LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+
+    // Two executed transactions and an active one.
+    executedTransactionIndexes = [];
+    for (let i = 0; i < 2; i++) {
+      const transactionIndex = await createTransaction(true);
+      const { instruction } =
+        await multisig.instructions.vaultTransactionExecute({
+          connection,
+          multisigPda,
+          transactionIndex,
+          member: members.almighty.publicKey,
+          programId,
+        });
+      await sendInstructions(connection, [instruction], [members.almighty]);
+      executedTransactionIndexes.push(transactionIndex);
+    }
+    activeTransactionIndex = await createTransaction(false);
+  });
+
+  it("error: odd number of accounts", async () => {
+    await assert.rejects(
+      () =>
+        closeTransactionsAccounts(
+          transactionsAccounts(executedTransactionIndexes).slice(0, 3)
+        ),
+      /InvalidNumberOfAccounts/
+    );
+  });
+
+  it("error: transaction that can't be closed yet", async () => {
+    // The active transaction can still be approved and executed.
+    await assert.rejects(
+      () =>
+        closeTransactionsAccounts(
+          transactionsAccounts([
+            ...executedTransactionIndexes,
+            activeTransactionIndex,
+          ])
+        ),
+      /InvalidProposalStatus/
+    );
+  });
+
+  it("close many transactions at once", async () => {
+    const accounts = transactionsAccounts(executedTransactionIndexes);
+    let rent = 0;
+    for (const { pubkey } of accounts) {
+      rent += (await connection.getAccountInfo(pubkey))!.lamports;
+    }
+
+    await closeTransactionsAccounts(accounts);
+
+    for (const { pubkey } of accounts) {
+      assert.strictEqual(await connection.getAccountInfo(pubkey), null);
+    }
+    assert.strictEqual(await connection.getBalance(rentCollector), rent);
+  });
+});
@@ -28,6 +28,8 @@ import {
   generateMultisigMembers,
   getLogs,
   getTestProgramId,
+  processBufferInChunks,
+  sendInstructions,
 } from "../../utils";
 
 const programId = getTestProgramId();
@@ -190,6 +192,8 @@ describe("Instructions / vault_transaction_create_from_buffer", () => {
           multisig: multisigPda,
           transactionBuffer,
           creator: members.proposer.publicKey,
+          rentPayer: members.proposer.publicKey,
+          systemProgram: SystemProgram.programId,
         },
         {
           args: {
@@ -599,6 +603,8 @@ describe("Instructions / vault_transaction_create_from_buffer", () => {
           multisig: multisigPda,
           transactionBuffer,
           creator: members.proposer.publicKey,
+          rentPayer: members.proposer.publicKey,
+          systemProgram: SystemProgram.programId,
         },
         {
           args: {
@@ -673,4 +679,167 @@ describe("Instructions / vault_transaction_create_from_buffer", () => {
     );
     assert.equal(transactionInfo.message.instructions.length, 45);
   });
+
+  it("create from a buffer larger than a single allocation", async () => {
+    const multisigAccount = await multisig.accounts.Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    const transactionIndex =
+      multisig.utils.toBigInt(multisigAccount.transactionIndex) + 1n;
+    const bufferIndex = 2;
+
+    // 70 instructions with 200 bytes of data make a message of about 14.5 KiB,
+    // more than an account can be allocated within a single instruction.
+    const instructions = Array(70)
+      .fill(null)
+      .map(() => ({
+        programId: SystemProgram.programId,
+        keys: [{ pubkey: vaultPda, isSigner: false, isWritable: true }],
+        data: crypto.randomBytes(200),
+      }));
+
+    const messageBuffer =
+      multisig.utils.transactionMessageToMultisigTransactionMessageBytes({
+        message: new TransactionMessage({
+          payerKey: vaultPda,
+          recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+          instructions,
+        }),
+        addressLookupTableAccounts: [],
+        vaultPda,
+      });
+    assert.ok(messageBuffer.length > 10128);
+
+    const [transactionBuffer] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("multisig"),
+        multisigPda.toBuffer(),
+        Buffer.from("transaction_buffer"),
+        members.proposer.publicKey.toBuffer(),
+        Uint8Array.from([bufferIndex]),
+      ],
+      programId
+    );
+    const [transactionPda] = multisig.getTransactionPda({
+      multisigPda,
+      index: transactionIndex,
+      programId,
+    });
+
+    const messageHash = crypto
+      .createHash("sha256")
+      .update(messageBuffer)
+      .digest();
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.generated.createTransactionBufferCreateInstruction(
+          {
+            multisig: multisigPda,
+            transactionBuffer,
+            creator: members.proposer.publicKey,
+            rentPayer: members.proposer.publicKey,
+            systemProgram: SystemProgram.programId,
+          },
+          {
+            args: {
+              bufferIndex,
+              vaultIndex: 0,
+              finalBufferHash: Array.from(messageHash),
+              finalBufferSize: messageBuffer.length,
+              buffer: messageBuffer.slice(0, 900),
+            } as TransactionBufferCreateArgs,
+          } as TransactionBufferCreateInstructionArgs,
+          programId
+        ),
+      ],
+      [members.proposer]
+    );
+
+    // The buffer grows past its initial allocation as it's extended.
+    await processBufferInChunks(
+      members.proposer,
+      multisigPda,
+      transactionBuffer,
+      messageBuffer,
+      connection,
+      programId,
+      900,
+      900
+    );
+    const bufferAccount = await connection.getAccountInfo(transactionBuffer);
+    const [bufferData] =
+      multisig.generated.TransactionBuffer.fromAccountInfo(bufferAccount!);
+    assert.equal(bufferData.buffer.length, messageBuffer.length);
+
+    const requestHeapIx = ComputeBudgetProgram.requestHeapFrame({
+      bytes: 262144,
+    });
+
+    // The transaction is created with the message pending.
+    await sendInstructions(
+      connection,
+      [
+        requestHeapIx,
+        multisig.generated.createVaultTransactionCreateFromBufferInstruction(
+          {
+            vaultTransactionCreateItemMultisig: multisigPda,
+            vaultTransactionCreateItemTransaction: transactionPda,
+            vaultTransactionCreateItemCreator: members.proposer.publicKey,
+            vaultTransactionCreateItemRentPayer: members.proposer.publicKey,
+            vaultTransactionCreateItemSystemProgram: SystemProgram.programId,
+            creator: members.proposer.publicKey,
+            transactionBuffer,
+          },
+          {
+            args: {
+              vaultIndex: 0,
+              ephemeralSigners: 0,
+              transactionMessage: new Uint8Array(6).fill(0),
+              memo: null,
+            } as VaultTransactionCreateArgs,
+          } as VaultTransactionCreateFromBufferInstructionArgs,
+          programId
+        ),
+      ],
+      [members.proposer]
+    );
+    assert.notEqual(await connection.getAccountInfo(transactionBuffer), null);
+
+    // Grow the transaction until the message is moved in and the buffer is closed.
+    for (let i = 0; i < 3; i++) {
+      await sendInstructions(
+        connection,
+        [
+          requestHeapIx,
+          multisig.generated.createVaultTransactionExtendFromBufferInstruction(
+            {
+              multisig: multisigPda,
+              transaction: transactionPda,
+              transactionBuffer,
+              creator: members.proposer.publicKey,
+              rentPayer: members.proposer.publicKey,
+              systemProgram: SystemProgram.programId,
+            },
+            programId
+          ),
+        ],
+        [members.proposer]
+      );
+
+      if ((await connection.getAccountInfo(transactionBuffer)) === null) {
+        break;
+      }
+    }
+    assert.equal(await connection.getAccountInfo(transactionBuffer), null);
+
+    const transactionInfo =
+      await multisig.accounts.VaultTransaction.fromAccountAddress(
+        connection,
+        transactionPda
+      );
+    assert.equal(transactionInfo.message.instructions.length, 70);
+  });
 });
@@ -0,0 +1,405 @@
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  TransactionInstruction,
+  TransactionMessage,
+} from "@solana/web3.js";
+import * as multisig from "@sqds/multisig";
+import assert from "assert";
+import {
+  createAutonomousMultisig,
+  createLocalhostConnection,
+  createTestTransferInstruction,
+  generateMultisigMembers,
+  getTestProgramId,
+  sendInstructions,
+  TestMembers,
+} from "../../utils";
+
+const { Multisig, Proposal } = multisig.accounts;
+const { toBigInt } = multisig.utils;
+
+const programId = getTestProgramId();
+const connection = createLocalhostConnection();
+
+describe("Instructions / vault_transaction_execute", () => {
+  let members: TestMembers;
+  let multisigPda: PublicKey;
+  let vaultPda: PublicKey;
+
+  const getNextTransactionIndex = async () => {
+    const multisigAccount = await Multisig.fromAccountAddress(
+      connection,
+      multisigPda
+    );
+    return toBigInt(multisigAccount.transactionIndex) + 1n;
+  };
+
+  /** Creates a vault transaction with `instructions` and approves it by `almighty`. */
+  const createApprovedTransaction = async (
+    instructions: TransactionInstruction[],
+    options: Partial<
+      Parameters<typeof multisig.instructions.vaultTransactionCreateV2>[0]
+    > = {}
+  ) => {
+    const transactionIndex = await getNextTransactionIndex();
+
+    await sendInstructions(
+      connection,
+      [
+        multisig.instructions.vaultTransactionCreateV2({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          vaultIndex: 0,
+          ephemeralSigners: 0,
+          transactionMessage: new TransactionMessage({
+            payerKey: vaultPda,
+            recentBlockhash: PublicKey.default.toBase58(),
+            instructions,
+          }),
+          programId,
+          ...options,
+        }),
+        multisig.instructions.proposalCreate({
+          multisigPda,
+          transactionIndex,
+          creator: members.almighty.publicKey,
+          programId,
+        }),
+        multisig.instructions.proposalApprove({
+          multisigPda,
+          transactionIndex,
+          member: members.almighty.publicKey,
+          programId,
+        }),
+      ],
+      [members.almighty]
+    );
+
+    return transactionIndex;
+  };
+
+  /** Executes the transaction, passing `extraAccounts` after the message accounts. */
+  const executeTransaction = async (
+    transactionIndex: bigint,
+    extraAccounts: PublicKey[] = []
+  ) => {
+    const { instruction } = await multisig.instructions.vaultTransactionExecute(
+      {
+        connection,
+        multisigPda,
+        transactionIndex,
+        member: members.almighty.publicKey,
+        programId,
+      }
+    );
+    instruction.keys.push(
+      ...extraAccounts.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: false,
+      }))
+    );
+
+    return sendInstructions(connection, [instruction], [members.almighty]);
+  };
+
+  /** Executes the next `numInstructions` instructions of the transaction. */
+  const executeTransactionPartially = async (
+    transactionIndex: bigint,
+    numInstructions: number
+  ) => {
+    const { instruction } = await multisig.instructions.vaultTransactionExecute(
+      {
+        connection,
+        multisigPda,
+        transactionIndex,
+        member: members.almighty.publicKey,
+        programId,
+      }
+    );
+    // Partial execution takes the same accounts as the full one.
+    const [
+      multisigMeta,
+      proposalMeta,
+      transactionMeta,
+      memberMeta,
+      ...accounts
+    ] = instruction.keys;
+
+    return sendInstructions(
+      connection,
+      [
+        multisig.generated.createVaultTransactionExecutePartialInstruction(
+          {
+            multisig: multisigMeta.pubkey,
+            proposal: proposalMeta.pubkey,
+            transaction: transactionMeta.pubkey,
+            member: memberMeta.pubkey,
+            anchorRemainingAccounts: accounts,
+          },
+          { args: { numInstructions } },
+          programId
+        ),
+      ],
+      [members.almighty]
+    );
+  };
+
+  before(async () => {
+    members = await generateMultisigMembers(connection);
+
+    [multisigPda] = await createAutonomousMultisig({
+      connection,
+      members,
+      threshold: 1,
+      timeLock: 0,
+      programId,
+    });
+
+    [vaultPda] = multisig.getVaultPda({ multisigPda, index: 0, programId });
+
+    // Fund the vault.
+    const signature = await connection.requestAirdrop(
+      vaultPda,
+      10 * LAMPORTS_PER_SOL
+    );
+    await connection.confirmTransaction(signature);
+  });
+
+  it("execute a transfer within the balance guards", async () => {
+    const recipient = Keypair.generate().publicKey;
+
+    const transactionIndex = await createApprovedTransaction(
+      [createTestTransferInstruction(vaultPda, recipient, LAMPORTS_PER_SOL)],
+      {
+        balanceGuards: {
+          maxLamportsOutflow: LAMPORTS_PER_SOL,
+          maxTokenOutflows: [],
+        },
+      }
+    );
+
+    await executeTransaction(transactionIndex);
+
+    assert.strictEqual(
+      await connection.getBalance(recipient),
+      LAMPORTS_PER_SOL
+    );
+  });
+
+  it("error: transfer exceeding the balance guards", async () => {
+    const recipient = Keypair.generate().publicKey;
+
+    const transactionIndex = await createApprovedTransaction(
+      [createTestTransferInstruction(vaultPda, recipient, LAMPORTS_PER_SOL)],
+      {
+        balanceGuards: {
+          maxLamportsOutflow: LAMPORTS_PER_SOL - 1,
+          maxTokenOutflows: [],
+        },
+      }
+    );
+
+    await assert.rejects(
+      () => executeTransaction(transactionIndex),
+      /LamportsOutflowExceeded/
+    );
+  });
+
+  it("error: vault assigned to another program", async () => {
+    const transactionIndex = await createApprovedTransaction([
+      SystemProgram.assign({ accountPubkey: vaultPda, programId }),
+    ]);
+
+    await assert.rejects(
+      () => executeTransaction(transactionIndex),
+      /SignerAccountStateChanged/
+    );
+  });
+
+  it("ephemeral signer account created for another program", async () => {
+    const createEphemeralSignerAccount = async (
+      denyEphemeralSignerAccountChanges: boolean
+    ) => {
+      const transactionIndex = await getNextTransactionIndex();
+      const [transactionPda] = multisig.getTransactionPda({
+        multisigPda,
+        index: transactionIndex,
+        programId,
+      });
+      const [ephemeralSignerPda] = multisig.getEphemeralSignerPda({
+        transactionPda,
+        ephemeralSignerIndex: 0,
+        programId,
+      });
+
+      await createApprovedTransaction(
+        [
+          SystemProgram.createAccount({
+            fromPubkey: vaultPda,
+            newAccountPubkey: ephemeralSignerPda,
+            lamports: await connection.getMinimumBalanceForRentExemption(8),
+            space: 8,
+            programId,
+          }),
+        ],
+        { ephemeralSigners: 1, denyEphemeralSignerAccountChanges }
+      );
+
+      await executeTransaction(transactionIndex);
+
+      return ephemeralSignerPda;
+    };
+
+    // The ephemeral signers can be turned into accounts unless the transaction denies it.
+    await assert.rejects(
+      () => createEphemeralSignerAccount(true),
+      /SignerAccountStateChanged/
+    );
+
+    const ephemeralSignerPda = await createEphemeralSignerAccount(false);
+    const ephemeralSignerAccount = await connection.getAccountInfo(
+      ephemeralSignerPda
+    );
+    assert.strictEqual(
+      ephemeralSignerAccount?.owner.toBase58(),
+      programId.toBase58()
+    );
+  });
+
+  it("execute a transaction whose preconditions hold", async () => {
+    const vaultBalance = await connection.getBalance(vaultPda);
+    // An account that is not a part of the message.
+    const otherAccount = Keypair.generate().publicKey;
+
+    const transactionIndex = await createApprovedTransaction(
+      [createTestTransferInstruction(vaultPda, Keypair.generate().publicKey)],
+      {
+        preconditions: [
+          {
+            account: vaultPda,
+            check: {
+              __kind: "Lamports",
+              min: vaultBalance,
+              max: vaultBalance,
+            },
+          },
+          {
+            account: otherAccount,
+            check: { __kind: "Owner", owner: SystemProgram.programId },
+          },
+        ],
+      }
+    );
+
+    // The accounts referenced by the preconditions must be passed.
+    await assert.rejects(
+      () => executeTransaction(transactionIndex),
+      /MissingAccount/
+    );
+
+    await executeTransaction(transactionIndex, [otherAccount]);
+
+    const proposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      multisig.getProposalPda({ multisigPda, transactionIndex, programId })[0]
+    );
+    assert.ok(multisig.types.isProposalStatusExecuted(proposalAccount.status));
+  });
+
+  it("error: precondition doesn't hold", async () => {
+    const vaultBalance = await connection.getBalance(vaultPda);
+
+    const transactionIndex = await createApprovedTransaction(
+      [createTestTransferInstruction(vaultPda, Keypair.generate().publicKey)],
+      {
+        preconditions: [
+          {
+            account: vaultPda,
+            check: {
+              __kind: "Lamports",
+              min: vaultBalance + 1,
+              max: vaultBalance + LAMPORTS_PER_SOL,
+            },
+          },
+        ],
+      }
+    );
+
+    await assert.rejects(
+      () => executeTransaction(transactionIndex),
+      /PreconditionFailed/
+    );
+  });
+
+  it("execute a transaction in several steps", async () => {
+    const recipients = [
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+      Keypair.generate().publicKey,
+    ];
+
+    const transactionIndex = await createApprovedTransaction(
+      recipients.map((recipient) =>
+        createTestTransferInstruction(vaultPda, recipient)
+      )
+    );
+    const [proposalPda] = multisig.getProposalPda({
+      multisigPda,
+      transactionIndex,
+      programId,
+    });
+
+    // Execute the first two instructions.
+    await executeTransactionPartially(transactionIndex, 2);
+
+    assert.strictEqual(await connection.getBalance(recipients[0]), 1_000_000);
+    assert.strictEqual(await connection.getBalance(recipients[1]), 1_000_000);
+    assert.strictEqual(await connection.getBalance(recipients[2]), 0);
+    let proposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      proposalPda
+    );
+    assert.ok(multisig.types.isProposalStatusApproved(proposalAccount.status));
+
+    // Can't execute past the last instruction.
+    await assert.rejects(
+      () => executeTransactionPartially(transactionIndex, 2),
+      /InvalidInstructionArgs/
+    );
+
+    // Execute the rest.
+    await executeTransactionPartially(transactionIndex, 1);
+
+    assert.strictEqual(await connection.getBalance(recipients[2]), 1_000_000);
+    proposalAccount = await Proposal.fromAccountAddress(
+      connection,
+      proposalPda
+    );
+    assert.ok(multisig.types.isProposalStatusExecuted(proposalAccount.status));
+  });
+
+  it("error: partial execution of a transaction with balance guards", async () => {
+    const transactionIndex = await createApprovedTransaction(
+      [
+        createTestTransferInstruction(vaultPda, Keypair.generate().publicKey),
+        createTestTransferInstruction(vaultPda, Keypair.generate().publicKey),
+      ],
+      {
+        balanceGuards: {
+          maxLamportsOutflow: LAMPORTS_PER_SOL,
+          maxTokenOutflows: [],
+        },
+      }
+    );
+
+    await assert.rejects(
+      () => executeTransactionPartially(transactionIndex, 1),
+      /PartialExecutionNotAllowed/
+    );
+  });
+});
@@ -5,6 +5,7 @@ import {
   LAMPORTS_PER_SOL,
   PublicKey,
   SystemProgram,
+  TransactionInstruction,
   TransactionMessage,
   VersionedTransaction,
 } from "@solana/web3.js";
@@ -14,7 +15,10 @@ import { readFileSync } from "fs";
 import path from "path";
 
 const { Permission, Permissions } = multisig.types;
-const { Proposal } = multisig.accounts;
+const { Multisig, Proposal } = multisig.accounts;
+
+/** Marks the start of an account extension in the account data. */
+const ACCOUNT_EXTENSION_PREFIX = Buffer.from("SQDSEXT1");
 
 export function getTestProgramId() {
   const programKeypair = Keypair.fromSecretKey(
@@ -1188,6 +1192,64 @@ export function comparePubkeys(a: PublicKey, b: PublicKey) {
   return a.toBuffer().compare(b.toBuffer());
 }
 
+/** Signs `instructions` with `signers`, the first of which pays the fees, and sends them. */
+export async function sendInstructions(
+  connection: Connection,
+  instructions: TransactionInstruction[],
+  signers: Keypair[]
+) {
+  const message = new TransactionMessage({
+    payerKey: signers[0].publicKey,
+    recentBlockhash: (await connection.getLatestBlockhash()).blockhash,
+    instructions,
+  }).compileToV0Message();
+
+  const tx = new VersionedTransaction(message);
+  tx.sign(signers);
+
+  const signature = await connection
+    .sendTransaction(tx)
+    .catch(multisig.errors.translateAndThrowAnchorError);
+  await connection.confirmTransaction(signature);
+
+  return signature;
+}
+
+/**
+ * Reads the account extension that starts at `offset`, after the original fields of the account.
+ * Returns null if the account doesn't have one yet.
+ */
+export function getAccountExtension<T>(
+  data: Buffer,
+  offset: number,
+  extensionBeet: { deserialize(buf: Buffer, offset?: number): [T, number] }
+): T | null {
+  const prefix = data.subarray(offset, offset + ACCOUNT_EXTENSION_PREFIX.length);
+  if (!prefix.equals(ACCOUNT_EXTENSION_PREFIX)) {
+    return null;
+  }
+
+  return extensionBeet.deserialize(
+    data,
+    offset + ACCOUNT_EXTENSION_PREFIX.length
+  )[0];
+}
+
+/** Reads the extension of the multisig account at `multisigPda`. */
+export async function getMultisigExtension(
+  connection: Connection,
+  multisigPda: PublicKey
+) {
+  const accountInfo = await connection.getAccountInfo(multisigPda);
+  const [, offset] = Multisig.fromAccountInfo(accountInfo!);
+
+  return getAccountExtension(
+    accountInfo!.data,
+    offset,
+    multisig.generated.multisigExtensionBeet
+  );
+}
+
 export async function processBufferInChunks(
   member: Keypair,
   multisigPda: PublicKey,
@@ -1210,6 +1272,9 @@ export async function processBufferInChunks(
         multisig: multisigPda,
         transactionBuffer: bufferAccount,
         creator: member.publicKey,
+        // Pays for growing buffers larger than a single allocation.
+        rentPayer: member.publicKey,
+        systemProgram: SystemProgram.programId,
       },
       {
         args: {